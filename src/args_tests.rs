@@ -0,0 +1,141 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use crate::domain::{Node, Table};
+use crate::env::Environment;
+use crate::env::args::CommandLineSource;
+use crate::env::layer::SourceKind;
+use crate::env::source::EnvSource;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::ConfigerError;
+use crate::reader::{ConfigReaderRegistry, ReaderRegistry};
+use crate::reader::properties::PropertiesConfigReader;
+use crate::test_support::{nested, string};
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_command_line_options() {
+    let args = CommandLineSource::new()
+        .load_from([
+            "run",
+            "--server.port=8080",
+            "--server.host",
+            "localhost",
+            "--verbose",
+            "--no-cache",
+            "-v",
+            "--offset",
+            "-5",
+            "--debug",
+        ])
+        .unwrap();
+
+    let table: Table = Table::from([
+        (String::from("server"), nested(vec![("port", string("8080")), ("host", string("localhost"))])),
+        (String::from("verbose"), Node::Boolean(true)),
+        (String::from("cache"), Node::Boolean(false)),
+        (String::from("offset"), string("-5")),
+        (String::from("debug"), Node::Boolean(true)),
+    ]);
+    assert_eq!(args.table(), &table);
+    assert_eq!(args.positionals(), &[String::from("run"), String::from("-v")]);
+}
+
+#[test]
+fn test_command_line_key_paths_and_terminator() {
+    let args = CommandLineSource::new()
+        .load_from([
+            "--hosts[+]=a",
+            "--hosts[+]=b",
+            "--servers[0].host=x",
+            "--log.level.\"my.crate\"=debug",
+            "--server.port=1",
+            "--server.port=2",
+            "--",
+            "--not-an-option",
+            "file.txt",
+        ])
+        .unwrap();
+
+    assert_eq!(args.table().get("hosts"), Some(&Node::Array(vec![string("a"), string("b")])));
+    assert_eq!(args.table().get("servers"), Some(&Node::Array(vec![nested(vec![("host", string("x"))])])));
+    assert_eq!(args.table().get("log"), Some(&nested(vec![("level", nested(vec![("my.crate", string("debug"))]))])));
+    assert_eq!(args.table().get("server"), Some(&nested(vec![("port", string("2"))])));
+    assert_eq!(args.positionals(), &[String::from("--not-an-option"), String::from("file.txt")]);
+
+    assert_eq!(CommandLineSource::new().load_from(["--=value"]), Err(ConfigerError::EmptyKey));
+    assert_eq!(
+        CommandLineSource::new().load_from(["--a[=value"]),
+        Err(ConfigerError::InvalidKey(String::from("--a[=value")))
+    );
+    assert_eq!(
+        CommandLineSource::new().load_from(["--server=a", "--server.port=1"]),
+        Err(ConfigerError::NonNested)
+    );
+}
+
+#[test]
+fn test_command_line_infer_types() {
+    let args = CommandLineSource::new()
+        .with_infer_types(true)
+        .load_from(["--server.port=8080", "--ratio", "0.5", "--hosts=[a,b]"])
+        .unwrap();
+
+    assert_eq!(args.table().get("server"), Some(&nested(vec![("port", Node::Int64(8080))])));
+    assert_eq!(args.table().get("ratio"), Some(&Node::Float64(0.5)));
+    assert_eq!(args.table().get("hosts"), Some(&Node::Array(vec![string("a"), string("b")])));
+}
+
+#[test]
+fn test_build_configer_with_command_line() {
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<PropertiesConfigReader>::default());
+
+    let args = CommandLineSource::new().load_from(["serve", "--server.port", "6060"]).unwrap();
+    let env_vars = HashMap::from([(String::from("APP_SERVER__PORT"), String::from("7070"))]);
+
+    let configer = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(String::from("resources/testdata/application.properties"))
+        .with_env_source(EnvSource::prefixed("APP_").with_separator("__").with_vars(env_vars))
+        .with_command_line(&args)
+        .build()
+        .unwrap();
+
+    // The command line wins over the environment variables and the files
+    assert_eq!(configer.get("server.port"), Ok(&string("6060")));
+    assert_eq!(configer.get("server.host"), Ok(&string("127.0.0.1")));
+    assert_eq!(args.positionals(), &[String::from("serve")]);
+
+    let explanation = configer.explain("server.port").unwrap();
+    let winner = explanation.winner.unwrap();
+    assert_eq!(winner.origin.kind, SourceKind::CommandLine);
+    assert_eq!(winner.origin.to_string(), "command_line[--server.port]");
+    assert_eq!(explanation.shadowed[0].origin.variable, Some(String::from("APP_SERVER__PORT")));
+
+    // The `table`-only branch stacks it too
+    let configer = ConfigerEnvironment::builder()
+        .with_table(Table::from([(String::from("debug"), Node::Boolean(false))]))
+        .with_command_line(&CommandLineSource::new().load_from(["--debug"]).unwrap())
+        .build()
+        .unwrap();
+    assert_eq!(configer.get("debug"), Ok(&Node::Boolean(true)));
+}
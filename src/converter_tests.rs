@@ -16,7 +16,7 @@
 
 use std::f32::consts::PI;
 
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use chronounit::formatter::pattern::DateTimePattern;
 use snowflaker::snowflake_dynamic;
 
@@ -25,9 +25,11 @@ use crate::domain::{Node, Table};
 use crate::domain::converter::NodeConverter;
 use crate::env::Environment;
 use crate::env::standard::ConfigerEnvironment;
+use crate::error::{ConfigerError, ConversionError};
 
 #[test]
 #[rustfmt::skip]
+#[allow(clippy::single_match)]
 fn test_get_converter_nested() {
     let mut configer = ConfigerEnvironment::new();
 
@@ -52,6 +54,7 @@ fn test_get_converter_nested() {
 
 #[test]
 #[rustfmt::skip]
+#[allow(clippy::vec_init_then_push)]
 fn test_get_converter_array() {
     let mut configer = ConfigerEnvironment::new();
     let now = 1710265983u32;
@@ -80,6 +83,7 @@ fn assert_array_equals(array: &domain::Array, vec: &domain::Array) -> bool {
 
 #[test]
 #[rustfmt::skip]
+#[allow(clippy::collapsible_match, clippy::single_match)]
 fn test_get_converter_date_time() {
     let mut configer = ConfigerEnvironment::new();
 
@@ -141,6 +145,7 @@ fn test_get_converter_str() {
 
 #[test]
 #[rustfmt::skip]
+#[allow(clippy::bool_assert_comparison)]
 fn test_get_converter_bool() {
     let mut configer = ConfigerEnvironment::new();
 
@@ -297,3 +302,127 @@ fn test_get_converter_none() {
         panic!("failed to convert the value to none")
     }
 }
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_to_integers() {
+    assert_eq!(NodeConverter::to_i64(Ok(&Node::Int32(9527))), Ok(9527_i64));
+    assert_eq!(NodeConverter::to_i64(Ok(&Node::IntU64(9527))), Ok(9527_i64));
+    assert_eq!(NodeConverter::to_i64(Ok(&Node::Int128(-9527))), Ok(-9527_i64));
+    assert_eq!(NodeConverter::to_i64(Ok(&Node::String(" 9090 ".to_string()))), Ok(9090_i64));
+    assert_eq!(NodeConverter::to_i64(Ok(&Node::Float64(8080.0))), Ok(8080_i64));
+    assert_eq!(NodeConverter::to_u16(Ok(&Node::Int64(8080))), Ok(8080_u16));
+    assert_eq!(NodeConverter::to_u128(Ok(&Node::IntU128(u128::MAX))), Ok(u128::MAX));
+    assert_eq!(NodeConverter::to_usize(Ok(&Node::IntU32(3))), Ok(3_usize));
+
+    assert_eq!(
+        NodeConverter::to_u16(Ok(&Node::Int64(65536))),
+        Err(ConversionError::Overflow("Int64".to_string(), "u16".to_string()))
+    );
+    assert_eq!(
+        NodeConverter::to_u32(Ok(&Node::Int32(-1))),
+        Err(ConversionError::Overflow("Int32".to_string(), "u32".to_string()))
+    );
+    assert_eq!(
+        NodeConverter::to_i64(Ok(&Node::String("999999999999999999999999999999999999999999".to_string()))),
+        Err(ConversionError::Overflow("String".to_string(), "i64".to_string()))
+    );
+    assert_eq!(
+        NodeConverter::to_i64(Ok(&Node::String("abc".to_string()))),
+        Err(ConversionError::InvalidValue("String".to_string(), "i64".to_string(), "[abc] is not an integer".to_string()))
+    );
+    assert_eq!(
+        NodeConverter::to_i32(Ok(&Node::Float64(1.5))),
+        Err(ConversionError::InvalidValue("Float64".to_string(), "i32".to_string(), "[1.5] is not an integral number".to_string()))
+    );
+    assert_eq!(
+        NodeConverter::to_i64(Ok(&Node::Boolean(true))),
+        Err(ConversionError::Unsupported("Boolean".to_string(), "i64".to_string()))
+    );
+    assert_eq!(
+        NodeConverter::to_i64(Err(ConfigerError::NotFound)),
+        Err(ConversionError::Lookup(ConfigerError::NotFound))
+    );
+}
+
+#[test]
+fn test_to_bool() {
+    for value in ["true", "YES", "on", "1", " y "] {
+        assert_eq!(NodeConverter::to_bool(Ok(&Node::String(value.to_string()))), Ok(true));
+    }
+    for value in ["false", "No", "OFF", "0", "n"] {
+        assert_eq!(NodeConverter::to_bool(Ok(&Node::String(value.to_string()))), Ok(false));
+    }
+
+    assert_eq!(NodeConverter::to_bool(Ok(&Node::Boolean(true))), Ok(true));
+    assert_eq!(NodeConverter::to_bool(Ok(&Node::IntU32(0))), Ok(false));
+    assert_eq!(NodeConverter::to_bool(Ok(&Node::Int64(1))), Ok(true));
+    assert!(matches!(
+        NodeConverter::to_bool(Ok(&Node::Int64(2))),
+        Err(ConversionError::InvalidValue(_, _, _))
+    ));
+    assert!(matches!(
+        NodeConverter::to_bool(Ok(&Node::String("maybe".to_string()))),
+        Err(ConversionError::InvalidValue(_, _, _))
+    ));
+    assert_eq!(
+        NodeConverter::to_bool(Ok(&Node::Float64(1.0))),
+        Err(ConversionError::Unsupported("Float64".to_string(), "bool".to_string()))
+    );
+}
+
+#[test]
+fn test_to_floats() {
+    assert_eq!(NodeConverter::to_f64(Ok(&Node::Float32(1.5))), Ok(1.5_f64));
+    assert_eq!(NodeConverter::to_f64(Ok(&Node::Int32(3))), Ok(3.0_f64));
+    assert_eq!(NodeConverter::to_f64(Ok(&Node::String("2.5".to_string()))), Ok(2.5_f64));
+    assert_eq!(NodeConverter::to_f32(Ok(&Node::Float64(2.5))), Ok(2.5_f32));
+    assert_eq!(
+        NodeConverter::to_f32(Ok(&Node::Float64(f64::MAX))),
+        Err(ConversionError::Overflow("Float64".to_string(), "f32".to_string()))
+    );
+    assert_eq!(NodeConverter::to_f32(Ok(&Node::String("2.5".to_string()))), Ok(2.5_f32));
+    assert_eq!(
+        NodeConverter::to_f32(Ok(&Node::String("1e300".to_string()))),
+        Err(ConversionError::Overflow("String".to_string(), "f32".to_string()))
+    );
+    assert!(matches!(
+        NodeConverter::to_f64(Ok(&Node::String("pi".to_string()))),
+        Err(ConversionError::InvalidValue(_, _, _))
+    ));
+    assert_eq!(
+        NodeConverter::to_f64(Ok(&Node::None)),
+        Err(ConversionError::Unsupported("None".to_string(), "f64".to_string()))
+    );
+}
+
+#[test]
+fn test_to_i64_after_merge() {
+    let mut configer = ConfigerEnvironment::new();
+    configer.set("server.port", Node::String("9090".to_string())).unwrap();
+    configer.set("server.workers", Node::Int32(8)).unwrap();
+
+    assert_eq!(NodeConverter::try_int_i64(configer.get("server.port")), None);
+    assert_eq!(NodeConverter::to_i64(configer.get("server.port")), Ok(9090));
+    assert_eq!(NodeConverter::to_u16(configer.get("server.workers")), Ok(8));
+}
+
+#[test]
+fn test_get_converter_date_time_variants() {
+    let mut configer = ConfigerEnvironment::new();
+
+    let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    let time = NaiveTime::from_hms_opt(1, 2, 3).unwrap();
+    let offset = DateTime::parse_from_rfc3339("2024-03-15T01:02:03+08:00").unwrap();
+
+    configer.set("io.github.photowey.configer.date", date.into()).unwrap();
+    configer.set("io.github.photowey.configer.time", time.into()).unwrap();
+    configer.set("io.github.photowey.configer.offset", offset.into()).unwrap();
+
+    assert_eq!(NodeConverter::try_date(configer.get("io.github.photowey.configer.date")), Some(&date));
+    assert_eq!(NodeConverter::try_time(configer.get("io.github.photowey.configer.time")), Some(&time));
+    assert_eq!(NodeConverter::try_offset_datetime(configer.get("io.github.photowey.configer.offset")), Some(&offset));
+    assert_eq!(NodeConverter::try_datetime(configer.get("io.github.photowey.configer.date")), None);
+    assert_eq!(configer.get("io.github.photowey.configer.offset").unwrap().variant_name(), "OffsetDateTime");
+}
@@ -0,0 +1,195 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use crate::domain::{diff, Node, Table};
+use crate::domain::diff::{Change, ChangeKind, diff_with, DiffOptions};
+use crate::env::Environment;
+use crate::env::reload::ChangedKeys;
+use crate::env::standard::ConfigerEnvironment;
+use crate::test_support::{string, table};
+
+// ----------------------------------------------------------------
+
+fn old_config() -> Table {
+    table(vec![
+        ("server", Node::Nested(table(vec![("port", Node::Int64(8080)), ("host", string("localhost"))]))),
+        ("hosts", Node::Array(vec![string("a"), string("b")])),
+        ("log", Node::Nested(table(vec![("level", string("info"))]))),
+    ])
+}
+
+fn new_config() -> Table {
+    table(vec![
+        ("server", Node::Nested(table(vec![("port", Node::Int64(9090))]))),
+        ("hosts", Node::Array(vec![string("a"), string("c"), string("d")])),
+        ("log", string("off")),
+    ])
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_diff() {
+    let changes = diff(&old_config(), &new_config());
+
+    assert_eq!(
+        changes,
+        vec![
+            Change::Changed { path: "hosts[1]".to_string(), old: string("b"), new: string("c") },
+            Change::Added { path: "hosts[2]".to_string(), value: string("d") },
+            Change::Added { path: "log".to_string(), value: string("off") },
+            Change::Removed { path: "log.level".to_string(), value: string("info") },
+            Change::Removed { path: "server.host".to_string(), value: string("localhost") },
+            Change::Changed { path: "server.port".to_string(), old: Node::Int64(8080), new: Node::Int64(9090) },
+        ]
+    );
+    assert!(diff(&old_config(), &old_config()).is_empty());
+}
+
+#[test]
+fn test_diff_empty_containers_are_leaves() {
+    let old = table(vec![("tags", Node::Array(Vec::new())), ("extra", Node::Nested(Table::new()))]);
+    let new = table(vec![("tags", Node::Array(vec![string("a")])), ("extra", Node::Nested(Table::new()))]);
+
+    assert_eq!(
+        diff(&old, &new),
+        vec![
+            Change::Removed { path: "tags".to_string(), value: Node::Array(Vec::new()) },
+            Change::Added { path: "tags[0]".to_string(), value: string("a") },
+        ]
+    );
+}
+
+#[test]
+fn test_diff_sorted_by_index() {
+    let hosts = |hosts: Vec<Node>| table(vec![("hosts", Node::Array(hosts)), ("hosts_extra", Node::Int64(1))]);
+    let old = hosts(Vec::new());
+    let new = hosts((0..12).map(Node::Int64).collect());
+
+    let paths: Vec<String> = diff(&old, &new).iter().map(|change| change.path().to_string()).collect();
+    let mut expected = vec![String::from("hosts")];
+    expected.extend((0..12).map(|index| format!("hosts[{}]", index)));
+    assert_eq!(paths, expected);
+
+    // The same order in the changed keys of a reload
+    let keys = ChangedKeys::between(&old, &new);
+    assert_eq!(keys.added[2], "hosts[2]");
+    assert_eq!(keys.added[11], "hosts[11]");
+}
+
+#[test]
+fn test_diff_with_numeric_equivalence() {
+    let old = table(vec![("a", Node::Int32(1)), ("b", Node::IntU64(2)), ("c", Node::Float64(3.0)), ("d", Node::Int64(-1))]);
+    let new = table(vec![("a", Node::Int64(1)), ("b", Node::Int128(2)), ("c", Node::Int32(3)), ("d", Node::IntU128(u128::MAX))]);
+
+    assert_eq!(diff(&old, &new).len(), 4);
+
+    let options = DiffOptions::new().with_numeric_equivalence(true);
+    assert_eq!(
+        diff_with(&old, &new, &options),
+        vec![Change::Changed { path: "d".to_string(), old: Node::Int64(-1), new: Node::IntU128(u128::MAX) }]
+    );
+
+    // Not a number
+    let old = table(vec![("a", string("1"))]);
+    let new = table(vec![("a", Node::Int64(1))]);
+    assert_eq!(diff_with(&old, &new, &options).len(), 1);
+}
+
+#[test]
+fn test_diff_with_float_tolerance() {
+    let old = table(vec![("ratio", Node::Float64(0.1)), ("nan", Node::Float64(f64::NAN)), ("f32", Node::Float32(0.5))]);
+    let new = table(vec![("ratio", Node::Float64(0.1000001)), ("nan", Node::Float64(f64::NAN)), ("f32", Node::Float64(0.5))]);
+
+    let paths = |changes: Vec<Change>| -> Vec<String> { changes.iter().map(|change| change.path().to_string()).collect() };
+    assert_eq!(paths(diff(&old, &new)), vec!["f32", "ratio"]);
+
+    let options = DiffOptions::new().with_float_tolerance(1e-6);
+    assert_eq!(paths(diff_with(&old, &new, &options)), vec!["f32"]);
+
+    let options = options.with_numeric_equivalence(true);
+    assert!(diff_with(&old, &new, &options).is_empty());
+}
+
+#[test]
+fn test_change_display() {
+    let rendered: Vec<String> = diff(&old_config(), &new_config()).iter().map(ToString::to_string).collect();
+
+    assert_eq!(
+        rendered,
+        vec![
+            "~ hosts[1]: String(\"b\") => String(\"c\")",
+            "+ hosts[2] = String(\"d\")",
+            "+ log = String(\"off\")",
+            "- log.level = String(\"info\")",
+            "- server.host = String(\"localhost\")",
+            "~ server.port: Int64(8080) => Int64(9090)",
+        ]
+    );
+    assert_eq!(ChangeKind::Changed.to_string(), "changed");
+}
+
+#[test]
+fn test_change_to_node() {
+    let change = Change::Changed { path: "server.port".to_string(), old: Node::Int64(8080), new: Node::Int64(9090) };
+    assert_eq!(change.kind(), ChangeKind::Changed);
+    assert_eq!(
+        change.to_node(),
+        Node::Nested(table(vec![
+            ("kind", string("changed")),
+            ("path", string("server.port")),
+            ("old", Node::Int64(8080)),
+            ("new", Node::Int64(9090)),
+        ]))
+    );
+
+    let change = Change::Added { path: "server.host".to_string(), value: string("localhost") };
+    assert_eq!(change.old_value(), None);
+    assert_eq!(
+        change.to_node(),
+        Node::Nested(table(vec![("kind", string("added")), ("path", string("server.host")), ("new", string("localhost"))]))
+    );
+}
+
+#[test]
+fn test_environment_diff() {
+    let old = ConfigerEnvironment::builder().with_table(old_config()).with_env_enabled(false).build().unwrap();
+    let mut new = ConfigerEnvironment::builder().with_table(old_config()).with_env_enabled(false).build().unwrap();
+    new.set("server.port", Node::Int32(8080)).unwrap();
+
+    assert_eq!(old.diff(&new).len(), 1);
+    assert!(old.diff_with(&new, &DiffOptions::new().with_numeric_equivalence(true)).is_empty());
+
+    let shared = new.into_shared();
+    let before = shared.snapshot();
+    shared.set("log.level", string("debug")).unwrap();
+
+    assert_eq!(
+        before.diff(&shared.snapshot()),
+        vec![Change::Changed { path: "log.level".to_string(), old: string("info"), new: string("debug") }]
+    );
+}
+
+#[test]
+#[cfg(all(feature = "serde", feature = "usejson"))]
+fn test_change_serialize() {
+    let change = Change::Removed { path: "log.level".to_string(), value: string("info") };
+    let json: serde_json::Value = serde_json::to_value(&change).unwrap();
+
+    assert_eq!(json, serde_json::json!({"kind": "removed", "path": "log.level", "old": "info"}));
+}
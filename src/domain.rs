@@ -19,10 +19,29 @@
 use std::collections::HashMap;
 use std::mem;
 
-use chrono::NaiveDateTime;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 
 // ----------------------------------------------------------------
 pub mod converter;
+/// @since 0.7.0
+pub mod keypath;
+/// @since 0.7.0
+pub mod units;
+/// @since 0.7.0
+pub mod merge;
+/// @since 0.7.0
+pub mod diff;
+/// @since 0.7.0
+pub mod patch;
+/// @since 0.7.0
+#[cfg(feature = "serde")]
+pub mod de;
+/// @since 0.7.0
+#[cfg(feature = "serde")]
+pub mod ser;
+
+/// @since 0.7.0
+pub use self::diff::diff;
 
 // ----------------------------------------------------------------
 
@@ -30,6 +49,7 @@ pub type Table = HashMap<String, Node>;
 
 // ----------------------------------------------------------------
 
+#[allow(clippy::empty_line_after_doc_comments)]
 /// @since 0.2.0
 
 pub type Array = Vec<Node>;
@@ -41,7 +61,20 @@ pub enum Node {
     Nested(Table),
     /// @since 0.2.0
     Array(Array),
+    /// A local date-time, without an offset.
     DateTime(NaiveDateTime),
+    /// A local date, e.g.: `2024-03-15`.
+    ///
+    /// @since 0.7.0
+    Date(NaiveDate),
+    /// A local time, e.g.: `01:02:03`.
+    ///
+    /// @since 0.7.0
+    Time(NaiveTime),
+    /// An offset-aware date-time, e.g.: `2024-03-15T01:02:03+08:00`.
+    ///
+    /// @since 0.7.0
+    OffsetDateTime(DateTime<FixedOffset>),
     String(String),
     /// @since 0.3.0
     Boolean(bool),
@@ -66,10 +99,36 @@ impl Node {
             _ => None,
         }
     }
+
+    /// The name of the variant, e.g.: `Int64`.
+    ///
+    /// @since 0.7.0
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Node::Nested(_) => "Nested",
+            Node::Array(_) => "Array",
+            Node::DateTime(_) => "DateTime",
+            Node::Date(_) => "Date",
+            Node::Time(_) => "Time",
+            Node::OffsetDateTime(_) => "OffsetDateTime",
+            Node::String(_) => "String",
+            Node::Boolean(_) => "Boolean",
+            Node::IntU128(_) => "IntU128",
+            Node::IntU64(_) => "IntU64",
+            Node::IntU32(_) => "IntU32",
+            Node::Int128(_) => "Int128",
+            Node::Int64(_) => "Int64",
+            Node::Int32(_) => "Int32",
+            Node::Float64(_) => "Float64",
+            Node::Float32(_) => "Float32",
+            Node::None => "None",
+        }
+    }
 }
 
 // ----------------------------------------------------------------
 
+#[allow(clippy::derivable_impls)]
 impl Default for Node {
     fn default() -> Self {
         Self::None
@@ -102,6 +161,27 @@ impl From<NaiveDateTime> for Node {
 
 // ----------------------------------------------------------------
 
+/// @since 0.7.0
+impl From<NaiveDate> for Node {
+    fn from(value: NaiveDate) -> Self {
+        Node::Date(value)
+    }
+}
+
+/// @since 0.7.0
+impl From<NaiveTime> for Node {
+    fn from(value: NaiveTime) -> Self {
+        Node::Time(value)
+    }
+}
+
+/// @since 0.7.0
+impl From<DateTime<FixedOffset>> for Node {
+    fn from(value: DateTime<FixedOffset>) -> Self {
+        Node::OffsetDateTime(value)
+    }
+}
+
 impl From<String> for Node {
     fn from(value: String) -> Self {
         Node::String(value)
@@ -205,6 +285,33 @@ impl<'a> From<&'a Node> for Option<&'a NaiveDateTime> {
     }
 }
 
+impl<'a> From<&'a Node> for Option<&'a NaiveDate> {
+    fn from(node: &'a Node) -> Option<&'a NaiveDate> {
+        match *node {
+            Node::Date(ref date) => Some(date),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> From<&'a Node> for Option<&'a NaiveTime> {
+    fn from(node: &'a Node) -> Option<&'a NaiveTime> {
+        match *node {
+            Node::Time(ref time) => Some(time),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> From<&'a Node> for Option<&'a DateTime<FixedOffset>> {
+    fn from(node: &'a Node) -> Option<&'a DateTime<FixedOffset>> {
+        match *node {
+            Node::OffsetDateTime(ref datetime) => Some(datetime),
+            _ => None,
+        }
+    }
+}
+
 impl<'a> From<&'a Node> for Option<&'a String> {
     fn from(node: &'a Node) -> Option<&'a String> {
         match *node {
@@ -324,6 +431,7 @@ impl<'a> From<&'a Node> for Option<&'a ()> {
 ///
 /// # Warning
 /// The [`merge_tables`] algo. is not implemented well, it just implements the function.
+/// See: [`merge_tables_with`](merge::merge_tables_with) for the array strategies and the tombstones. `@since 0.7.0`
 pub fn merge_tables(mut dst: Table, src: Table) -> Table {
     for (key, src_node) in src {
         let dst_node = dst.get_mut(&key).map(mem::take);
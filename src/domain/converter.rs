@@ -16,15 +16,28 @@
 
 // ----------------------------------------------------------------
 
-use chrono::NaiveDateTime;
+use std::str::FromStr;
+use std::time::Duration;
 
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::domain::units::{parse_byte_size, parse_duration, ByteSize, ByteUnit, DurationUnit, UnitError};
 use crate::domain::{Array, Node, Table};
-use crate::error::ConfigerError;
+use crate::error::{ConfigerError, ConversionError};
+
+// ----------------------------------------------------------------
+
+const TRUE_VALUES: [&str; 5] = ["true", "yes", "on", "y", "1"];
+const FALSE_VALUES: [&str; 5] = ["false", "no", "off", "n", "0"];
 
 // ----------------------------------------------------------------
 
 /// A struct responsible for converting between node types.
 ///
+/// The `try_*` family is strict: it only matches the exact variant.
+/// The `to_*` family is lenient: it converts across the integer variants with overflow checks,
+/// parses strings and widens floats, failing with a [`ConversionError`].
+///
 /// @sine 0.2.0
 pub struct NodeConverter;
 
@@ -36,6 +49,30 @@ impl NodeConverter {
         }
     }
 
+    /// @since 0.7.0
+    pub fn try_date(rvt: Result<&Node, ConfigerError>) -> Option<&NaiveDate> {
+        match rvt {
+            Ok(node) => node.into(),
+            _ => None,
+        }
+    }
+
+    /// @since 0.7.0
+    pub fn try_time(rvt: Result<&Node, ConfigerError>) -> Option<&NaiveTime> {
+        match rvt {
+            Ok(node) => node.into(),
+            _ => None,
+        }
+    }
+
+    /// @since 0.7.0
+    pub fn try_offset_datetime(rvt: Result<&Node, ConfigerError>) -> Option<&DateTime<FixedOffset>> {
+        match rvt {
+            Ok(node) => node.into(),
+            _ => None,
+        }
+    }
+
     pub fn try_string(rvt: Result<&Node, ConfigerError>) -> Option<&String> {
         match rvt {
             Ok(node) => node.into(),
@@ -134,4 +171,268 @@ impl NodeConverter {
             _ => None,
         }
     }
+
+    // ----------------------------------------------------------------
+
+    /// @since 0.7.0
+    pub fn to_i8(rvt: Result<&Node, ConfigerError>) -> Result<i8, ConversionError> {
+        to_integer(rvt, "i8")
+    }
+
+    /// @since 0.7.0
+    pub fn to_i16(rvt: Result<&Node, ConfigerError>) -> Result<i16, ConversionError> {
+        to_integer(rvt, "i16")
+    }
+
+    /// @since 0.7.0
+    pub fn to_i32(rvt: Result<&Node, ConfigerError>) -> Result<i32, ConversionError> {
+        to_integer(rvt, "i32")
+    }
+
+    /// @since 0.7.0
+    pub fn to_i64(rvt: Result<&Node, ConfigerError>) -> Result<i64, ConversionError> {
+        to_integer(rvt, "i64")
+    }
+
+    /// @since 0.7.0
+    pub fn to_i128(rvt: Result<&Node, ConfigerError>) -> Result<i128, ConversionError> {
+        to_integer(rvt, "i128")
+    }
+
+    /// @since 0.7.0
+    pub fn to_isize(rvt: Result<&Node, ConfigerError>) -> Result<isize, ConversionError> {
+        to_integer(rvt, "isize")
+    }
+
+    /// @since 0.7.0
+    pub fn to_u8(rvt: Result<&Node, ConfigerError>) -> Result<u8, ConversionError> {
+        to_integer(rvt, "u8")
+    }
+
+    /// @since 0.7.0
+    pub fn to_u16(rvt: Result<&Node, ConfigerError>) -> Result<u16, ConversionError> {
+        to_integer(rvt, "u16")
+    }
+
+    /// @since 0.7.0
+    pub fn to_u32(rvt: Result<&Node, ConfigerError>) -> Result<u32, ConversionError> {
+        to_integer(rvt, "u32")
+    }
+
+    /// @since 0.7.0
+    pub fn to_u64(rvt: Result<&Node, ConfigerError>) -> Result<u64, ConversionError> {
+        to_integer(rvt, "u64")
+    }
+
+    /// @since 0.7.0
+    pub fn to_u128(rvt: Result<&Node, ConfigerError>) -> Result<u128, ConversionError> {
+        to_integer(rvt, "u128")
+    }
+
+    /// @since 0.7.0
+    pub fn to_usize(rvt: Result<&Node, ConfigerError>) -> Result<usize, ConversionError> {
+        to_integer(rvt, "usize")
+    }
+
+    /// Converts floats, widening [`Node::Float32`], integers and numeric strings.
+    ///
+    /// @since 0.7.0
+    pub fn to_f64(rvt: Result<&Node, ConfigerError>) -> Result<f64, ConversionError> {
+        let node = rvt?;
+        match node {
+            Node::Float64(f) => Ok(*f),
+            Node::Float32(f) => Ok(*f as f64),
+            Node::String(s) => parse_str(node, s, "f64", "not a number"),
+            _ => match integer_of(node) {
+                Some(Integer::Signed(i)) => Ok(i as f64),
+                Some(Integer::Unsigned(u)) => Ok(u as f64),
+                None => Err(unsupported(node, "f64")),
+            },
+        }
+    }
+
+    /// Converts floats, narrowing finite [`Node::Float64`] values within range, integers and numeric strings.
+    ///
+    /// @since 0.7.0
+    pub fn to_f32(rvt: Result<&Node, ConfigerError>) -> Result<f32, ConversionError> {
+        let node = rvt?;
+        match node {
+            Node::Float32(f) => Ok(*f),
+            Node::Float64(f) => narrow_f32(node, *f),
+            Node::String(s) => narrow_f32(node, parse_str(node, s, "f32", "not a number")?),
+            _ => match integer_of(node) {
+                Some(Integer::Signed(i)) => Ok(i as f32),
+                Some(Integer::Unsigned(u)) => Ok(u as f32),
+                None => Err(unsupported(node, "f32")),
+            },
+        }
+    }
+
+    /// Converts booleans, `0` | `1` integers and, case-insensitive,
+    /// `true` | `yes` | `on` | `y` | `1` and `false` | `no` | `off` | `n` | `0` strings.
+    ///
+    /// @since 0.7.0
+    pub fn to_bool(rvt: Result<&Node, ConfigerError>) -> Result<bool, ConversionError> {
+        let node = rvt?;
+        match node {
+            Node::Boolean(b) => Ok(*b),
+            Node::String(s) => {
+                let value = s.trim();
+                if TRUE_VALUES.iter().any(|v| v.eq_ignore_ascii_case(value)) {
+                    return Ok(true);
+                }
+                if FALSE_VALUES.iter().any(|v| v.eq_ignore_ascii_case(value)) {
+                    return Ok(false);
+                }
+
+                Err(invalid(node, "bool", format!("[{}] is not a boolean", s)))
+            }
+            _ => match integer_of(node) {
+                Some(Integer::Signed(0)) | Some(Integer::Unsigned(0)) => Ok(false),
+                Some(Integer::Signed(1)) | Some(Integer::Unsigned(1)) => Ok(true),
+                Some(_) => Err(invalid(node, "bool", "only 0 and 1 are booleans".to_string())),
+                None => Err(unsupported(node, "bool")),
+            },
+        }
+    }
+
+    /// Converts unit-suffixed strings, e.g.: `30s` or `1m30s`, and plain numbers in `default_unit`,
+    /// see: [`parse_duration`].
+    ///
+    /// @since 0.7.0
+    pub fn to_duration(rvt: Result<&Node, ConfigerError>, default_unit: DurationUnit) -> Result<Duration, ConversionError> {
+        let node = rvt?;
+        let value = unit_text(node, "Duration")?;
+
+        parse_duration(&value, default_unit).map_err(|err| unit_error(node, "Duration", err))
+    }
+
+    /// Converts unit-suffixed strings, e.g.: `512MiB` or `1.5GB`, and plain numbers in `default_unit`,
+    /// see: [`parse_byte_size`].
+    ///
+    /// @since 0.7.0
+    pub fn to_byte_size(rvt: Result<&Node, ConfigerError>, default_unit: ByteUnit) -> Result<ByteSize, ConversionError> {
+        let node = rvt?;
+        let value = unit_text(node, "ByteSize")?;
+
+        parse_byte_size(&value, default_unit).map_err(|err| unit_error(node, "ByteSize", err))
+    }
+}
+
+// ----------------------------------------------------------------
+
+enum Integer {
+    Signed(i128),
+    Unsigned(u128),
+}
+
+fn integer_of(node: &Node) -> Option<Integer> {
+    match node {
+        Node::IntU128(u) => Some(Integer::Unsigned(*u)),
+        Node::IntU64(u) => Some(Integer::Unsigned(*u as u128)),
+        Node::IntU32(u) => Some(Integer::Unsigned(*u as u128)),
+        Node::Int128(i) => Some(Integer::Signed(*i)),
+        Node::Int64(i) => Some(Integer::Signed(*i as i128)),
+        Node::Int32(i) => Some(Integer::Signed(*i as i128)),
+        _ => None,
+    }
+}
+
+fn to_integer<T>(rvt: Result<&Node, ConfigerError>, target: &str) -> Result<T, ConversionError>
+    where
+        T: TryFrom<i128> + TryFrom<u128>,
+{
+    let node = rvt?;
+    let integer = match node {
+        Node::String(s) => {
+            let value = s.trim();
+            match value.parse::<i128>() {
+                Ok(i) => Integer::Signed(i),
+                Err(_) => match value.parse::<u128>() {
+                    Ok(u) => Integer::Unsigned(u),
+                    Err(_) if is_integer_literal(value) => return Err(overflow(node, target)),
+                    Err(_) => return Err(invalid(node, target, format!("[{}] is not an integer", s))),
+                },
+            }
+        }
+        Node::Float64(f) => float_to_integer(node, *f, target)?,
+        Node::Float32(f) => float_to_integer(node, *f as f64, target)?,
+        _ => integer_of(node).ok_or_else(|| unsupported(node, target))?,
+    };
+
+    let converted = match integer {
+        Integer::Signed(i) => T::try_from(i).ok(),
+        Integer::Unsigned(u) => T::try_from(u).ok(),
+    };
+
+    converted.ok_or_else(|| overflow(node, target))
+}
+
+fn is_integer_literal(value: &str) -> bool {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Only integral floats within the `i128` | `u128` range are converted.
+fn float_to_integer(node: &Node, f: f64, target: &str) -> Result<Integer, ConversionError> {
+    if !f.is_finite() || f.fract() != 0.0 {
+        return Err(invalid(node, target, format!("[{}] is not an integral number", f)));
+    }
+    if f >= i128::MIN as f64 && f < i128::MAX as f64 {
+        return Ok(Integer::Signed(f as i128));
+    }
+    if f >= 0.0 && f < u128::MAX as f64 {
+        return Ok(Integer::Unsigned(f as u128));
+    }
+
+    Err(overflow(node, target))
+}
+
+/// The text of a string or a number to parse with a unit.
+fn unit_text(node: &Node, target: &str) -> Result<String, ConversionError> {
+    match node {
+        Node::String(s) => Ok(s.clone()),
+        Node::Float64(f) => Ok(f.to_string()),
+        Node::Float32(f) => Ok(f.to_string()),
+        _ => match integer_of(node) {
+            Some(Integer::Signed(i)) => Ok(i.to_string()),
+            Some(Integer::Unsigned(u)) => Ok(u.to_string()),
+            None => Err(unsupported(node, target)),
+        },
+    }
+}
+
+fn unit_error(node: &Node, target: &str, error: UnitError) -> ConversionError {
+    match error {
+        UnitError::Overflow => overflow(node, target),
+        UnitError::Invalid(message) => invalid(node, target, message),
+    }
+}
+
+fn parse_str<T: FromStr>(node: &Node, value: &str, target: &str, message: &str) -> Result<T, ConversionError> {
+    value
+        .trim()
+        .parse::<T>()
+        .map_err(|_| invalid(node, target, format!("[{}] is {}", value, message)))
+}
+
+/// Narrows a finite `f64` within the range of `f32`, the infinities and `NaN` are kept as they are.
+fn narrow_f32(node: &Node, f: f64) -> Result<f32, ConversionError> {
+    if f.is_finite() && f.abs() > f32::MAX as f64 {
+        return Err(overflow(node, "f32"));
+    }
+
+    Ok(f as f32)
+}
+
+fn unsupported(node: &Node, target: &str) -> ConversionError {
+    ConversionError::Unsupported(node.variant_name().to_string(), target.to_string())
+}
+
+fn overflow(node: &Node, target: &str) -> ConversionError {
+    ConversionError::Overflow(node.variant_name().to_string(), target.to_string())
+}
+
+fn invalid(node: &Node, target: &str, message: String) -> ConversionError {
+    ConversionError::InvalidValue(node.variant_name().to_string(), target.to_string(), message)
 }
@@ -0,0 +1,503 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::hash_map;
+use std::error::Error;
+use std::fmt;
+use std::slice;
+
+use serde::de::value::BorrowedStrDeserializer;
+use serde::de::{
+    self, Deserialize, DeserializeOwned, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess,
+    SeqAccess, VariantAccess, Visitor,
+};
+use serde::forward_to_deserialize_any;
+
+use crate::domain::keypath::KeyPath;
+use crate::domain::{Array, Node, Table};
+use crate::error::ConfigerError;
+use crate::writer::{format_date, format_datetime, format_offset_datetime, format_time};
+
+// ----------------------------------------------------------------
+
+/// Deserializes a `T` from `node`.
+///
+/// @since 0.7.0
+pub fn from_node<T: DeserializeOwned>(node: &Node) -> Result<T, ConfigerError> {
+    T::deserialize(NodeDeserializer::new(node)).map_err(ConfigerError::from)
+}
+
+/// Deserializes a `T` from `table`.
+///
+/// @since 0.7.0
+pub fn from_table<T: DeserializeOwned>(table: &Table) -> Result<T, ConfigerError> {
+    T::deserialize(NodeDeserializer::table(table, KeyPath::default())).map_err(ConfigerError::from)
+}
+
+// ----------------------------------------------------------------
+
+/// The error of [`NodeDeserializer`], converted into [`ConfigerError::BindFailed`] with the failing key path.
+///
+/// @since 0.7.0
+#[derive(Debug, PartialEq)]
+pub struct DeError {
+    path: Option<KeyPath>,
+    message: String,
+}
+
+impl DeError {
+    pub fn path(&self) -> Option<&KeyPath> {
+        self.path.as_ref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn with_path(mut self, path: &KeyPath) -> Self {
+        if self.path.is_none() {
+            self.path = Some(path.clone());
+        }
+        self
+    }
+}
+
+impl de::Error for DeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self {
+            path: None,
+            message: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.path {
+            Some(ref path) => write!(f, "key:[{}], {}", path, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for DeError {}
+
+impl From<DeError> for ConfigerError {
+    fn from(error: DeError) -> Self {
+        let path = error.path.map(|path| path.to_string()).unwrap_or_default();
+        ConfigerError::BindFailed(path, error.message)
+    }
+}
+
+// ----------------------------------------------------------------
+
+#[derive(Clone, Copy)]
+enum Value<'de> {
+    Node(&'de Node),
+    Table(&'de Table),
+    /// A table key, parsed like [`Node::String`] when a number or a boolean is requested, e.g.: `HashMap<u32, _>`.
+    Key(&'de str),
+}
+
+/// A [`Deserializer`](de::Deserializer) over a borrowed [`Node`] or [`Table`].
+///
+/// Every integer variant is widened or narrowed into the requested integer type when it fits,
+/// [`Node::DateTime`] is deserialized as an `ISO 8601` string, [`Node::None`] as `None`/`()`,
+/// and [`Node::String`] is parsed when a number or a boolean is requested, e.g.: for environment variables.
+///
+/// @since 0.7.0
+pub struct NodeDeserializer<'de> {
+    value: Value<'de>,
+    path: KeyPath,
+}
+
+impl<'de> NodeDeserializer<'de> {
+    pub fn new(node: &'de Node) -> Self {
+        Self::with_path(node, KeyPath::default())
+    }
+
+    /// Deserializes `node` that lives at `path`, errors report the path relative to it.
+    pub fn with_path(node: &'de Node, path: KeyPath) -> Self {
+        Self {
+            value: Value::Node(node),
+            path,
+        }
+    }
+
+    fn table(table: &'de Table, path: KeyPath) -> Self {
+        Self {
+            value: Value::Table(table),
+            path,
+        }
+    }
+
+    fn key(key: &'de str, path: KeyPath) -> Self {
+        Self {
+            value: Value::Key(key),
+            path,
+        }
+    }
+
+    fn as_str(&self) -> Option<&'de str> {
+        match self.value {
+            Value::Node(Node::String(value)) => Some(value),
+            Value::Key(key) => Some(key),
+            _ => None,
+        }
+    }
+
+    fn attach<T>(&self, rvt: Result<T, DeError>) -> Result<T, DeError> {
+        rvt.map_err(|error| error.with_path(&self.path))
+    }
+
+    fn parse_failed(&self, expected: &str, value: &str) -> DeError {
+        de::Error::custom(format!("invalid {} value:[{}]", expected, value))
+    }
+}
+
+// ----------------------------------------------------------------
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                if let Some(value) = self.as_str() {
+                    let parsed = value.trim().parse::<$ty>().map_err(|_| self.parse_failed(stringify!($ty), value))?;
+                    return self.attach(visitor.$visit(parsed));
+                }
+
+                self.deserialize_any(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for NodeDeserializer<'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let node = match self.value {
+            Value::Table(table) => return self.attach(visitor.visit_map(TableAccess::new(table, &self.path))),
+            Value::Key(key) => return self.attach(visitor.visit_borrowed_str(key)),
+            Value::Node(node) => node,
+        };
+
+        let rvt = match node {
+            Node::Nested(table) => visitor.visit_map(TableAccess::new(table, &self.path)),
+            Node::Array(array) => visitor.visit_seq(ArrayAccess::new(array, &self.path)),
+            Node::DateTime(datetime) => visitor.visit_string(format_datetime(datetime)),
+            Node::Date(date) => visitor.visit_string(format_date(date)),
+            Node::Time(time) => visitor.visit_string(format_time(time)),
+            Node::OffsetDateTime(datetime) => visitor.visit_string(format_offset_datetime(datetime)),
+            Node::String(value) => visitor.visit_borrowed_str(value),
+            Node::Boolean(value) => visitor.visit_bool(*value),
+            // Serde integer visitors don't all accept 128-bit values, narrow them when they fit
+            Node::IntU128(value) => match u64::try_from(*value) {
+                Ok(value) => visitor.visit_u64(value),
+                Err(_) => visitor.visit_u128(*value),
+            },
+            Node::IntU64(value) => visitor.visit_u64(*value),
+            Node::IntU32(value) => visitor.visit_u32(*value),
+            Node::Int128(value) => match i64::try_from(*value) {
+                Ok(value) => visitor.visit_i64(value),
+                Err(_) => visitor.visit_i128(*value),
+            },
+            Node::Int64(value) => visitor.visit_i64(*value),
+            Node::Int32(value) => visitor.visit_i32(*value),
+            Node::Float64(value) => visitor.visit_f64(*value),
+            Node::Float32(value) => visitor.visit_f32(*value),
+            Node::None => visitor.visit_unit(),
+        };
+
+        self.attach(rvt)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool(bool),
+        deserialize_i8 => visit_i8(i8),
+        deserialize_i16 => visit_i16(i16),
+        deserialize_i32 => visit_i32(i32),
+        deserialize_i64 => visit_i64(i64),
+        deserialize_i128 => visit_i128(i128),
+        deserialize_u8 => visit_u8(u8),
+        deserialize_u16 => visit_u16(u16),
+        deserialize_u32 => visit_u32(u32),
+        deserialize_u64 => visit_u64(u64),
+        deserialize_u128 => visit_u128(u128),
+        deserialize_f32 => visit_f32(f32),
+        deserialize_f64 => visit_f64(f64),
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Node(Node::None) => self.attach(visitor.visit_none()),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let rvt = match self.value {
+            // Unit variant
+            Value::Node(Node::String(variant)) => visitor.visit_enum(variant.as_str().into_deserializer()),
+            Value::Key(variant) => visitor.visit_enum(variant.into_deserializer()),
+            // `{ variant = content }`
+            Value::Node(Node::Nested(table)) if table.len() == 1 => {
+                let (variant, node) = table.iter().next().unwrap();
+                visitor.visit_enum(EnumDeserializer {
+                    variant,
+                    node,
+                    path: self.path.clone().with_key(variant),
+                })
+            }
+            _ => Err(de::Error::custom("expected a string or a table with a single key for an enum")),
+        };
+
+        self.attach(rvt)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        char str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier
+    }
+}
+
+// ----------------------------------------------------------------
+
+struct TableAccess<'de, 'p> {
+    entries: hash_map::Iter<'de, String, Node>,
+    value: Option<(&'de String, &'de Node)>,
+    path: &'p KeyPath,
+}
+
+impl<'de, 'p> TableAccess<'de, 'p> {
+    fn new(table: &'de Table, path: &'p KeyPath) -> Self {
+        Self {
+            entries: table.iter(),
+            value: None,
+            path,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for TableAccess<'de, '_> {
+    type Error = DeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        match self.entries.next() {
+            Some((key, node)) => {
+                self.value = Some((key, node));
+                seed.deserialize(NodeDeserializer::key(key, self.path.clone())).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        match self.value.take() {
+            Some((key, node)) => seed.deserialize(NodeDeserializer::with_path(node, self.path.clone().with_key(key))),
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+// ----------------------------------------------------------------
+
+struct ArrayAccess<'de, 'p> {
+    elements: std::iter::Enumerate<slice::Iter<'de, Node>>,
+    path: &'p KeyPath,
+}
+
+impl<'de, 'p> ArrayAccess<'de, 'p> {
+    fn new(array: &'de [Node], path: &'p KeyPath) -> Self {
+        Self {
+            elements: array.iter().enumerate(),
+            path,
+        }
+    }
+}
+
+impl<'de> SeqAccess<'de> for ArrayAccess<'de, '_> {
+    type Error = DeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> {
+        match self.elements.next() {
+            Some((index, node)) => seed
+                .deserialize(NodeDeserializer::with_path(node, self.path.clone().with_index(index)))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+// ----------------------------------------------------------------
+
+struct EnumDeserializer<'de> {
+    variant: &'de str,
+    node: &'de Node,
+    path: KeyPath,
+}
+
+impl<'de> EnumAccess<'de> for EnumDeserializer<'de> {
+    type Error = DeError;
+    type Variant = NodeDeserializer<'de>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(BorrowedStrDeserializer::new(self.variant))?;
+        Ok((variant, NodeDeserializer::with_path(self.node, self.path)))
+    }
+}
+
+impl<'de> VariantAccess<'de> for NodeDeserializer<'de> {
+    type Error = DeError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Deserializes any self-describing value into a [`Node`], every integer keeps the width it was visited with.
+///
+/// @since 0.7.0
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+    type Value = Node;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a configuration value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Node, E> {
+        Ok(Node::Boolean(v))
+    }
+
+    fn visit_i32<E: de::Error>(self, v: i32) -> Result<Node, E> {
+        Ok(Node::Int32(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Node, E> {
+        Ok(Node::Int64(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Node, E> {
+        Ok(Node::Int128(v))
+    }
+
+    fn visit_u32<E: de::Error>(self, v: u32) -> Result<Node, E> {
+        Ok(Node::IntU32(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Node, E> {
+        Ok(Node::IntU64(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Node, E> {
+        Ok(Node::IntU128(v))
+    }
+
+    fn visit_f32<E: de::Error>(self, v: f32) -> Result<Node, E> {
+        Ok(Node::Float32(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Node, E> {
+        Ok(Node::Float64(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Node, E> {
+        Ok(Node::String(v.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Node, E> {
+        Ok(Node::String(v))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Node, E> {
+        Ok(Node::None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Node, D::Error> {
+        Node::deserialize(deserializer)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Node, E> {
+        Ok(Node::None)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Node, A::Error> {
+        let mut array = Array::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(node) = seq.next_element()? {
+            array.push(node);
+        }
+
+        Ok(Node::Array(array))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Node, A::Error> {
+        let mut table = Table::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some((key, node)) = map.next_entry::<String, Node>()? {
+            table.insert(key, node);
+        }
+
+        Ok(Node::Nested(table))
+    }
+}
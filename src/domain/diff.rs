@@ -0,0 +1,311 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+use crate::domain::{Node, Table};
+use crate::domain::keypath::KeyPath;
+
+// ----------------------------------------------------------------
+
+/// The options of [`diff_with`].
+///
+/// By default, the nodes are compared as they are: `Int32(1)` and `Int64(1)` differ, and so do `0.1` and `0.1000001`.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DiffOptions {
+    numeric_equivalence: bool,
+    float_tolerance: f64,
+}
+
+impl DiffOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compares the numbers by value across the variants, e.g.: `Int32(1)`, `IntU64(1)` and `Float64(1.0)` are equal.
+    ///
+    /// A `Float32` is widened to `f64`, use a tolerance to ignore the rounding.
+    pub fn with_numeric_equivalence(mut self, numeric_equivalence: bool) -> Self {
+        self.numeric_equivalence = numeric_equivalence;
+        self
+    }
+
+    /// Two floats that differ by at most `tolerance` are equal.
+    pub fn with_float_tolerance(mut self, tolerance: f64) -> Self {
+        self.float_tolerance = tolerance.abs();
+        self
+    }
+
+    /// Whether `old` and `new` are the same value.
+    pub fn equals(&self, old: &Node, new: &Node) -> bool {
+        match (old, new) {
+            (Node::Float64(old), Node::Float64(new)) => self.floats_equal(*old, *new),
+            (Node::Float32(old), Node::Float32(new)) => self.floats_equal(*old as f64, *new as f64),
+            _ if self.numeric_equivalence => match (Number::of(old), Number::of(new)) {
+                (Some(old), Some(new)) => self.numbers_equal(old, new),
+                _ => old == new,
+            },
+            _ => old == new,
+        }
+    }
+
+    fn numbers_equal(&self, old: Number, new: Number) -> bool {
+        match (old, new) {
+            (Number::Signed(old), Number::Signed(new)) => old == new,
+            (Number::Unsigned(old), Number::Unsigned(new)) => old == new,
+            (Number::Signed(_), Number::Unsigned(_)) | (Number::Unsigned(_), Number::Signed(_)) => false,
+            (old, new) => self.floats_equal(old.as_f64(), new.as_f64()),
+        }
+    }
+
+    /// `NaN` equals `NaN`, a config that keeps it did not change.
+    fn floats_equal(&self, old: f64, new: f64) -> bool {
+        old == new || (old.is_nan() && new.is_nan()) || (old - new).abs() <= self.float_tolerance
+    }
+}
+
+/// A number, the integers are `Signed` unless they are too large for an `i128`.
+#[derive(Clone, Copy)]
+enum Number {
+    Signed(i128),
+    Unsigned(u128),
+    Float(f64),
+}
+
+impl Number {
+    fn of(node: &Node) -> Option<Self> {
+        let number = match *node {
+            Node::IntU128(value) => i128::try_from(value).map_or(Number::Unsigned(value), Number::Signed),
+            Node::IntU64(value) => Number::Signed(value as i128),
+            Node::IntU32(value) => Number::Signed(value as i128),
+            Node::Int128(value) => Number::Signed(value),
+            Node::Int64(value) => Number::Signed(value as i128),
+            Node::Int32(value) => Number::Signed(value as i128),
+            Node::Float64(value) => Number::Float(value),
+            Node::Float32(value) => Number::Float(value as f64),
+            _ => return None,
+        };
+
+        Some(number)
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Number::Signed(value) => value as f64,
+            Number::Unsigned(value) => value as f64,
+            Number::Float(value) => value,
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// The kind of a [`Change`].
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl ChangeKind {
+    /// e.g.: `added`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Changed => "changed",
+        }
+    }
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A leaf that differs between two tables, see: [`diff`].
+///
+/// The leaves are the scalars, and the empty tables and arrays,
+/// the `path` is the full key path, e.g.: `server.port` or `servers[0].host`.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Added { path: String, value: Node },
+    Removed { path: String, value: Node },
+    Changed { path: String, old: Node, new: Node },
+}
+
+impl Change {
+    pub fn path(&self) -> &str {
+        match self {
+            Change::Added { path, .. } | Change::Removed { path, .. } | Change::Changed { path, .. } => path,
+        }
+    }
+
+    pub fn kind(&self) -> ChangeKind {
+        match self {
+            Change::Added { .. } => ChangeKind::Added,
+            Change::Removed { .. } => ChangeKind::Removed,
+            Change::Changed { .. } => ChangeKind::Changed,
+        }
+    }
+
+    /// `None` for an added leaf.
+    pub fn old_value(&self) -> Option<&Node> {
+        match self {
+            Change::Added { .. } => None,
+            Change::Removed { value, .. } => Some(value),
+            Change::Changed { old, .. } => Some(old),
+        }
+    }
+
+    /// `None` for a removed leaf.
+    pub fn new_value(&self) -> Option<&Node> {
+        match self {
+            Change::Added { value, .. } => Some(value),
+            Change::Removed { .. } => None,
+            Change::Changed { new, .. } => Some(new),
+        }
+    }
+
+    /// The machine-readable form, e.g.: `{ kind = "changed", path = "server.port", old = 8080, new = 9090 }`,
+    /// that any [`ConfigWriter`](crate::writer::ConfigWriter) can write, `old` or `new` is left out when there is none.
+    pub fn to_node(&self) -> Node {
+        let mut table = Table::new();
+        table.insert("kind".to_string(), Node::String(self.kind().as_str().to_string()));
+        table.insert("path".to_string(), Node::String(self.path().to_string()));
+        if let Some(old) = self.old_value() {
+            table.insert("old".to_string(), old.clone());
+        }
+        if let Some(new) = self.new_value() {
+            table.insert("new".to_string(), new.clone());
+        }
+
+        Node::Nested(table)
+    }
+}
+
+/// e.g.: `+ server.host = String("localhost")`, `- log.file = String("app.log")` or `~ server.port: Int64(8080) => Int64(9090)`.
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Added { path, value } => write!(f, "+ {} = {:?}", path, value),
+            Change::Removed { path, value } => write!(f, "- {} = {:?}", path, value),
+            Change::Changed { path, old, new } => write!(f, "~ {}: {:?} => {:?}", path, old, new),
+        }
+    }
+}
+
+/// Serializes [`Change::to_node`].
+#[cfg(feature = "serde")]
+impl Serialize for Change {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_node().serialize(serializer)
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// The leaves added, removed and changed from `old` to `new`, sorted by their key paths.
+///
+/// The tables are walked by key and the arrays by index, e.g.: a longer array adds `hosts[2]`.
+///
+/// @since 0.7.0
+pub fn diff(old: &Table, new: &Table) -> Vec<Change> {
+    diff_with(old, new, &DiffOptions::default())
+}
+
+/// The same as [`diff`], the leaves are compared by `options`.
+///
+/// @since 0.7.0
+pub fn diff_with(old: &Table, new: &Table, options: &DiffOptions) -> Vec<Change> {
+    changes_between(leaves(old), leaves(new), options)
+}
+
+/// The changes of the leaves at or under `path`, from the `old` to the `new` node.
+pub(crate) fn diff_nodes_at(path: &KeyPath, old: Option<&Node>, new: Option<&Node>, options: &DiffOptions) -> Vec<Change> {
+    changes_between(leaves_at(path, old), leaves_at(path, new), options)
+}
+
+fn changes_between(old_leaves: BTreeMap<String, &Node>, mut new_leaves: BTreeMap<String, &Node>, options: &DiffOptions) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for (path, old) in old_leaves {
+        match new_leaves.remove(&path) {
+            Some(new) if options.equals(old, new) => {}
+            Some(new) => changes.push(Change::Changed {
+                path,
+                old: old.clone(),
+                new: new.clone(),
+            }),
+            None => changes.push(Change::Removed { path, value: old.clone() }),
+        }
+    }
+    changes.extend(new_leaves.into_iter().map(|(path, value)| Change::Added { path, value: value.clone() }));
+    // By the segments, e.g.: `hosts[2]` before `hosts[10]`
+    changes.sort_by_cached_key(|change| change.path().parse::<KeyPath>().ok());
+
+    changes
+}
+
+/// The leaves of `table` by their key path, e.g.: `server.port` or `servers[0].host`.
+pub(crate) fn leaves(table: &Table) -> BTreeMap<String, &Node> {
+    let mut leaves = BTreeMap::new();
+    for (key, node) in table {
+        collect_leaves(KeyPath::default().with_key(key), node, &mut leaves);
+    }
+
+    leaves
+}
+
+fn leaves_at<'a>(path: &KeyPath, node: Option<&'a Node>) -> BTreeMap<String, &'a Node> {
+    let mut leaves = BTreeMap::new();
+    if let Some(node) = node {
+        collect_leaves(path.clone(), node, &mut leaves);
+    }
+
+    leaves
+}
+
+fn collect_leaves<'a>(path: KeyPath, node: &'a Node, leaves: &mut BTreeMap<String, &'a Node>) {
+    match node {
+        Node::Nested(table) if !table.is_empty() => {
+            for (key, child) in table {
+                collect_leaves(path.clone().with_key(key), child, leaves);
+            }
+        }
+        Node::Array(array) if !array.is_empty() => {
+            for (index, child) in array.iter().enumerate() {
+                collect_leaves(path.clone().with_index(index), child, leaves);
+            }
+        }
+        _ => {
+            leaves.insert(path.to_string(), node);
+        }
+    }
+}
@@ -0,0 +1,299 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::borrow::Cow;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+use crate::error::ConfigerError;
+
+// ----------------------------------------------------------------
+
+const DOT: char = '.';
+const QUOTE: char = '"';
+const BACKSLASH: char = '\\';
+const OPEN_BRACKET: char = '[';
+const CLOSE_BRACKET: char = ']';
+const APPEND: &str = "+";
+
+// ----------------------------------------------------------------
+
+/// A segment of a [`KeyPath`], e.g.: `servers[1].host` => `servers`, `[1]`, `host`.
+///
+/// Ordered by the keys, then the indexes numerically, e.g.: `[2]` before `[10]`.
+///
+/// @since 0.7.0
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub enum Segment {
+    /// The key of a nested table.
+    Key(String),
+    /// `[n]`, the index of an array element.
+    Index(usize),
+    /// `[+]`, appends to an array, only valid when setting.
+    Append,
+}
+
+// ----------------------------------------------------------------
+
+/// A parsed key path, accepted by [`ConfigerEnvironment::set_key/get_key`](crate::env::standard::ConfigerEnvironment::set_key) via [`ToKeyPath`],
+/// the [`Environment`](crate::env::Environment) `set/get` take the same grammar as a `&str`.
+///
+/// The textual grammar:
+///
+/// - `.` separates the keys of nested tables, e.g.: `io.github.photowey`.
+/// - A key may be double-quoted to contain `.`, `[` and `]`, e.g.: `log.level."my.crate"`.
+///   `\"` and `\\` are escapes inside quotes.
+/// - Outside quotes, `\` escapes the next character, e.g.: `log.level.my\.crate`.
+/// - A key may be followed by any number of `[n]` indexes or a `[+]` append, e.g.: `servers[1].host`.
+///
+/// Use [`KeyPath::from_segments`] to build a path from raw keys without any escaping.
+///
+/// @since 0.7.0
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Default)]
+pub struct KeyPath {
+    segments: Vec<Segment>,
+}
+
+impl KeyPath {
+    pub fn new(segments: Vec<Segment>) -> Self {
+        Self { segments }
+    }
+
+    /// Builds a path of nested table keys, every key is taken literally.
+    pub fn from_segments<S: AsRef<str>>(keys: &[S]) -> Self {
+        Self::new(keys.iter().map(|key| Segment::Key(key.as_ref().to_string())).collect())
+    }
+
+    pub fn parse(key: &str) -> Result<Self, ConfigerError> {
+        KeyPathParser::new(key).parse()
+    }
+
+    pub fn with_key(mut self, key: &str) -> Self {
+        self.segments.push(Segment::Key(key.to_string()));
+        self
+    }
+
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.segments.push(Segment::Index(index));
+        self
+    }
+
+    pub fn with_append(mut self) -> Self {
+        self.segments.push(Segment::Append);
+        self
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl FromStr for KeyPath {
+    type Err = ConfigerError;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        KeyPath::parse(key)
+    }
+}
+
+impl From<&[&str]> for KeyPath {
+    fn from(keys: &[&str]) -> Self {
+        KeyPath::from_segments(keys)
+    }
+}
+
+impl From<Vec<Segment>> for KeyPath {
+    fn from(segments: Vec<Segment>) -> Self {
+        KeyPath::new(segments)
+    }
+}
+
+/// Writes the textual form, [`KeyPath::parse`] reads it back into an equal path.
+impl fmt::Display for KeyPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, segment) in self.segments.iter().enumerate() {
+            match segment {
+                Segment::Key(key) => {
+                    if index > 0 {
+                        write!(f, "{}", DOT)?;
+                    }
+                    write_key(f, key)?;
+                }
+                Segment::Index(index) => write!(f, "[{}]", index)?,
+                Segment::Append => write!(f, "[{}]", APPEND)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn write_key(f: &mut fmt::Formatter, key: &str) -> fmt::Result {
+    let plain = !key.is_empty() && !key.contains([DOT, QUOTE, BACKSLASH, OPEN_BRACKET, CLOSE_BRACKET]);
+    if plain {
+        return write!(f, "{}", key);
+    }
+
+    write!(f, "{}", QUOTE)?;
+    for c in key.chars() {
+        if c == QUOTE || c == BACKSLASH {
+            write!(f, "{}", BACKSLASH)?;
+        }
+        write!(f, "{}", c)?;
+    }
+    write!(f, "{}", QUOTE)
+}
+
+// ----------------------------------------------------------------
+
+/// Converts a value into a [`KeyPath`], implemented for `str`, `String` and [`KeyPath`] itself.
+///
+/// @since 0.7.0
+pub trait ToKeyPath {
+    fn to_key_path(&self) -> Result<Cow<'_, KeyPath>, ConfigerError>;
+}
+
+impl ToKeyPath for str {
+    fn to_key_path(&self) -> Result<Cow<'_, KeyPath>, ConfigerError> {
+        KeyPath::parse(self).map(Cow::Owned)
+    }
+}
+
+impl ToKeyPath for String {
+    fn to_key_path(&self) -> Result<Cow<'_, KeyPath>, ConfigerError> {
+        self.as_str().to_key_path()
+    }
+}
+
+impl ToKeyPath for KeyPath {
+    fn to_key_path(&self) -> Result<Cow<'_, KeyPath>, ConfigerError> {
+        Ok(Cow::Borrowed(self))
+    }
+}
+
+impl<T: ToKeyPath + ?Sized> ToKeyPath for &T {
+    fn to_key_path(&self) -> Result<Cow<'_, KeyPath>, ConfigerError> {
+        (**self).to_key_path()
+    }
+}
+
+// ----------------------------------------------------------------
+
+struct KeyPathParser<'a> {
+    key: &'a str,
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> KeyPathParser<'a> {
+    fn new(key: &'a str) -> Self {
+        Self {
+            key,
+            chars: key.chars().peekable(),
+        }
+    }
+
+    fn invalid_key(&self) -> ConfigerError {
+        ConfigerError::InvalidKey(self.key.to_string())
+    }
+
+    fn parse(mut self) -> Result<KeyPath, ConfigerError> {
+        let mut segments = Vec::new();
+        if self.key.is_empty() {
+            return Ok(KeyPath::new(segments));
+        }
+
+        loop {
+            let (key, quoted) = self.parse_key()?;
+            if key.is_empty() && !quoted && self.chars.peek() == Some(&OPEN_BRACKET) {
+                return Err(self.invalid_key());
+            }
+            segments.push(Segment::Key(key));
+
+            while self.chars.peek() == Some(&OPEN_BRACKET) {
+                self.chars.next();
+                segments.push(self.parse_index()?);
+            }
+
+            match self.chars.next() {
+                None => break,
+                Some(DOT) => continue,
+                Some(_) => return Err(self.invalid_key()),
+            }
+        }
+
+        Ok(KeyPath::new(segments))
+    }
+
+    fn parse_key(&mut self) -> Result<(String, bool), ConfigerError> {
+        let mut key = String::new();
+
+        if self.chars.peek() == Some(&QUOTE) {
+            self.chars.next();
+            while let Some(c) = self.chars.next() {
+                match c {
+                    QUOTE => return Ok((key, true)),
+                    BACKSLASH => key.push(self.chars.next().ok_or_else(|| self.invalid_key())?),
+                    _ => key.push(c),
+                }
+            }
+
+            return Err(self.invalid_key());
+        }
+
+        while let Some(&c) = self.chars.peek() {
+            match c {
+                DOT | OPEN_BRACKET => break,
+                QUOTE | CLOSE_BRACKET => return Err(self.invalid_key()),
+                BACKSLASH => {
+                    self.chars.next();
+                    key.push(self.chars.next().ok_or_else(|| self.invalid_key())?);
+                }
+                _ => {
+                    self.chars.next();
+                    key.push(c);
+                }
+            }
+        }
+
+        Ok((key, false))
+    }
+
+    fn parse_index(&mut self) -> Result<Segment, ConfigerError> {
+        let mut index = String::new();
+        loop {
+            match self.chars.next() {
+                Some(CLOSE_BRACKET) => break,
+                Some(c) => index.push(c),
+                None => return Err(self.invalid_key()),
+            }
+        }
+
+        if index == APPEND {
+            return Ok(Segment::Append);
+        }
+
+        index.parse::<usize>().map(Segment::Index).map_err(|_| self.invalid_key())
+    }
+}
@@ -0,0 +1,253 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::mem;
+
+use crate::domain::keypath::{KeyPath, ToKeyPath};
+use crate::domain::{Array, Node, Table};
+use crate::error::ConfigerError;
+
+// ----------------------------------------------------------------
+
+/// The conventional tombstone, a higher layer sets a key to it to remove the key from the lower layers,
+/// opt in by [`MergeOptions::with_tombstone`], e.g.: `with_tombstone(Some(Node::String(TOMBSTONE.to_string())))`.
+///
+/// As an array element, it is dropped, with [`ArrayStrategy::MergeByIndex`] it removes the element at its index.
+pub const TOMBSTONE: &str = "__delete__";
+
+// ----------------------------------------------------------------
+
+/// How the arrays at the same key are merged.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArrayStrategy {
+    /// The higher layer's array replaces the lower one.
+    Replace,
+    /// The higher layer's elements are appended, the same as [`merge_tables`](crate::domain::merge_tables).
+    #[default]
+    Append,
+    /// The higher layer's elements are prepended.
+    Prepend,
+    /// The higher layer's elements that aren't in the lower array yet are appended.
+    DedupeAppend,
+    /// The elements at the same index are merged, extra elements are appended.
+    MergeByIndex,
+}
+
+/// What happens when the nodes at the same key are of different kinds, e.g.: a table and a string.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictPolicy {
+    /// The higher layer's node wins.
+    #[default]
+    Override,
+    /// Fails with [`ConfigerError::MergeConflict`].
+    Error,
+}
+
+// ----------------------------------------------------------------
+
+/// The options of [`merge_tables_with`].
+///
+/// By default, arrays are appended, the higher layer wins on conflicts, and there's no tombstone,
+/// so no value, e.g.: `"__delete__"` in a file or an environment variable, removes a key by surprise.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeOptions {
+    array_strategy: ArrayStrategy,
+    /// Keyed by the [`KeyPath`] string.
+    array_strategies: HashMap<String, ArrayStrategy>,
+    conflict_policy: ConflictPolicy,
+    tombstone: Option<Node>,
+}
+
+impl MergeOptions {
+    pub fn new() -> Self {
+        Self {
+            array_strategy: ArrayStrategy::default(),
+            array_strategies: HashMap::new(),
+            conflict_policy: ConflictPolicy::default(),
+            tombstone: None,
+        }
+    }
+
+    /// The strategy for every array without a strategy of its own.
+    pub fn with_array_strategy(mut self, strategy: ArrayStrategy) -> Self {
+        self.array_strategy = strategy;
+        self
+    }
+
+    /// The strategy for the array at `key`, e.g.: `server.allowed_hosts`, an invalid `key` is ignored.
+    pub fn with_array_strategy_at<K: ToKeyPath + ?Sized>(mut self, key: &K, strategy: ArrayStrategy) -> Self {
+        if let Ok(path) = key.to_key_path() {
+            self.array_strategies.insert(path.to_string(), strategy);
+        }
+        self
+    }
+
+    pub fn with_conflict_policy(mut self, policy: ConflictPolicy) -> Self {
+        self.conflict_policy = policy;
+        self
+    }
+
+    /// The node that removes a key, e.g.: [`TOMBSTONE`], `None` by default disables the removal.
+    pub fn with_tombstone(mut self, tombstone: Option<Node>) -> Self {
+        self.tombstone = tombstone;
+        self
+    }
+
+    pub fn array_strategy_at(&self, path: &KeyPath) -> ArrayStrategy {
+        self.array_strategies
+            .get(&path.to_string())
+            .copied()
+            .unwrap_or(self.array_strategy)
+    }
+
+    fn is_tombstone(&self, node: &Node) -> bool {
+        self.tombstone.as_ref() == Some(node)
+    }
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Merges `src` into `dst` by `options`, `src` is the higher layer.
+///
+/// The tombstones in `src` remove their keys from `dst`. A tombstone without a key beneath it is kept,
+/// so that the layers can be merged in any grouping, use [`remove_tombstones`] on the final table.
+///
+/// @since 0.7.0
+pub fn merge_tables_with(dst: Table, src: Table, options: &MergeOptions) -> Result<Table, ConfigerError> {
+    merge_table_at(dst, src, options, &KeyPath::default())
+}
+
+fn merge_table_at(mut dst: Table, src: Table, options: &MergeOptions, path: &KeyPath) -> Result<Table, ConfigerError> {
+    for (key, src_node) in src {
+        if options.is_tombstone(&src_node) {
+            if dst.remove(&key).is_none() {
+                dst.insert(key, src_node);
+            }
+            continue;
+        }
+
+        let child_path = path.clone().with_key(&key);
+        let merged = merge_node_at(dst.remove(&key), src_node, options, &child_path)?;
+        dst.insert(key, merged);
+    }
+
+    Ok(dst)
+}
+
+fn merge_node_at(dst: Option<Node>, src: Node, options: &MergeOptions, path: &KeyPath) -> Result<Node, ConfigerError> {
+    match (dst, src) {
+        (Some(Node::Nested(dst_nested)), Node::Nested(src_nested)) => {
+            Ok(Node::Nested(merge_table_at(dst_nested, src_nested, options, path)?))
+        }
+        (Some(Node::Array(dst_array)), Node::Array(src_array)) => {
+            Ok(Node::Array(merge_arrays(dst_array, src_array, options, path)?))
+        }
+        (Some(dst_node), src_node) if options.conflict_policy == ConflictPolicy::Error && conflicts(&dst_node, &src_node) => {
+            Err(ConfigerError::MergeConflict(
+                path.to_string(),
+                dst_node.variant_name().to_string(),
+                src_node.variant_name().to_string(),
+            ))
+        }
+        (_, src_node) => Ok(src_node),
+    }
+}
+
+fn merge_arrays(mut dst: Array, src: Array, options: &MergeOptions, path: &KeyPath) -> Result<Array, ConfigerError> {
+    match options.array_strategy_at(path) {
+        ArrayStrategy::Replace => Ok(src),
+        ArrayStrategy::Append => {
+            dst.extend(src);
+            Ok(dst)
+        }
+        ArrayStrategy::Prepend => {
+            let mut merged = src;
+            merged.extend(dst);
+            Ok(merged)
+        }
+        ArrayStrategy::DedupeAppend => {
+            for node in src {
+                if !dst.contains(&node) {
+                    dst.push(node);
+                }
+            }
+            Ok(dst)
+        }
+        ArrayStrategy::MergeByIndex => {
+            for (index, src_node) in src.into_iter().enumerate() {
+                let element_path = path.clone().with_index(index);
+                match dst.get_mut(index) {
+                    Some(dst_node) => *dst_node = merge_node_at(Some(mem::take(dst_node)), src_node, options, &element_path)?,
+                    None => dst.push(merge_node_at(None, src_node, options, &element_path)?),
+                }
+            }
+            Ok(dst)
+        }
+    }
+}
+
+/// Removes the tombstones left in `table` by [`merge_tables_with`], the keys and the array elements.
+///
+/// @since 0.7.0
+pub fn remove_tombstones(table: &mut Table, options: &MergeOptions) {
+    table.retain(|_, node| !options.is_tombstone(node));
+    for node in table.values_mut() {
+        remove_tombstones_in(node, options);
+    }
+}
+
+fn remove_tombstones_in(node: &mut Node, options: &MergeOptions) {
+    match node {
+        Node::Nested(table) => remove_tombstones(table, options),
+        Node::Array(array) => {
+            array.retain(|element| !options.is_tombstone(element));
+            array.iter_mut().for_each(|element| remove_tombstones_in(element, options));
+        }
+        _ => {}
+    }
+}
+
+/// Tables, arrays and scalars are different kinds, [`Node::None`] never conflicts.
+fn conflicts(dst: &Node, src: &Node) -> bool {
+    fn kind(node: &Node) -> Option<u8> {
+        match node {
+            Node::None => None,
+            Node::Nested(_) => Some(0),
+            Node::Array(_) => Some(1),
+            _ => Some(2),
+        }
+    }
+
+    match (kind(dst), kind(src)) {
+        (Some(dst_kind), Some(src_kind)) => dst_kind != src_kind,
+        _ => false,
+    }
+}
@@ -0,0 +1,339 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use crate::domain::{Node, Table};
+use crate::domain::diff::DiffOptions;
+#[cfg(feature = "usejson")]
+use crate::reader::json::parse_json_node;
+use crate::error::ConfigerError;
+
+// ----------------------------------------------------------------
+
+const APPEND_TOKEN: &str = "-";
+
+// ----------------------------------------------------------------
+
+/// Applies an `RFC 7386` JSON Merge Patch to `target`.
+///
+/// - A [`Node::None`], i.e.: a `JSON` `null`, removes the key.
+/// - A table is merged into the table at the same key, recursively, anything else at that key is replaced.
+/// - Any other node, arrays included, replaces the node at the same key.
+///
+/// @since 0.7.0
+pub fn apply_merge_patch(target: &mut Table, patch: Table) {
+    for (key, value) in patch {
+        match value {
+            Node::None => {
+                target.remove(&key);
+            }
+            Node::Nested(nested) => {
+                let node = target.entry(key).or_insert_with(|| Node::Nested(Table::new()));
+                if !matches!(node, Node::Nested(_)) {
+                    *node = Node::Nested(Table::new());
+                }
+                if let Node::Nested(table) = node {
+                    apply_merge_patch(table, nested);
+                }
+            }
+            value => {
+                target.insert(key, value);
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// An `RFC 6902` JSON Patch operation, the paths are `RFC 6901` JSON Pointers, e.g.: `/servers/0/host`.
+///
+/// A pointer token is a key of a [`Node::Nested`] or an index of a [`Node::Array`],
+/// `-` is the end of an array, e.g.: `add` `/hosts/-` appends.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchOp {
+    /// Sets the value, inserts into an array.
+    Add { path: String, value: Node },
+    Remove { path: String },
+    /// Sets the value, which must exist.
+    Replace { path: String, value: Node },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    /// Fails unless the value equals `value`, the numbers are compared by value, e.g.: `Int32(1)` == `Int64(1)`.
+    Test { path: String, value: Node },
+}
+
+impl PatchOp {
+    /// The target pointer.
+    pub fn path(&self) -> &str {
+        match self {
+            PatchOp::Add { path, .. }
+            | PatchOp::Remove { path }
+            | PatchOp::Replace { path, .. }
+            | PatchOp::Move { path, .. }
+            | PatchOp::Copy { path, .. }
+            | PatchOp::Test { path, .. } => path,
+        }
+    }
+}
+
+/// Reads an operation object, e.g.: `{ "op": "replace", "path": "/server/port", "value": 9090 }`.
+impl TryFrom<&Node> for PatchOp {
+    type Error = ConfigerError;
+
+    fn try_from(node: &Node) -> Result<Self, Self::Error> {
+        let table = match node {
+            Node::Nested(table) => table,
+            _ => return Err(ConfigerError::InvalidPatch(format!("an operation must be an object, found {}", node.variant_name()))),
+        };
+
+        let string = |name: &str| match table.get(name) {
+            Some(Node::String(value)) => Ok(value.clone()),
+            _ => Err(ConfigerError::InvalidPatch(format!("missing string member `{}`", name))),
+        };
+        let value = || {
+            table
+                .get("value")
+                .cloned()
+                .ok_or_else(|| ConfigerError::InvalidPatch("missing member `value`".to_string()))
+        };
+
+        match string("op")?.as_str() {
+            "add" => Ok(PatchOp::Add { path: string("path")?, value: value()? }),
+            "remove" => Ok(PatchOp::Remove { path: string("path")? }),
+            "replace" => Ok(PatchOp::Replace { path: string("path")?, value: value()? }),
+            "move" => Ok(PatchOp::Move { from: string("from")?, path: string("path")? }),
+            "copy" => Ok(PatchOp::Copy { from: string("from")?, path: string("path")? }),
+            "test" => Ok(PatchOp::Test { path: string("path")?, value: value()? }),
+            op => Err(ConfigerError::InvalidPatch(format!("unknown op `{}`", op))),
+        }
+    }
+}
+
+/// Reads a JSON Patch document, an array of operation objects.
+///
+/// @since 0.7.0
+pub fn parse_json_patch(document: &Node) -> Result<Vec<PatchOp>, ConfigerError> {
+    match document {
+        Node::Array(ops) => ops.iter().map(PatchOp::try_from).collect(),
+        _ => Err(ConfigerError::InvalidPatch(format!("a patch must be an array, found {}", document.variant_name()))),
+    }
+}
+
+/// Reads a JSON Patch document from its `JSON` text.
+///
+/// @since 0.7.0
+#[cfg(feature = "usejson")]
+pub fn parse_json_patch_str(data: &str) -> Result<Vec<PatchOp>, ConfigerError> {
+    let document = parse_json_node(data).map_err(|err| ConfigerError::InvalidPatch(err.to_string()))?;
+    parse_json_patch(&document)
+}
+
+// ----------------------------------------------------------------
+
+/// Applies the `RFC 6902` JSON Patch `ops` to `target`, in order, all or nothing.
+///
+/// On failure, e.g.: a `test` that doesn't match or a missing path, `target` is left untouched.
+///
+/// @since 0.7.0
+pub fn apply_json_patch(target: &mut Table, ops: &[PatchOp]) -> Result<(), ConfigerError> {
+    let mut root = Node::Nested(target.clone());
+    for op in ops {
+        apply(&mut root, op)?;
+    }
+
+    if let Node::Nested(table) = root {
+        *target = table;
+    }
+
+    Ok(())
+}
+
+/// Splits an `RFC 6901` JSON Pointer into its unescaped tokens, `""` is the whole table.
+pub(crate) fn parse_pointer(pointer: &str) -> Result<Vec<String>, ConfigerError> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rest = pointer
+        .strip_prefix('/')
+        .ok_or_else(|| ConfigerError::InvalidPointer(pointer.to_string()))?;
+
+    rest.split('/')
+        .map(|token| {
+            let escapes_valid = token.match_indices('~').all(|(index, _)| matches!(token.as_bytes().get(index + 1), Some(b'0' | b'1')));
+            if !escapes_valid {
+                return Err(ConfigerError::InvalidPointer(pointer.to_string()));
+            }
+
+            Ok(token.replace("~1", "/").replace("~0", "~"))
+        })
+        .collect()
+}
+
+fn apply(root: &mut Node, op: &PatchOp) -> Result<(), ConfigerError> {
+    match op {
+        PatchOp::Add { path, value } => add(root, &parse_pointer(path)?, value.clone()).map_err(at(path)),
+        PatchOp::Remove { path } => remove(root, &parse_pointer(path)?).map(drop).map_err(at(path)),
+        PatchOp::Replace { path, value } => replace(root, &parse_pointer(path)?, value.clone()).map_err(at(path)),
+        PatchOp::Move { from, path } => {
+            let from_tokens = parse_pointer(from)?;
+            let path_tokens = parse_pointer(path)?;
+            if path_tokens.len() > from_tokens.len() && path_tokens.starts_with(&from_tokens) {
+                return Err(ConfigerError::InvalidPatch(format!("can't move {} into its own child {}", from, path)));
+            }
+
+            let value = remove(root, &from_tokens).map_err(at(from))?;
+            add(root, &path_tokens, value).map_err(at(path))
+        }
+        PatchOp::Copy { from, path } => {
+            let value = pointee(root, &parse_pointer(from)?).map_err(at(from))?.clone();
+            add(root, &parse_pointer(path)?, value).map_err(at(path))
+        }
+        PatchOp::Test { path, value } => {
+            let actual = pointee(root, &parse_pointer(path)?).map_err(at(path))?;
+            if !same_value(actual, value) {
+                return Err(ConfigerError::PatchTestFailed(path.clone()));
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Wraps the error of the operation at `pointer`.
+fn at(pointer: &str) -> impl FnOnce(ConfigerError) -> ConfigerError + '_ {
+    move |err| ConfigerError::PatchFailed(pointer.to_string(), Box::new(err))
+}
+
+fn add(root: &mut Node, tokens: &[String], value: Node) -> Result<(), ConfigerError> {
+    let (last, parents) = match tokens.split_last() {
+        Some(split) => split,
+        None => return replace_root(root, value),
+    };
+
+    match pointee_mut(root, parents)? {
+        Node::Nested(table) => {
+            table.insert(last.clone(), value);
+            Ok(())
+        }
+        Node::Array(array) => {
+            let index = match last.as_str() {
+                APPEND_TOKEN => array.len(),
+                token => array_index(token)?,
+            };
+            if index > array.len() {
+                return Err(ConfigerError::IndexOutOfBounds(index, array.len()));
+            }
+
+            array.insert(index, value);
+            Ok(())
+        }
+        _ => Err(ConfigerError::NonNested),
+    }
+}
+
+fn remove(root: &mut Node, tokens: &[String]) -> Result<Node, ConfigerError> {
+    // The whole table can only be replaced
+    let (last, parents) = tokens.split_last().ok_or(ConfigerError::EmptyKey)?;
+
+    match pointee_mut(root, parents)? {
+        Node::Nested(table) => table.remove(last).ok_or(ConfigerError::NotFound),
+        Node::Array(array) => {
+            let index = match last.as_str() {
+                APPEND_TOKEN => array.len(),
+                token => array_index(token)?,
+            };
+            if index >= array.len() {
+                return Err(ConfigerError::IndexOutOfBounds(index, array.len()));
+            }
+
+            Ok(array.remove(index))
+        }
+        _ => Err(ConfigerError::NonNested),
+    }
+}
+
+fn replace(root: &mut Node, tokens: &[String], value: Node) -> Result<(), ConfigerError> {
+    if tokens.is_empty() {
+        return replace_root(root, value);
+    }
+
+    *pointee_mut(root, tokens)? = value;
+    Ok(())
+}
+
+/// The whole table may only be replaced by another table.
+fn replace_root(root: &mut Node, value: Node) -> Result<(), ConfigerError> {
+    match value {
+        Node::Nested(_) => {
+            *root = value;
+            Ok(())
+        }
+        _ => Err(ConfigerError::NonNested),
+    }
+}
+
+fn pointee<'a>(root: &'a Node, tokens: &[String]) -> Result<&'a Node, ConfigerError> {
+    tokens.iter().try_fold(root, |node, token| match node {
+        Node::Nested(table) => table.get(token).ok_or(ConfigerError::NotFound),
+        Node::Array(array) => {
+            let index = array_index(token)?;
+            array.get(index).ok_or(ConfigerError::IndexOutOfBounds(index, array.len()))
+        }
+        _ => Err(ConfigerError::NonNested),
+    })
+}
+
+fn pointee_mut<'a>(root: &'a mut Node, tokens: &[String]) -> Result<&'a mut Node, ConfigerError> {
+    tokens.iter().try_fold(root, |node, token| match node {
+        Node::Nested(table) => table.get_mut(token).ok_or(ConfigerError::NotFound),
+        Node::Array(array) => {
+            let index = array_index(token)?;
+            let len = array.len();
+            array.get_mut(index).ok_or(ConfigerError::IndexOutOfBounds(index, len))
+        }
+        _ => Err(ConfigerError::NonNested),
+    })
+}
+
+/// `0`, or digits without a leading zero, `-` is past the end.
+fn array_index(token: &str) -> Result<usize, ConfigerError> {
+    let digits = !token.is_empty() && token.bytes().all(|byte| byte.is_ascii_digit());
+    if !digits || (token.len() > 1 && token.starts_with('0')) {
+        return Err(ConfigerError::InvalidKey(token.to_string()));
+    }
+
+    token.parse().map_err(|_| ConfigerError::InvalidKey(token.to_string()))
+}
+
+/// Equal by value, the numbers across the variants too, as `JSON` has a single number type.
+fn same_value(actual: &Node, expected: &Node) -> bool {
+    match (actual, expected) {
+        (Node::Nested(actual), Node::Nested(expected)) => {
+            actual.len() == expected.len()
+                && actual
+                    .iter()
+                    .all(|(key, node)| expected.get(key).is_some_and(|other| same_value(node, other)))
+        }
+        (Node::Array(actual), Node::Array(expected)) => {
+            actual.len() == expected.len() && actual.iter().zip(expected).all(|(node, other)| same_value(node, other))
+        }
+        _ => DiffOptions::new().with_numeric_equivalence(true).equals(actual, expected),
+    }
+}
@@ -0,0 +1,478 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::error::Error;
+use std::fmt;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::ser::{
+    self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant, Serializer,
+};
+
+use crate::domain::{Array, Node, Table};
+use crate::error::ConfigerError;
+use crate::writer::{format_date, format_datetime, format_offset_datetime, format_time, DATE_PATTERN, DATETIME_PATTERN, TIME_PATTERN};
+
+// ----------------------------------------------------------------
+
+/// Serializes `value` into a [`Node`].
+///
+/// @since 0.7.0
+pub fn to_node<T: Serialize + ?Sized>(value: &T) -> Result<Node, ConfigerError> {
+    value.serialize(NodeSerializer).map_err(ConfigerError::from)
+}
+
+/// Serializes `value` into a [`Table`], `value` must serialize as a struct or a map.
+///
+/// @since 0.7.0
+pub fn to_table<T: Serialize + ?Sized>(value: &T) -> Result<Table, ConfigerError> {
+    match to_node(value)? {
+        Node::Nested(table) => Ok(table),
+        _ => Err(ConfigerError::SerializeFailed("expected a struct or a map".to_string())),
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Builds a [`Table`] from any `T: Serialize`, e.g.: defaults as the lowest-precedence layer.
+///
+/// ```rust,ignore
+/// let defaults = Table::from_serialize(&AppConfig::default())?;
+/// let configer = ConfigerEnvironment::builder().with_table(defaults).build()?;
+/// ```
+///
+/// @since 0.7.0
+pub trait FromSerialize: Sized {
+    fn from_serialize<T: Serialize + ?Sized>(value: &T) -> Result<Self, ConfigerError>;
+}
+
+impl FromSerialize for Table {
+    fn from_serialize<T: Serialize + ?Sized>(value: &T) -> Result<Self, ConfigerError> {
+        to_table(value)
+    }
+}
+
+impl FromSerialize for Node {
+    fn from_serialize<T: Serialize + ?Sized>(value: &T) -> Result<Self, ConfigerError> {
+        to_node(value)
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// The error of [`NodeSerializer`], converted into [`ConfigerError::SerializeFailed`].
+///
+/// @since 0.7.0
+#[derive(Debug, PartialEq)]
+pub struct SerError(String);
+
+impl ser::Error for SerError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        SerError(msg.to_string())
+    }
+}
+
+impl fmt::Display for SerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for SerError {}
+
+impl From<SerError> for ConfigerError {
+    fn from(error: SerError) -> Self {
+        ConfigerError::SerializeFailed(error.0)
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Serializes [`Node`] the way [`NodeSerializer`] reads it back, [`Node::DateTime`] as an `ISO 8601` string.
+///
+/// @since 0.7.0
+impl Serialize for Node {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Node::Nested(table) => serializer.collect_map(table),
+            Node::Array(array) => serializer.collect_seq(array),
+            Node::DateTime(datetime) => serializer.collect_str(&format_datetime(datetime)),
+            Node::Date(date) => serializer.collect_str(&format_date(date)),
+            Node::Time(time) => serializer.collect_str(&format_time(time)),
+            Node::OffsetDateTime(datetime) => serializer.collect_str(&format_offset_datetime(datetime)),
+            Node::String(value) => serializer.serialize_str(value),
+            Node::Boolean(value) => serializer.serialize_bool(*value),
+            Node::IntU128(value) => serializer.serialize_u128(*value),
+            Node::IntU64(value) => serializer.serialize_u64(*value),
+            Node::IntU32(value) => serializer.serialize_u32(*value),
+            Node::Int128(value) => serializer.serialize_i128(*value),
+            Node::Int64(value) => serializer.serialize_i64(*value),
+            Node::Int32(value) => serializer.serialize_i32(*value),
+            Node::Float64(value) => serializer.serialize_f64(*value),
+            Node::Float32(value) => serializer.serialize_f32(*value),
+            Node::None => serializer.serialize_none(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// A [`Serializer`] that builds a [`Node`].
+///
+/// - `i8`, `i16`, `i32` => `Int32`, `i64` => `Int64`, `i128` => `Int128`, and the unsigned counterparts.
+/// - `Option::None` and `()` => `None`, unit variants => `String`.
+/// - Values serialized through `collect_str` that are `ISO 8601` local date-times,
+///   e.g.: [`NaiveDateTime`] with `chrono/serde`, => `DateTime`.
+/// - Newtype, tuple and struct variants => a table with the variant name as the single key.
+///
+/// @since 0.7.0
+pub struct NodeSerializer;
+
+impl Serializer for NodeSerializer {
+    type Ok = Node;
+    type Error = SerError;
+
+    type SerializeSeq = ArraySerializer;
+    type SerializeTuple = ArraySerializer;
+    type SerializeTupleStruct = ArraySerializer;
+    type SerializeTupleVariant = VariantSerializer<ArraySerializer>;
+    type SerializeMap = TableSerializer;
+    type SerializeStruct = TableSerializer;
+    type SerializeStructVariant = VariantSerializer<TableSerializer>;
+
+    fn serialize_bool(self, v: bool) -> Result<Node, SerError> {
+        Ok(Node::Boolean(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Node, SerError> {
+        Ok(Node::Int32(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Node, SerError> {
+        Ok(Node::Int32(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Node, SerError> {
+        Ok(Node::Int32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Node, SerError> {
+        Ok(Node::Int64(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Node, SerError> {
+        Ok(Node::Int128(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Node, SerError> {
+        Ok(Node::IntU32(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Node, SerError> {
+        Ok(Node::IntU32(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Node, SerError> {
+        Ok(Node::IntU32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Node, SerError> {
+        Ok(Node::IntU64(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Node, SerError> {
+        Ok(Node::IntU128(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Node, SerError> {
+        Ok(Node::Float32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Node, SerError> {
+        Ok(Node::Float64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Node, SerError> {
+        Ok(Node::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Node, SerError> {
+        Ok(Node::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Node, SerError> {
+        Ok(Node::Array(v.iter().map(|b| Node::IntU32((*b).into())).collect()))
+    }
+
+    fn serialize_none(self) -> Result<Node, SerError> {
+        Ok(Node::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Node, SerError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Node, SerError> {
+        Ok(Node::None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Node, SerError> {
+        Ok(Node::None)
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<Node, SerError> {
+        Ok(Node::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<Node, SerError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Node, SerError> {
+        let mut table = Table::new();
+        table.insert(variant.to_string(), value.serialize(self)?);
+        Ok(Node::Nested(table))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<ArraySerializer, SerError> {
+        Ok(ArraySerializer::new(len.unwrap_or_default()))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ArraySerializer, SerError> {
+        Ok(ArraySerializer::new(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<ArraySerializer, SerError> {
+        Ok(ArraySerializer::new(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer<ArraySerializer>, SerError> {
+        Ok(VariantSerializer::new(variant, ArraySerializer::new(len)))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<TableSerializer, SerError> {
+        Ok(TableSerializer::default())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<TableSerializer, SerError> {
+        Ok(TableSerializer::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<VariantSerializer<TableSerializer>, SerError> {
+        Ok(VariantSerializer::new(variant, TableSerializer::default()))
+    }
+
+    fn collect_str<T: fmt::Display + ?Sized>(self, value: &T) -> Result<Node, SerError> {
+        let value = value.to_string();
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(&value, DATETIME_PATTERN) {
+            return Ok(Node::DateTime(datetime));
+        }
+        if let Ok(datetime) = DateTime::parse_from_rfc3339(&value) {
+            return Ok(Node::OffsetDateTime(datetime));
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(&value, DATE_PATTERN) {
+            return Ok(Node::Date(date));
+        }
+        if let Ok(time) = NaiveTime::parse_from_str(&value, TIME_PATTERN) {
+            return Ok(Node::Time(time));
+        }
+
+        Ok(Node::String(value))
+    }
+}
+
+// ----------------------------------------------------------------
+
+pub struct ArraySerializer {
+    array: Array,
+}
+
+impl ArraySerializer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            array: Array::with_capacity(capacity),
+        }
+    }
+}
+
+impl SerializeSeq for ArraySerializer {
+    type Ok = Node;
+    type Error = SerError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        self.array.push(value.serialize(NodeSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, SerError> {
+        Ok(Node::Array(self.array))
+    }
+}
+
+impl SerializeTuple for ArraySerializer {
+    type Ok = Node;
+    type Error = SerError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node, SerError> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleStruct for ArraySerializer {
+    type Ok = Node;
+    type Error = SerError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Node, SerError> {
+        SerializeSeq::end(self)
+    }
+}
+
+// ----------------------------------------------------------------
+
+#[derive(Default)]
+pub struct TableSerializer {
+    table: Table,
+    key: Option<String>,
+}
+
+impl SerializeMap for TableSerializer {
+    type Ok = Node;
+    type Error = SerError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerError> {
+        let key = match key.serialize(NodeSerializer)? {
+            Node::String(key) => key,
+            Node::Boolean(key) => key.to_string(),
+            Node::IntU128(key) => key.to_string(),
+            Node::IntU64(key) => key.to_string(),
+            Node::IntU32(key) => key.to_string(),
+            Node::Int128(key) => key.to_string(),
+            Node::Int64(key) => key.to_string(),
+            Node::Int32(key) => key.to_string(),
+            Node::DateTime(key) => format_datetime(&key),
+            Node::Date(key) => format_date(&key),
+            Node::Time(key) => format_time(&key),
+            Node::OffsetDateTime(key) => format_offset_datetime(&key),
+            _ => return Err(ser::Error::custom("map keys must be strings, integers or booleans")),
+        };
+
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| ser::Error::custom("serialize_value called before serialize_key"))?;
+
+        self.table.insert(key, value.serialize(NodeSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, SerError> {
+        Ok(Node::Nested(self.table))
+    }
+}
+
+impl SerializeStruct for TableSerializer {
+    type Ok = Node;
+    type Error = SerError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), SerError> {
+        self.table.insert(key.to_string(), value.serialize(NodeSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Node, SerError> {
+        Ok(Node::Nested(self.table))
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Wraps the content of a tuple or struct variant into `{ variant = content }`.
+pub struct VariantSerializer<S> {
+    variant: &'static str,
+    content: S,
+}
+
+impl<S> VariantSerializer<S> {
+    fn new(variant: &'static str, content: S) -> Self {
+        Self { variant, content }
+    }
+
+    fn wrap(variant: &'static str, content: Node) -> Node {
+        let mut table = Table::new();
+        table.insert(variant.to_string(), content);
+        Node::Nested(table)
+    }
+}
+
+impl SerializeTupleVariant for VariantSerializer<ArraySerializer> {
+    type Ok = Node;
+    type Error = SerError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        SerializeSeq::serialize_element(&mut self.content, value)
+    }
+
+    fn end(self) -> Result<Node, SerError> {
+        Ok(Self::wrap(self.variant, SerializeSeq::end(self.content)?))
+    }
+}
+
+impl SerializeStructVariant for VariantSerializer<TableSerializer> {
+    type Ok = Node;
+    type Error = SerError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), SerError> {
+        SerializeStruct::serialize_field(&mut self.content, key, value)
+    }
+
+    fn end(self) -> Result<Node, SerError> {
+        Ok(Self::wrap(self.variant, SerializeStruct::end(self.content)?))
+    }
+}
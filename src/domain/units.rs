@@ -0,0 +1,502 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+// ----------------------------------------------------------------
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+const DURATION_UNITS: [(&str, DurationUnit); 10] = [
+    ("ns", DurationUnit::Nanos),
+    ("us", DurationUnit::Micros),
+    ("µs", DurationUnit::Micros),
+    ("ms", DurationUnit::Millis),
+    ("s", DurationUnit::Seconds),
+    ("sec", DurationUnit::Seconds),
+    ("m", DurationUnit::Minutes),
+    ("min", DurationUnit::Minutes),
+    ("h", DurationUnit::Hours),
+    ("d", DurationUnit::Days),
+];
+
+const BYTE_UNITS: [(&str, ByteUnit); 11] = [
+    ("b", ByteUnit::Bytes),
+    ("kb", ByteUnit::KB),
+    ("kib", ByteUnit::KiB),
+    ("mb", ByteUnit::MB),
+    ("mib", ByteUnit::MiB),
+    ("gb", ByteUnit::GB),
+    ("gib", ByteUnit::GiB),
+    ("tb", ByteUnit::TB),
+    ("tib", ByteUnit::TiB),
+    ("pb", ByteUnit::PB),
+    ("pib", ByteUnit::PiB),
+];
+
+// ----------------------------------------------------------------
+
+/// The unit of a plain number parsed as a [`Duration`].
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationUnit {
+    Nanos,
+    Micros,
+    Millis,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+}
+
+impl DurationUnit {
+    pub fn nanos(&self) -> u128 {
+        match self {
+            DurationUnit::Nanos => 1,
+            DurationUnit::Micros => 1_000,
+            DurationUnit::Millis => 1_000_000,
+            DurationUnit::Seconds => NANOS_PER_SECOND,
+            DurationUnit::Minutes => 60 * NANOS_PER_SECOND,
+            DurationUnit::Hours => 60 * 60 * NANOS_PER_SECOND,
+            DurationUnit::Days => 24 * 60 * 60 * NANOS_PER_SECOND,
+        }
+    }
+}
+
+/// The unit of a plain number parsed as a [`ByteSize`], `KB` is `1000` and `KiB` is `1024` bytes.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteUnit {
+    Bytes,
+    KB,
+    KiB,
+    MB,
+    MiB,
+    GB,
+    GiB,
+    TB,
+    TiB,
+    PB,
+    PiB,
+}
+
+impl ByteUnit {
+    pub fn bytes(&self) -> u64 {
+        match self {
+            ByteUnit::Bytes => 1,
+            ByteUnit::KB => 1_000,
+            ByteUnit::KiB => 1 << 10,
+            ByteUnit::MB => 1_000_000,
+            ByteUnit::MiB => 1 << 20,
+            ByteUnit::GB => 1_000_000_000,
+            ByteUnit::GiB => 1 << 30,
+            ByteUnit::TB => 1_000_000_000_000,
+            ByteUnit::TiB => 1 << 40,
+            ByteUnit::PB => 1_000_000_000_000_000,
+            ByteUnit::PiB => 1 << 50,
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// A number of bytes, parsed from e.g.: `512MiB`, `1.5GB` or `4096`.
+///
+/// Formatted with the largest binary unit that divides it, e.g.: `512MiB`.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteSize(pub u64);
+
+impl ByteSize {
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for ByteSize {
+    type Err = UnitError;
+
+    /// Plain numbers are bytes.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_byte_size(value, ByteUnit::Bytes)
+    }
+}
+
+impl fmt::Display for ByteSize {
+    // `is_multiple_of` needs Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = [("PiB", ByteUnit::PiB), ("TiB", ByteUnit::TiB), ("GiB", ByteUnit::GiB), ("MiB", ByteUnit::MiB), ("KiB", ByteUnit::KiB)];
+        for (name, unit) in units {
+            if self.0 != 0 && self.0 % unit.bytes() == 0 {
+                return write!(f, "{}{}", self.0 / unit.bytes(), name);
+            }
+        }
+
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// The failures of parsing a [`Duration`] or a [`ByteSize`].
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnitError {
+    Invalid(String),
+    Overflow,
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnitError::Invalid(message) => write!(f, "{}", message),
+            UnitError::Overflow => write!(f, "out of range"),
+        }
+    }
+}
+
+impl std::error::Error for UnitError {}
+
+// ----------------------------------------------------------------
+
+/// Parses a duration, e.g.: `30s`, `1m30s`, `1.5h`, `250ms` or `30`, plain numbers are in `default_unit`.
+///
+/// Units: `ns`, `us` | `µs`, `ms`, `s` | `sec`, `m` | `min`, `h` and `d`.
+///
+/// @since 0.7.0
+pub fn parse_duration(value: &str, default_unit: DurationUnit) -> Result<Duration, UnitError> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Err(invalid_duration(value));
+    }
+
+    if let Some(nanos) = parse_number(trimmed) {
+        return nanos_to_duration(scale(nanos?, default_unit.nanos())?);
+    }
+
+    let mut total: u128 = 0;
+    let mut rest = trimmed;
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .ok_or_else(|| invalid_duration(value))?;
+        let (number, tail) = rest.split_at(number_end);
+        let unit_end = tail.find(|c: char| c.is_ascii_digit() || c == '.').unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_end);
+
+        let unit = DURATION_UNITS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit.trim()))
+            .map(|(_, unit)| unit)
+            .ok_or_else(|| invalid_duration(value))?;
+        let number = parse_number(number).ok_or_else(|| invalid_duration(value))??;
+
+        total = total.checked_add(scale(number, unit.nanos())?).ok_or(UnitError::Overflow)?;
+        rest = tail.trim_start();
+    }
+
+    nanos_to_duration(total)
+}
+
+/// Parses a byte size, e.g.: `512MiB`, `1.5 GB` or `4096`, plain numbers are in `default_unit`.
+///
+/// Units, case-insensitive: `B`, `KB` | `KiB`, `MB` | `MiB`, `GB` | `GiB`, `TB` | `TiB` and `PB` | `PiB`.
+///
+/// @since 0.7.0
+pub fn parse_byte_size(value: &str, default_unit: ByteUnit) -> Result<ByteSize, UnitError> {
+    let trimmed = value.trim();
+    let number_end = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(number_end);
+    let unit = unit.trim();
+
+    let unit = if unit.is_empty() {
+        default_unit
+    } else {
+        BYTE_UNITS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+            .map(|(_, unit)| *unit)
+            .ok_or_else(|| invalid_byte_size(value))?
+    };
+    let number = parse_number(number).ok_or_else(|| invalid_byte_size(value))??;
+
+    let bytes = scale(number, unit.bytes() as u128)?;
+    u64::try_from(bytes).map(ByteSize).map_err(|_| UnitError::Overflow)
+}
+
+// ----------------------------------------------------------------
+
+/// A non-negative decimal number, as an integral part and a fraction of `10^scale`.
+struct Number {
+    integral: u128,
+    fraction: u128,
+    scale: u32,
+}
+
+/// `None` if `value` isn't a plain number.
+fn parse_number(value: &str) -> Option<Result<Number, UnitError>> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return None;
+    }
+
+    let (integral, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if (integral.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+        return Some(Err(UnitError::Invalid(format!("[{}] is not a number", value))));
+    }
+
+    let parse = |digits: &str| -> Result<u128, UnitError> {
+        if digits.is_empty() {
+            return Ok(0);
+        }
+
+        digits.parse::<u128>().map_err(|_| UnitError::Overflow)
+    };
+
+    Some(parse(integral).and_then(|integral| {
+        let fraction_digits = fraction.trim_end_matches('0');
+        Ok(Number {
+            integral,
+            fraction: parse(fraction_digits)?,
+            scale: fraction_digits.len() as u32,
+        })
+    }))
+}
+
+/// Multiplies `number` by `factor`, the result must be integral.
+fn scale(number: Number, factor: u128) -> Result<u128, UnitError> {
+    let integral = number.integral.checked_mul(factor).ok_or(UnitError::Overflow)?;
+    if number.scale == 0 {
+        return Ok(integral);
+    }
+
+    let divisor = 10_u128.checked_pow(number.scale).ok_or(UnitError::Overflow)?;
+    let fraction = number.fraction.checked_mul(factor).ok_or(UnitError::Overflow)?;
+    if fraction % divisor != 0 {
+        return Err(UnitError::Invalid("too many fractional digits for the unit".to_string()));
+    }
+
+    integral.checked_add(fraction / divisor).ok_or(UnitError::Overflow)
+}
+
+fn nanos_to_duration(nanos: u128) -> Result<Duration, UnitError> {
+    let seconds = u64::try_from(nanos / NANOS_PER_SECOND).map_err(|_| UnitError::Overflow)?;
+    Ok(Duration::new(seconds, (nanos % NANOS_PER_SECOND) as u32))
+}
+
+fn invalid_duration(value: &str) -> UnitError {
+    UnitError::Invalid(format!("[{}] is not a duration", value))
+}
+
+fn invalid_byte_size(value: &str) -> UnitError {
+    UnitError::Invalid(format!("[{}] is not a byte size", value))
+}
+
+/// Formats a duration with a single unit that represents it exactly, e.g.: `90s` or `250ms`.
+///
+/// @since 0.7.0
+// `is_multiple_of` needs Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+pub fn format_duration(duration: &Duration) -> String {
+    let nanos = duration.as_nanos();
+    let units = [("d", DurationUnit::Days), ("h", DurationUnit::Hours), ("m", DurationUnit::Minutes), ("s", DurationUnit::Seconds), ("ms", DurationUnit::Millis), ("us", DurationUnit::Micros)];
+    for (name, unit) in units {
+        if nanos != 0 && nanos % unit.nanos() == 0 {
+            return format!("{}{}", nanos / unit.nanos(), name);
+        }
+    }
+
+    format!("{}ns", nanos)
+}
+
+// ----------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use std::fmt;
+
+    use serde::de::{self, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{parse_byte_size, ByteSize, ByteUnit};
+
+    impl Serialize for ByteSize {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ByteSize {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(ByteSizeVisitor)
+        }
+    }
+
+    struct ByteSizeVisitor;
+
+    impl<'de> Visitor<'de> for ByteSizeVisitor {
+        type Value = ByteSize;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a byte size, e.g.: `512MiB` or a number of bytes")
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            Ok(ByteSize(value))
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            u64::try_from(value)
+                .map(ByteSize)
+                .map_err(|_| E::custom(format!("[{}] is out of range", value)))
+        }
+
+        fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+            u64::try_from(value)
+                .map(ByteSize)
+                .map_err(|_| E::custom(format!("[{}] is out of range", value)))
+        }
+
+        fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E> {
+            u64::try_from(value)
+                .map(ByteSize)
+                .map_err(|_| E::custom(format!("[{}] is out of range", value)))
+        }
+
+        /// A whole number of bytes, e.g.: `4096.0`.
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            self.visit_str(&value.to_string())
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            parse_byte_size(value, ByteUnit::Bytes).map_err(|err| E::custom(format!("[{}]: {}", value, err)))
+        }
+    }
+}
+
+/// `#[serde(with = "configer::domain::units::duration")]` binds a [`Duration`] from e.g.: `30s`, `1m30s`,
+/// or a number of seconds, and serializes it as e.g.: `90s`.
+///
+/// The plain numbers of the other units are bound by the nested modules,
+/// e.g.: `#[serde(with = "configer::domain::units::duration::millis")]` binds `timeout_ms = 500` as `500ms`.
+///
+/// @since 0.7.0
+#[cfg(feature = "serde")]
+pub mod duration {
+    use std::fmt;
+    use std::time::Duration;
+
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    use super::{format_duration, parse_duration, DurationUnit};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format_duration(duration))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        deserialize_in(deserializer, DurationUnit::Seconds)
+    }
+
+    /// Binds a [`Duration`], the plain numbers are in `unit`.
+    pub fn deserialize_in<'de, D: Deserializer<'de>>(deserializer: D, unit: DurationUnit) -> Result<Duration, D::Error> {
+        deserializer.deserialize_any(DurationVisitor(unit))
+    }
+
+    macro_rules! unit_module {
+        ($name:ident, $unit:expr, $doc:literal) => {
+            #[doc = $doc]
+            pub mod $name {
+                use std::time::Duration;
+
+                use serde::{Deserializer, Serializer};
+
+                use super::super::DurationUnit;
+
+                pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::serialize(duration, serializer)
+                }
+
+                pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+                    super::deserialize_in(deserializer, $unit)
+                }
+            }
+        };
+    }
+
+    unit_module!(nanos, DurationUnit::Nanos, "Plain numbers are nanoseconds.");
+    unit_module!(micros, DurationUnit::Micros, "Plain numbers are microseconds.");
+    unit_module!(millis, DurationUnit::Millis, "Plain numbers are milliseconds.");
+    unit_module!(minutes, DurationUnit::Minutes, "Plain numbers are minutes.");
+    unit_module!(hours, DurationUnit::Hours, "Plain numbers are hours.");
+
+    struct DurationVisitor(DurationUnit);
+
+    impl DurationVisitor {
+        fn parse<E: de::Error>(&self, value: &str) -> Result<Duration, E> {
+            parse_duration(value, self.0).map_err(|err| E::custom(format!("[{}]: {}", value, err)))
+        }
+    }
+
+    impl<'de> Visitor<'de> for DurationVisitor {
+        type Value = Duration;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a duration, e.g.: `1m30s` or a number of {:?}", self.0)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            self.parse(&value.to_string())
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            self.parse(&value.to_string())
+        }
+
+        fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+            self.parse(&value.to_string())
+        }
+
+        fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E> {
+            self.parse(&value.to_string())
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            self.parse(&value.to_string())
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            self.parse(value)
+        }
+    }
+}
@@ -0,0 +1,191 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::env;
+
+use crate::domain::{Node, Table};
+use crate::env::Environment;
+use crate::env::source::EnvSource;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::{ConfigerError, FileError};
+use crate::reader::{ConfigReader, ConfigReaderRegistry, ReaderRegistry};
+use crate::reader::dotenv::{DOTENV, DotenvConfigReader};
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_dotenv_reader_read_from_path() {
+    let dotenv_reader = DotenvConfigReader::default();
+
+    let table = dotenv_reader.read_from_path("resources/testdata/configer.env").unwrap();
+
+    assert_string(&table, "CONFIGER_DOTENV_HOST", "localhost");
+    assert_string(&table, "CONFIGER_DOTENV_PORT", "8080");
+    assert_string(&table, "CONFIGER_DOTENV_URL", "http://localhost:8080");
+    assert_string(&table, "CONFIGER_DOTENV_LITERAL", "${CONFIGER_DOTENV_HOST} # not a comment");
+    assert_string(&table, "CONFIGER_DOTENV_QUOTED", "line1\nline2 ${escaped} localhost");
+    assert_string(&table, "CONFIGER_DOTENV_MULTILINE", "first\nsecond");
+    assert_string(&table, "CONFIGER_DOTENV_HASH", "value#hash");
+    assert_string(&table, "CONFIGER_DOTENV_EMPTY", "");
+}
+
+#[test]
+fn test_dotenv_reader_parse_failed() {
+    let dotenv_reader = DotenvConfigReader::default();
+
+    assert_eq!(
+        dotenv_reader.read_from_str("OK=1\nNOT OK=2"),
+        Err(FileError::ParseFailed(DOTENV.to_string(), "line 2: missing `=` after key:[NOT]".to_string()))
+    );
+    assert_eq!(
+        dotenv_reader.read_from_str("# comment\n1KEY=value"),
+        Err(FileError::ParseFailed(DOTENV.to_string(), "line 2: invalid key:[1KEY]".to_string()))
+    );
+    assert_eq!(
+        dotenv_reader.read_from_str("KEY=\"unterminated\nOTHER=1"),
+        Err(FileError::ParseFailed(DOTENV.to_string(), "line 1: unterminated quoted value".to_string()))
+    );
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_build_configer_builder_with_dotenv_files() {
+    env::set_var("CONFIGER_DOTENV_OVERRIDDEN", "from-env");
+
+    let builder_rvt = ConfigerEnvironment::builder()
+        .with_dotenv_files(vec![
+            String::from("resources/testdata/configer.env"),
+            String::from("resources/testdata/configer-local.env"),
+            String::from("resources/testdata/configer-missing.env"),
+        ])
+        .build();
+
+    if let Ok(configer) = builder_rvt {
+        // configer-local.env
+        assert_eq!(configer.get("CONFIGER_DOTENV_PORT"), Ok(&Node::String("9090".to_string())));
+        // configer.env
+        assert_eq!(configer.get("CONFIGER_DOTENV_HOST"), Ok(&Node::String("localhost".to_string())));
+        // The environment variables win
+        assert_eq!(configer.get("CONFIGER_DOTENV_OVERRIDDEN"), Ok(&Node::String("from-env".to_string())));
+        // The process environment is left untouched
+        assert!(env::var("CONFIGER_DOTENV_HOST").is_err());
+
+        return;
+    }
+
+    panic!("Failed to read configer.env file")
+}
+
+#[test]
+fn test_build_configer_builder_with_table_and_dotenv_files() {
+    let mut table = Table::new();
+    table.insert("CONFIGER_DOTENV_HOST".to_string(), Node::String("127.0.0.1".to_string()));
+    table.insert("hello".to_string(), Node::String("world".to_string()));
+
+    let configer = ConfigerEnvironment::builder()
+        .with_table(table)
+        .with_dotenv_files(vec![String::from("resources/testdata/configer.env")])
+        .build()
+        .unwrap();
+
+    assert_eq!(configer.get("CONFIGER_DOTENV_HOST"), Ok(&Node::String("localhost".to_string())));
+    assert_eq!(configer.get("hello"), Ok(&Node::String("world".to_string())));
+    assert_eq!(configer.get("CONFIGER_DOTENV_UNKNOWN"), Err(ConfigerError::NotFound));
+}
+
+#[test]
+fn test_build_configer_builder_with_dotenv_path() {
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<DotenvConfigReader>::default());
+
+    // `.env` has no extension, it is read by its name
+    let configer = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(String::from("resources/testdata/dotenv/.env"))
+        .with_profiles(vec![String::from("dev")])
+        .build()
+        .unwrap();
+
+    assert_eq!(configer.get("CONFIGER_DOTENV_NAME"), Ok(&Node::String("configer".to_string())));
+    // .env-dev
+    assert_eq!(configer.get("CONFIGER_DOTENV_PROFILE"), Ok(&Node::String("dev".to_string())));
+}
+
+#[test]
+fn test_build_configer_builder_with_dotenv_files_and_env_source() {
+    let vars = HashMap::from([(String::from("APP_SERVER__HOST"), String::from("example.com"))]);
+    let env_source = EnvSource::prefixed("APP_").with_separator("__").with_infer_types(true).with_vars(vars);
+
+    let configer = ConfigerEnvironment::builder()
+        .with_table(Table::new())
+        .with_dotenv_files(vec![String::from("resources/testdata/configer-app.env")])
+        .with_env_source(env_source)
+        .build()
+        .unwrap();
+
+    // Mapped the same as the environment variables
+    assert_eq!(configer.get("server.port"), Ok(&Node::Int64(9090)));
+    assert_eq!(configer.get("db.secret"), Ok(&Node::String("s3cr3t".to_string())));
+    assert_eq!(configer.get("APP_SERVER__PORT"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.get("OTHER_NAME"), Err(ConfigerError::NotFound));
+    // The environment variables win
+    assert_eq!(configer.get("server.host"), Ok(&Node::String("example.com".to_string())));
+
+    let explanation = configer.explain("server.port").unwrap();
+    assert_eq!(explanation.winner.unwrap().origin.to_string(), "resources/testdata/configer-app.env:2[APP_SERVER__PORT]");
+}
+
+#[test]
+fn test_build_configer_builder_with_dotenv_files_filtered() {
+    let builder = || {
+        ConfigerEnvironment::builder()
+            .with_table(Table::new())
+            .with_dotenv_files(vec![String::from("resources/testdata/configer-app.env")])
+    };
+    let env_source = || EnvSource::prefixed("APP_").with_separator("__").with_vars(HashMap::new());
+
+    // The denied patterns win
+    let configer = builder()
+        .with_env_source(env_source().with_allowed(vec![String::from("APP_*")]).with_denied(vec![String::from("*SECRET")]))
+        .build()
+        .unwrap();
+    assert_eq!(configer.get("server.port"), Ok(&Node::String("9090".to_string())));
+    assert_eq!(configer.get("db.secret"), Err(ConfigerError::NotFound));
+
+    let configer = builder()
+        .with_env_source(env_source().with_allowed(vec![String::from("APP_DB__*")]))
+        .build()
+        .unwrap();
+    assert_eq!(configer.get("server.port"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.get("db.secret"), Ok(&Node::String("s3cr3t".to_string())));
+
+    // Disabled with the environment variables
+    let mut configer = builder().with_env_source(env_source()).with_env_enabled(false).build().unwrap();
+    assert_eq!(configer.get("server"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.get("APP_SERVER__PORT"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.reload().map(|changes| changes.is_empty()), Ok(true));
+    assert_eq!(configer.get("server"), Err(ConfigerError::NotFound));
+}
+
+// ----------------------------------------------------------------
+
+fn assert_string(table: &Table, key: &str, expected: &str) {
+    assert_eq!(table.get(key), Some(&Node::String(expected.to_string())), "key:[{}]", key);
+}
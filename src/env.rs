@@ -26,6 +26,22 @@ use crate::reader::ConfigReader;
 
 /// @since 0.1.0
 pub mod standard;
+/// @since 0.7.0
+pub mod placeholder;
+/// @since 0.7.0
+pub mod source;
+/// @since 0.7.0
+pub mod infer;
+/// @since 0.7.0
+pub mod layer;
+/// @since 0.7.0
+pub mod args;
+/// @since 0.7.0
+pub mod reload;
+/// @since 0.7.0
+pub mod shared;
+/// @since 0.7.0
+pub mod subscribe;
 
 // ----------------------------------------------------------------
 
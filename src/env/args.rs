@@ -0,0 +1,160 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::env;
+
+use crate::domain::{Node, Table};
+use crate::domain::keypath::{KeyPath, Segment};
+use crate::env::infer::infer_node;
+use crate::env::layer::{key_prefix, PropertySource, SourceKind};
+use crate::env::standard::set_in_table;
+use crate::error::ConfigerError;
+
+// ----------------------------------------------------------------
+
+pub const COMMAND_LINE_SOURCE_NAME: &str = "command_line";
+
+const OPTION_PREFIX: &str = "--";
+const NEGATION_PREFIX: &str = "no-";
+const ASSIGNMENT: char = '=';
+
+// ----------------------------------------------------------------
+
+/// Parses the command-line arguments into a [`Table`], e.g.: `--server.port=8080` => `server.port = "8080"`.
+///
+/// - `--key=value` and `--key value`, the next argument is the value unless it starts with `--`.
+/// - `--flag` => `true`, when it is the last argument or followed by another `--` option.
+/// - `--no-flag` => `false`, it never takes the next argument as its value.
+/// - The keys follow the same rules as [`Environment::set`](crate::env::Environment::set),
+///   e.g.: `--servers[0].host=a` or `--hosts[+]=b`, a later option overrides an earlier one.
+/// - `--` ends the options, every argument after it is positional.
+///
+/// The other arguments, e.g.: `run`, `-v` or `-`, are positional, they are returned untouched and in order.
+///
+/// With type inference, the values are parsed by [`infer_node`], e.g.: `--server.port=8080` => [`Node::Int64`].
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CommandLineSource {
+    infer_types: bool,
+}
+
+impl CommandLineSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the values into booleans, integers, floats, datetimes and lists, when `true`.
+    pub fn with_infer_types(mut self, infer_types: bool) -> Self {
+        self.infer_types = infer_types;
+        self
+    }
+
+    /// Parses the arguments of the process, without the program name.
+    pub fn load(&self) -> Result<CommandLineArgs, ConfigerError> {
+        self.load_from(env::args().skip(1))
+    }
+
+    /// Parses the given arguments, without the program name.
+    pub fn load_from<I, S>(&self, args: I) -> Result<CommandLineArgs, ConfigerError>
+        where
+            I: IntoIterator<Item = S>,
+            S: Into<String>,
+    {
+        let mut parsed = CommandLineArgs::default();
+        let mut args = args.into_iter().map(Into::into).peekable();
+
+        while let Some(arg) = args.next() {
+            if arg == OPTION_PREFIX {
+                parsed.positionals.extend(args.by_ref());
+                break;
+            }
+
+            let option = match arg.strip_prefix(OPTION_PREFIX) {
+                Some(option) => option,
+                None => {
+                    parsed.positionals.push(arg);
+                    continue;
+                }
+            };
+
+            let (key, value) = match option.split_once(ASSIGNMENT) {
+                Some((key, value)) => (key, self.to_node(value)),
+                None => match option.strip_prefix(NEGATION_PREFIX) {
+                    Some(key) => (key, Node::Boolean(false)),
+                    None => match args.next_if(|next| !next.starts_with(OPTION_PREFIX)) {
+                        Some(value) => (option, self.to_node(&value)),
+                        None => (option, Node::Boolean(true)),
+                    },
+                },
+            };
+
+            let path = KeyPath::parse(key).map_err(|_| ConfigerError::InvalidKey(arg.clone()))?;
+            set_in_table(&mut parsed.table, &path, value)?;
+
+            // An appended element is known by its array
+            let origin_path = if path.segments().contains(&Segment::Append) {
+                KeyPath::from_segments(&key_prefix(&path))
+            } else {
+                path
+            };
+            parsed.arguments.insert(origin_path.to_string(), arg);
+        }
+
+        Ok(parsed)
+    }
+
+    fn to_node(&self, value: &str) -> Node {
+        if self.infer_types {
+            return infer_node(value);
+        }
+
+        Node::String(value.to_string())
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// The options parsed by [`CommandLineSource`], and the positional arguments left over.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CommandLineArgs {
+    table: Table,
+    positionals: Vec<String>,
+    /// Key path => the argument it was parsed from
+    arguments: HashMap<String, String>,
+}
+
+impl CommandLineArgs {
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    /// The arguments that are not options, in order.
+    pub fn positionals(&self) -> &[String] {
+        &self.positionals
+    }
+
+    /// A [`SourceKind::CommandLine`] source named `command_line`, that knows the argument of each key path.
+    pub fn to_source(&self) -> PropertySource {
+        PropertySource::new(COMMAND_LINE_SOURCE_NAME, SourceKind::CommandLine, self.table.clone())
+            .with_variables(self.arguments.clone())
+    }
+}
@@ -0,0 +1,182 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use crate::domain::Node;
+use crate::reader::try_parse_timestamp;
+
+// ----------------------------------------------------------------
+
+const LIST_SEPARATOR: char = ',';
+const LIST_START: char = '[';
+const LIST_END: char = ']';
+const QUOTE: char = '"';
+const ESCAPE: char = '\\';
+
+// ----------------------------------------------------------------
+
+/// Infers the [`Node`] of a raw text value, e.g.: of an environment variable.
+///
+/// - `true` | `false`, case-insensitive => [`Node::Boolean`]
+/// - integers => the narrowest of [`Node::Int64`], [`Node::IntU64`], [`Node::Int128`] and [`Node::IntU128`]
+/// - numbers with a fraction or an exponent => [`Node::Float64`]
+/// - `ISO-8601` timestamps => [`Node::OffsetDateTime`], [`Node::DateTime`], [`Node::Date`] or [`Node::Time`]
+/// - `[a, "b", 1]` => [`Node::Array`] of inferred elements, quoted elements stay strings,
+///   a value without the brackets stays a string, e.g.: `Hello, world`.
+/// - anything else => [`Node::String`]
+///
+/// @since 0.7.0
+pub fn infer_node(value: &str) -> Node {
+    let trimmed = value.trim();
+
+    if trimmed.starts_with(LIST_START) && trimmed.ends_with(LIST_END) {
+        if let Some(array) = infer_list(&trimmed[1..trimmed.len() - 1]) {
+            return array;
+        }
+    }
+
+    infer_scalar(value)
+}
+
+// ----------------------------------------------------------------
+
+fn infer_list(body: &str) -> Option<Node> {
+    if body.trim().is_empty() {
+        return Some(Node::Array(Vec::new()));
+    }
+
+    let elements = split_list(body)?;
+    let array = elements
+        .into_iter()
+        .map(|element| {
+            let element = element.trim();
+            if element.starts_with(LIST_START) && element.ends_with(LIST_END) {
+                infer_list(&element[1..element.len() - 1]).unwrap_or_else(|| Node::String(element.to_string()))
+            } else if let Some(unquoted) = unquote(element) {
+                Node::String(unquoted)
+            } else {
+                infer_scalar(element)
+            }
+        })
+        .collect();
+
+    Some(Node::Array(array))
+}
+
+/// Splits `body` at the commas that are neither quoted nor inside a nested list,
+/// `None` if the quotes or the brackets are unbalanced.
+fn split_list(body: &str) -> Option<Vec<&str>> {
+    let mut elements = Vec::new();
+    let mut depth = 0_usize;
+    let mut quoted = false;
+    let mut start = 0;
+    let mut chars = body.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            ESCAPE if quoted => {
+                chars.next();
+            }
+            QUOTE => quoted = !quoted,
+            LIST_START if !quoted => depth += 1,
+            LIST_END if !quoted => depth = depth.checked_sub(1)?,
+            LIST_SEPARATOR if !quoted && depth == 0 => {
+                elements.push(&body[start..index]);
+                start = index + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    if quoted || depth > 0 {
+        return None;
+    }
+
+    elements.push(&body[start..]);
+    Some(elements)
+}
+
+/// Unquotes a `"..."` element, `\"` and `\\` are unescaped.
+fn unquote(element: &str) -> Option<String> {
+    if element.len() < 2 || !element.starts_with(QUOTE) || !element.ends_with(QUOTE) {
+        return None;
+    }
+
+    let mut unquoted = String::with_capacity(element.len() - 2);
+    let mut chars = element[1..element.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            ESCAPE => unquoted.push(chars.next().unwrap_or(ESCAPE)),
+            _ => unquoted.push(c),
+        }
+    }
+
+    Some(unquoted)
+}
+
+fn infer_scalar(value: &str) -> Node {
+    let trimmed = value.trim();
+
+    if trimmed.eq_ignore_ascii_case("true") {
+        return Node::Boolean(true);
+    }
+    if trimmed.eq_ignore_ascii_case("false") {
+        return Node::Boolean(false);
+    }
+
+    if let Some(node) = infer_integer(trimmed) {
+        return node;
+    }
+    if let Some(node) = infer_float(trimmed) {
+        return node;
+    }
+    if let Some(node) = try_parse_timestamp(trimmed) {
+        return node;
+    }
+
+    Node::String(value.to_string())
+}
+
+fn infer_integer(value: &str) -> Option<Node> {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    if let Ok(i) = value.parse::<i64>() {
+        return Some(Node::Int64(i));
+    }
+    if let Ok(u) = value.parse::<u64>() {
+        return Some(Node::IntU64(u));
+    }
+    if let Ok(i) = value.parse::<i128>() {
+        return Some(Node::Int128(i));
+    }
+
+    value.parse::<u128>().ok().map(Node::IntU128)
+}
+
+fn infer_float(value: &str) -> Option<Node> {
+    // Only plain decimal notation, `inf` and `NaN` stay strings
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    let starts_with_digit = digits.starts_with(|c: char| c.is_ascii_digit() || c == '.');
+    if !starts_with_digit || !digits.bytes().all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'-' | b'+')) {
+        return None;
+    }
+
+    value.parse::<f64>().ok().map(Node::Float64)
+}
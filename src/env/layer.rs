@@ -0,0 +1,432 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::domain::{Node, Table};
+use crate::domain::diff::leaves;
+use crate::domain::keypath::{KeyPath, Segment};
+use crate::domain::merge::{merge_tables_with, MergeOptions, remove_tombstones};
+use crate::env::standard::get_in_table;
+use crate::error::ConfigerError;
+
+// ----------------------------------------------------------------
+
+pub const TABLE_SOURCE_NAME: &str = "table";
+pub const ENV_SOURCE_NAME: &str = "env";
+pub const RUNTIME_SOURCE_NAME: &str = "runtime";
+pub const MERGE_TABLE_SOURCE_NAME: &str = "merge_table";
+
+// ----------------------------------------------------------------
+
+/// Where the values of a [`PropertySource`] come from.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceKind {
+    /// A [`Table`] given in code, e.g.: `with_table` or `merge_table`.
+    Table,
+    /// A config file, the source is named by its path.
+    File,
+    /// The environment variables.
+    Env,
+    /// The command-line arguments, see: [`CommandLineSource`](crate::env::args::CommandLineSource).
+    CommandLine,
+    /// The values set at runtime by `set`.
+    Runtime,
+}
+
+// ----------------------------------------------------------------
+
+/// A named layer of the configuration, e.g.: `config.toml`, `config-dev.toml` or `env`.
+///
+/// The layers are ordered from the lowest to the highest precedence,
+/// the effective configuration is all of them merged in that order.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertySource {
+    name: String,
+    kind: SourceKind,
+    table: Table,
+    /// Key path => 1-based line
+    lines: HashMap<String, usize>,
+    /// Key path => environment variable name
+    variables: HashMap<String, String>,
+    /// The key-only paths removed at runtime, they are removed from the lower layers before `table` is laid over.
+    removals: Vec<Vec<String>>,
+}
+
+impl PropertySource {
+    pub fn new(name: &str, kind: SourceKind, table: Table) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            table,
+            lines: HashMap::new(),
+            variables: HashMap::new(),
+            removals: Vec::new(),
+        }
+    }
+
+    /// A [`SourceKind::Table`] source named `table`.
+    pub fn from_table(table: Table) -> Self {
+        Self::new(TABLE_SOURCE_NAME, SourceKind::Table, table)
+    }
+
+    /// A [`SourceKind::File`] source named by `path`.
+    pub fn file(path: &str, table: Table) -> Self {
+        Self::new(path, SourceKind::File, table)
+    }
+
+    /// A [`SourceKind::Env`] source named `env`.
+    pub fn env(table: Table) -> Self {
+        Self::new(ENV_SOURCE_NAME, SourceKind::Env, table)
+    }
+
+    /// An empty [`SourceKind::Runtime`] source named `runtime`.
+    pub fn runtime() -> Self {
+        Self::new(RUNTIME_SOURCE_NAME, SourceKind::Runtime, Table::new())
+    }
+
+    /// The 1-based line of each key path, see: [`ConfigReader::read_lines_from_str`](crate::reader::ConfigReader::read_lines_from_str).
+    pub fn with_lines(mut self, lines: HashMap<String, usize>) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    /// The environment variable name, or the command-line argument, of each key path, e.g.: `server.port` => `APP_SERVER__PORT`.
+    pub fn with_variables(mut self, variables: HashMap<String, String>) -> Self {
+        self.variables = variables;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> SourceKind {
+        self.kind
+    }
+
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    pub fn into_table(self) -> Table {
+        self.table
+    }
+
+    /// The key-only paths removed at runtime, e.g.: by `apply_json_patch`, see: [`SourceKind::Runtime`].
+    pub fn removals(&self) -> &[Vec<String>] {
+        &self.removals
+    }
+
+    /// Key path => environment variable name, or command-line argument.
+    pub(crate) fn variables(&self) -> &HashMap<String, String> {
+        &self.variables
+    }
+
+    /// The line of `path`, or else of its nearest ancestor, e.g.: an array element is on the line of its array.
+    pub fn line_of(&self, path: &KeyPath) -> Option<usize> {
+        ancestors(path).find_map(|ancestor| self.lines.get(&ancestor.to_string()).copied())
+    }
+
+    /// The environment variable, or the command-line argument, of `path`, or else of its nearest ancestor.
+    pub fn variable_of(&self, path: &KeyPath) -> Option<&str> {
+        ancestors(path).find_map(|ancestor| self.variables.get(&ancestor.to_string()).map(String::as_str))
+    }
+
+    /// The [`Origin`] of the value at `path`, if this source has one.
+    pub fn origin_of(&self, path: &KeyPath) -> Option<Origin> {
+        get_in_table(&self.table, path).ok()?;
+
+        Some(Origin {
+            source: self.name.clone(),
+            kind: self.kind,
+            line: self.line_of(path),
+            variable: self.variable_of(path).map(str::to_string),
+        })
+    }
+
+    /// Inserts `value` at the key-only `keys`, replacing whatever is in the way.
+    pub(crate) fn insert(&mut self, keys: &[&str], value: Node) {
+        let (last, parents) = match keys.split_last() {
+            Some(split) => split,
+            None => return,
+        };
+
+        let mut table = &mut self.table;
+        for key in parents {
+            let node = table.entry(key.to_string()).or_insert_with(|| Node::Nested(Table::new()));
+            if !matches!(node, Node::Nested(_)) {
+                *node = Node::Nested(Table::new());
+            }
+            table = node.as_nested_mut().expect("a nested table");
+        }
+
+        table.insert(last.to_string(), value);
+    }
+
+    /// Records the value at the key-only `keys` in `ctx`, or its removal when it's not there.
+    pub(crate) fn record(&mut self, ctx: &Table, keys: &[&str]) {
+        match get_in_table(ctx, &KeyPath::from_segments(keys)) {
+            Ok(node) => self.insert(keys, node.clone()),
+            Err(_) => self.remove(keys),
+        }
+    }
+
+    /// Removes the key-only `keys`, from this layer and, on merge, from the lower layers.
+    pub(crate) fn remove(&mut self, keys: &[&str]) {
+        let keys: Vec<String> = keys.iter().map(|key| key.to_string()).collect();
+        remove_in(&mut self.table, &keys);
+        if !self.removals.contains(&keys) {
+            self.removals.push(keys);
+        }
+    }
+
+    /// Whether `path`, or one of its parents, was removed by this layer.
+    fn removes(&self, path: &KeyPath) -> bool {
+        self.removals.iter().any(|keys| {
+            keys.len() <= path.segments().len()
+                && keys.iter().zip(path.segments()).all(|(key, segment)| matches!(segment, Segment::Key(k) if k == key))
+        })
+    }
+
+    /// Removes the removals of this layer from `ctx`, then lays the values over it.
+    fn lay_over(&self, ctx: &mut Table) {
+        for keys in &self.removals {
+            remove_in(ctx, keys);
+        }
+        overlay(ctx, &self.table);
+    }
+
+    /// Whether the layer was added at runtime, i.e.: by `set` or `merge_table`.
+    pub(crate) fn is_runtime(&self) -> bool {
+        match self.kind {
+            SourceKind::Runtime => true,
+            SourceKind::Table => self.name == MERGE_TABLE_SOURCE_NAME,
+            _ => false,
+        }
+    }
+
+    /// Folds `later`, the layer right above this one, into this one, the same as they are merged, see: [`merge_sources`].
+    pub(crate) fn fold(&mut self, later: &PropertySource) {
+        later.lay_over(&mut self.table);
+        for keys in &later.removals {
+            if !self.removals.contains(keys) {
+                self.removals.push(keys.clone());
+            }
+        }
+        self.lines.extend(later.lines.clone());
+        self.variables.extend(later.variables.clone());
+    }
+}
+
+/// Merges `sources`, from the lowest to the highest precedence, by `options`, then removes the tombstones.
+///
+/// A source added at runtime, e.g.: by `set` or `merge_table`, records the effective values, it is laid over as it was set,
+/// its arrays replace the lower ones, and the keys it removed are removed from the lower sources.
+///
+/// @since 0.7.0
+pub fn merge_sources(sources: &[PropertySource], options: &MergeOptions) -> Result<Table, ConfigerError> {
+    let mut ctx = Table::new();
+    for source in sources {
+        if source.is_runtime() {
+            source.lay_over(&mut ctx);
+        } else {
+            ctx = merge_tables_with(ctx, source.table().clone(), options)?;
+        }
+    }
+    remove_tombstones(&mut ctx, options);
+
+    Ok(ctx)
+}
+
+/// Sets every value of `src` into `dst`, the nested tables are laid over recursively.
+fn overlay(dst: &mut Table, src: &Table) {
+    for (key, src_node) in src {
+        match (dst.get_mut(key), src_node) {
+            (Some(Node::Nested(dst_nested)), Node::Nested(src_nested)) => overlay(dst_nested, src_nested),
+            _ => {
+                dst.insert(key.clone(), src_node.clone());
+            }
+        }
+    }
+}
+
+/// Removes the value at the key-only `keys` from `table`, if any.
+fn remove_in(table: &mut Table, keys: &[String]) {
+    let (last, parents) = match keys.split_last() {
+        Some(split) => split,
+        None => return,
+    };
+
+    let mut table = table;
+    for key in parents {
+        table = match table.get_mut(key) {
+            Some(Node::Nested(nested)) => nested,
+            _ => return,
+        };
+    }
+
+    table.remove(last);
+}
+
+/// `path` first, then its parents up to the top-level key.
+fn ancestors(path: &KeyPath) -> impl Iterator<Item = KeyPath> + '_ {
+    (1..=path.segments().len())
+        .rev()
+        .map(|len| KeyPath::new(path.segments()[..len].to_vec()))
+}
+
+/// The key paths of the leaves that the environment variables set and no later source overrides,
+/// e.g.: to keep the strings of unrelated variables that aren't placeholders.
+pub(crate) fn env_leaf_keys(sources: &[PropertySource]) -> HashSet<String> {
+    let mut keys = HashSet::new();
+    for source in sources {
+        for key in leaves(source.table()).into_keys() {
+            match source.kind() {
+                SourceKind::Env => keys.insert(key),
+                _ => keys.remove(&key),
+            };
+        }
+    }
+
+    keys
+}
+
+/// The keys of `path` up to its first index, e.g.: `servers[0].host` => `servers`.
+pub(crate) fn key_prefix(path: &KeyPath) -> Vec<&str> {
+    path.segments()
+        .iter()
+        .map_while(|segment| match segment {
+            Segment::Key(key) => Some(key.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+// ----------------------------------------------------------------
+
+/// Where a value came from, e.g.: `resources/testdata/config.toml:3` or `env[APP_SERVER__PORT]`.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Origin {
+    /// The name of the [`PropertySource`].
+    pub source: String,
+    pub kind: SourceKind,
+    /// The 1-based line, if the reader could tell.
+    pub line: Option<usize>,
+    /// The environment variable or the command-line argument, if any.
+    pub variable: Option<String>,
+}
+
+impl Origin {
+    /// The file path, for a [`SourceKind::File`] source.
+    pub fn path(&self) -> Option<&str> {
+        match self.kind {
+            SourceKind::File => Some(&self.source),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)?;
+        if let Some(line) = self.line {
+            write!(f, ":{}", line)?;
+        }
+        if let Some(ref variable) = self.variable {
+            write!(f, "[{}]", variable)?;
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// A value of a [`PropertySource`] with its [`Origin`].
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourcedValue {
+    pub value: Node,
+    pub origin: Origin,
+}
+
+/// The answer to "where did this value come from?", see: [`ConfigerEnvironment::explain`](crate::env::standard::ConfigerEnvironment::explain).
+///
+/// The `winner` is the value of the highest source that has the key, the `shadowed` values follow from the highest to the lowest.
+/// The effective `value` may combine them, e.g.: appended arrays or merged tables.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub key: String,
+    /// The effective value, `None` when the key was removed, e.g.: by a tombstone.
+    /// The placeholders in it are resolved, unlike in the [`SourcedValue`]s, after `resolve_placeholders`.
+    pub value: Option<Node>,
+    pub winner: Option<SourcedValue>,
+    pub shadowed: Vec<SourcedValue>,
+}
+
+impl Explanation {
+    /// Collects the values at `path` from `sources`, ordered from the lowest to the highest precedence,
+    /// down to the source that removed `path`, if any.
+    pub(crate) fn collect(path: &KeyPath, value: Option<Node>, sources: &[PropertySource]) -> Self {
+        let mut values = Vec::new();
+        for source in sources.iter().rev() {
+            if let (Some(origin), Ok(value)) = (source.origin_of(path), get_in_table(source.table(), path)) {
+                values.push(SourcedValue { value: value.clone(), origin });
+            }
+            if source.removes(path) {
+                break;
+            }
+        }
+        let mut values = values.into_iter();
+
+        Self {
+            key: path.to_string(),
+            value,
+            winner: values.next(),
+            shadowed: values.collect(),
+        }
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.value {
+            Some(ref value) => write!(f, "{} = {:?}", self.key, value)?,
+            None => write!(f, "{} is not set", self.key)?,
+        }
+        if let Some(ref winner) = self.winner {
+            write!(f, "\n  from {} => {:?}", winner.origin, winner.value)?;
+        }
+        for shadowed in &self.shadowed {
+            write!(f, "\n  shadows {} => {:?}", shadowed.origin, shadowed.value)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashSet;
+
+use crate::domain::keypath::KeyPath;
+use crate::domain::{Node, Table};
+use crate::env::standard::get_in_table;
+use crate::error::ConfigerError;
+use crate::writer::{format_date, format_datetime, format_offset_datetime, format_time};
+
+// ----------------------------------------------------------------
+
+const PLACEHOLDER_PREFIX: &str = "${";
+const ESCAPED_PLACEHOLDER_PREFIX: &str = "\\${";
+const PLACEHOLDER_SUFFIX: char = '}';
+const DEFAULT_SEPARATOR: char = ':';
+const QUOTE: char = '"';
+const CYCLE_SEPARATOR: &str = " -> ";
+
+// ----------------------------------------------------------------
+
+/// Resolves `${key}` placeholders in [`Node::String`] values against a [`Table`].
+///
+/// - `${server.host}` is replaced by the value at the [`KeyPath`], scalars are formatted as strings.
+/// - `${server.port:8080}` falls back to the text after the first top-level `:` when the key is missing or [`Node::None`].
+/// - Placeholders may be nested in keys and defaults, e.g.: `${db.${profile}.url:${db.url}}`.
+/// - `\${` is an escaped, literal `${`.
+///
+/// Fails with [`ConfigerError::PlaceholderNotFound`], [`ConfigerError::InvalidPlaceholder`]
+/// or [`ConfigerError::PlaceholderCycle`] naming the cycle, e.g.: `a -> b -> a`.
+///
+/// @since 0.7.0
+pub struct PlaceholderResolver<'a> {
+    ctx: &'a Table,
+    /// The keys being resolved, to detect cycles.
+    stack: Vec<String>,
+    /// The key paths of the strings that are kept as they are when they can't be resolved.
+    lenient: HashSet<String>,
+}
+
+impl<'a> PlaceholderResolver<'a> {
+    pub fn new(ctx: &'a Table) -> Self {
+        Self {
+            ctx,
+            stack: Vec::new(),
+            lenient: HashSet::new(),
+        }
+    }
+
+    /// Keeps the strings at `keys` as they are when they can't be resolved, e.g.: the environment variables.
+    pub(crate) fn with_lenient_keys(mut self, keys: HashSet<String>) -> Self {
+        self.lenient = keys;
+        self
+    }
+
+    /// Resolves the node at `path`, every string in a table or an array is resolved.
+    pub fn resolve_key(&mut self, path: &KeyPath) -> Result<Node, ConfigerError> {
+        let node = get_in_table(self.ctx, path)?;
+        self.resolve_at(node, path)
+    }
+
+    /// Resolves every string in the table.
+    pub fn resolve_table(&mut self) -> Result<Table, ConfigerError> {
+        let mut resolved = Table::with_capacity(self.ctx.len());
+        for (key, node) in self.ctx {
+            let path = KeyPath::default().with_key(key);
+            resolved.insert(key.clone(), self.resolve_at(node, &path)?);
+        }
+
+        Ok(resolved)
+    }
+
+    /// Resolves the placeholders in `text`.
+    pub fn resolve_str(&mut self, text: &str) -> Result<String, ConfigerError> {
+        let mut resolved = String::with_capacity(text.len());
+        let mut index = 0;
+
+        while let Some(c) = text[index..].chars().next() {
+            let rest = &text[index..];
+
+            if rest.starts_with(ESCAPED_PLACEHOLDER_PREFIX) {
+                resolved.push_str(PLACEHOLDER_PREFIX);
+                index += ESCAPED_PLACEHOLDER_PREFIX.len();
+                continue;
+            }
+
+            if rest.starts_with(PLACEHOLDER_PREFIX) {
+                let end = find_placeholder_end(rest).ok_or_else(|| ConfigerError::InvalidPlaceholder(rest.to_string()))?;
+                resolved.push_str(&self.resolve_placeholder(&rest[..=end])?);
+                index += end + 1;
+                continue;
+            }
+
+            resolved.push(c);
+            index += c.len_utf8();
+        }
+
+        Ok(resolved)
+    }
+
+    fn resolve_at(&mut self, node: &Node, path: &KeyPath) -> Result<Node, ConfigerError> {
+        match node {
+            Node::String(value) => {
+                let key = path.to_string();
+                if let Some(start) = self.stack.iter().position(|k| k.eq(&key)) {
+                    let mut cycle = self.stack[start..].to_vec();
+                    cycle.push(key);
+                    return Err(ConfigerError::PlaceholderCycle(cycle.join(CYCLE_SEPARATOR)));
+                }
+
+                self.stack.push(key);
+                let rvt = self.resolve_str(value);
+                let key = self.stack.pop().unwrap_or_default();
+
+                match rvt {
+                    Err(_) if self.lenient.contains(&key) => Ok(Node::String(value.clone())),
+                    rvt => rvt.map(Node::String),
+                }
+            }
+            Node::Nested(table) => {
+                let mut resolved = Table::with_capacity(table.len());
+                for (key, child) in table {
+                    resolved.insert(key.clone(), self.resolve_at(child, &path.clone().with_key(key))?);
+                }
+
+                Ok(Node::Nested(resolved))
+            }
+            Node::Array(array) => {
+                let mut resolved = Vec::with_capacity(array.len());
+                for (index, child) in array.iter().enumerate() {
+                    resolved.push(self.resolve_at(child, &path.clone().with_index(index))?);
+                }
+
+                Ok(Node::Array(resolved))
+            }
+            _ => Ok(node.clone()),
+        }
+    }
+
+    /// Resolves a single `${...}` placeholder.
+    fn resolve_placeholder(&mut self, placeholder: &str) -> Result<String, ConfigerError> {
+        let body = &placeholder[PLACEHOLDER_PREFIX.len()..placeholder.len() - 1];
+        let (key, default) = split_default(body);
+
+        let key = self.resolve_str(key)?;
+        let path = KeyPath::parse(key.trim())?;
+
+        match get_in_table(self.ctx, &path) {
+            Ok(Node::Nested(_)) | Ok(Node::Array(_)) => Err(ConfigerError::InvalidPlaceholder(placeholder.to_string())),
+            Ok(Node::None)
+            | Err(ConfigerError::NotFound)
+            | Err(ConfigerError::NonNested)
+            | Err(ConfigerError::NonArray)
+            | Err(ConfigerError::IndexOutOfBounds(_, _)) => match default {
+                Some(default) => self.resolve_str(default),
+                None => Err(ConfigerError::PlaceholderNotFound(path.to_string())),
+            },
+            Ok(node) => {
+                let resolved = self.resolve_at(node, &path)?;
+                Ok(scalar_to_string(&resolved))
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Returns the index of the `}` that closes the placeholder at the start of `text`.
+fn find_placeholder_end(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '$' if text[index..].starts_with(PLACEHOLDER_PREFIX) => {
+                depth += 1;
+                chars.next();
+            }
+            PLACEHOLDER_SUFFIX => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Splits `body` at the first `:` that is neither quoted nor inside a nested placeholder.
+fn split_default(body: &str) -> (&str, Option<&str>) {
+    let mut depth = 0;
+    let mut quoted = false;
+    let mut chars = body.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            QUOTE => quoted = !quoted,
+            '\\' => {
+                chars.next();
+            }
+            '$' if body[index..].starts_with(PLACEHOLDER_PREFIX) => depth += 1,
+            PLACEHOLDER_SUFFIX if depth > 0 => depth -= 1,
+            DEFAULT_SEPARATOR if depth == 0 && !quoted => {
+                return (&body[..index], Some(&body[index + 1..]));
+            }
+            _ => {}
+        }
+    }
+
+    (body, None)
+}
+
+fn scalar_to_string(node: &Node) -> String {
+    match node {
+        Node::String(value) => value.clone(),
+        Node::DateTime(value) => format_datetime(value),
+        Node::Date(value) => format_date(value),
+        Node::Time(value) => format_time(value),
+        Node::OffsetDateTime(value) => format_offset_datetime(value),
+        Node::Boolean(value) => value.to_string(),
+        Node::IntU128(value) => value.to_string(),
+        Node::IntU64(value) => value.to_string(),
+        Node::IntU32(value) => value.to_string(),
+        Node::Int128(value) => value.to_string(),
+        Node::Int64(value) => value.to_string(),
+        Node::Int32(value) => value.to_string(),
+        Node::Float64(value) => value.to_string(),
+        Node::Float32(value) => value.to_string(),
+        Node::Nested(_) | Node::Array(_) | Node::None => String::new(),
+    }
+}
@@ -0,0 +1,263 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::time::SystemTime;
+
+use crate::domain::{Node, Table};
+use crate::domain::diff::{Change, diff};
+use crate::domain::keypath::KeyPath;
+use crate::domain::merge::MergeOptions;
+use crate::env::layer::PropertySource;
+use crate::env::source::EnvSource;
+use crate::error::FileError;
+use crate::reader::{ConfigReader, ReaderRegistry};
+use crate::reader::dotenv::DotenvConfigReader;
+
+// ----------------------------------------------------------------
+
+/// A layer of the read-and-merge pipeline of [`ConfigerEnvironmentBuilder::build`](crate::env::standard::ConfigerEnvironmentBuilder::build).
+///
+/// @since 0.7.0
+#[derive(Debug, Clone)]
+pub(crate) enum Layer {
+    /// A config file read by the reader of its `suffix`, an `optional` one, e.g.: a profile, may be missing.
+    File { path: String, suffix: String, optional: bool },
+    /// A dotenv file, which may be missing, its variables are loaded by `env_source`, the same as the environment variables.
+    Dotenv { path: String, env_source: EnvSource },
+    Env(EnvSource),
+    /// A source that is never re-read, e.g.: the `table` or the command line.
+    Fixed(PropertySource),
+}
+
+impl Layer {
+    fn watched_path(&self) -> Option<&str> {
+        match self {
+            Layer::File { path, .. } | Layer::Dotenv { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+}
+
+/// Remembers the layers a [`ConfigerEnvironment`](crate::env::standard::ConfigerEnvironment) was built from,
+/// to re-run the pipeline on reload.
+///
+/// @since 0.7.0
+pub(crate) struct ReloadPlan {
+    layers: Vec<Layer>,
+    options: MergeOptions,
+    resolve_placeholders: bool,
+}
+
+impl ReloadPlan {
+    pub(crate) fn new(layers: Vec<Layer>, options: MergeOptions, resolve_placeholders: bool) -> Self {
+        Self {
+            layers,
+            options,
+            resolve_placeholders,
+        }
+    }
+
+    pub(crate) fn options(&self) -> &MergeOptions {
+        &self.options
+    }
+
+    pub(crate) fn resolve_placeholders(&self) -> bool {
+        self.resolve_placeholders
+    }
+
+    /// Reads the layers into sources.
+    ///
+    /// When `strict`, every error but a missing optional file fails, as a reload must not swallow a broken file.
+    /// Otherwise, like `build` always did: an unreadable config file is reported as [`FileError::ReaderNotFound`],
+    /// and an unreadable profile is skipped.
+    pub(crate) fn read_sources(&self, registry: Option<&dyn ReaderRegistry>, strict: bool) -> Result<Vec<PropertySource>, FileError> {
+        let mut sources = Vec::with_capacity(self.layers.len());
+
+        for layer in &self.layers {
+            match layer {
+                Layer::File { path, suffix, optional } => {
+                    let reader = registry
+                        .and_then(|registry| registry.try_acquire(suffix))
+                        .ok_or_else(|| FileError::ReaderNotFound(suffix.clone()))?;
+
+                    match reader.read_source_from_path(path) {
+                        Ok(source) => sources.push(source),
+                        Err(FileError::InvalidPath(_)) if *optional => continue,
+                        Err(err) if strict => return Err(err),
+                        Err(_) if *optional => continue,
+                        Err(_) => return Err(FileError::ReaderNotFound(suffix.clone())),
+                    }
+                }
+                Layer::Dotenv { path, env_source } => match read_dotenv_source(path, env_source) {
+                    Ok(source) => sources.push(source),
+                    Err(FileError::InvalidPath(_)) => continue,
+                    Err(err) => return Err(err),
+                },
+                Layer::Env(env_source) => sources.push(env_source.load_source()),
+                Layer::Fixed(source) => sources.push(source.clone()),
+            }
+        }
+
+        Ok(sources)
+    }
+}
+
+/// Reads the dotenv file at `path` into a source named by `path`, its variables are mapped by `env_source`,
+/// e.g.: with the prefix `APP_` and the separator `__`, `APP_SERVER__PORT=9090` => `server.port = "9090"`.
+fn read_dotenv_source(path: &str, env_source: &EnvSource) -> Result<PropertySource, FileError> {
+    let dotenv = DotenvConfigReader::default().read_source_from_path(path)?;
+    let vars = dotenv.table().iter().filter_map(|(name, node)| match node {
+        Node::String(value) => Some((name.clone(), value.clone())),
+        _ => None,
+    });
+
+    let env = env_source.load_source_from(vars);
+    // The line of each key path is the line of its variable
+    let lines = env
+        .variables()
+        .iter()
+        .filter_map(|(key, name)| dotenv.line_of(&KeyPath::default().with_key(name)).map(|line| (key.clone(), line)))
+        .collect();
+    let variables = env.variables().clone();
+
+    Ok(PropertySource::file(path, env.into_table()).with_lines(lines).with_variables(variables))
+}
+
+// ----------------------------------------------------------------
+
+/// The state of a watched file, a missing file has none.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: Option<u64>,
+    hash: Option<u64>,
+}
+
+impl FileStamp {
+    /// Hashes the content only when the `modified` time or the length differs from `previous`.
+    fn read(path: &str, previous: Option<&FileStamp>) -> Self {
+        let metadata = match fs::metadata(path) {
+            Ok(metadata) => metadata,
+            Err(_) => return Self::default(),
+        };
+
+        let modified = metadata.modified().ok();
+        let len = Some(metadata.len());
+        if let Some(previous) = previous.filter(|previous| previous.modified == modified && previous.len == len) {
+            return previous.clone();
+        }
+
+        let hash = fs::read(path).ok().map(|content| {
+            let mut hasher = DefaultHasher::new();
+            content.hash(&mut hasher);
+            hasher.finish()
+        });
+
+        Self { modified, len, hash }
+    }
+
+    /// Only the content matters, e.g.: touching a file is not a change.
+    fn same_content(&self, other: &FileStamp) -> bool {
+        self.hash == other.hash
+    }
+}
+
+/// Polls the files of a [`ReloadPlan`] by their modified time and content hash, no OS-specific API is used.
+///
+/// @since 0.7.0
+#[derive(Default)]
+pub(crate) struct FileWatcher {
+    stamps: HashMap<String, FileStamp>,
+}
+
+impl FileWatcher {
+    pub(crate) fn watch(plan: &ReloadPlan) -> Self {
+        let stamps = plan
+            .layers
+            .iter()
+            .filter_map(Layer::watched_path)
+            .map(|path| (path.to_string(), FileStamp::read(path, None)))
+            .collect();
+
+        Self { stamps }
+    }
+
+    /// Whether any file was created, removed or changed since the last poll.
+    pub(crate) fn poll(&mut self) -> bool {
+        let mut changed = false;
+        for (path, stamp) in self.stamps.iter_mut() {
+            let current = FileStamp::read(path, Some(stamp));
+            changed |= !current.same_content(stamp);
+            *stamp = current;
+        }
+
+        changed
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// The key paths of the leaves that changed between two tables, each sorted.
+///
+/// The leaves are the scalars, and the empty tables and arrays, e.g.: `server.port` or `servers[0].host`.
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChangedKeys {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub modified: Vec<String>,
+}
+
+impl ChangedKeys {
+    pub fn between(old: &Table, new: &Table) -> Self {
+        let mut changes = Self::default();
+        for change in diff(old, new) {
+            match change {
+                Change::Added { path, .. } => changes.added.push(path),
+                Change::Removed { path, .. } => changes.removed.push(path),
+                Change::Changed { path, .. } => changes.modified.push(path),
+            }
+        }
+
+        changes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// What a reload did, passed to the listeners of [`ConfigerEnvironment::on_reload`](crate::env::standard::ConfigerEnvironment::on_reload).
+///
+/// @since 0.7.0
+#[derive(Debug, PartialEq)]
+pub enum ReloadEvent {
+    /// The new configuration was swapped in.
+    Reloaded(ChangedKeys),
+    /// The last good configuration was kept.
+    Failed(FileError),
+}
+
+pub type ReloadListener = Box<dyn Fn(&ReloadEvent) + Send + Sync>;
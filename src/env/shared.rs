@@ -0,0 +1,231 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use arc_swap::ArcSwap;
+
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+
+use crate::domain::{Node, Table};
+use crate::domain::diff::{Change, diff, diff_with, DiffOptions};
+use crate::domain::keypath::{Segment, ToKeyPath};
+use crate::domain::merge::MergeOptions;
+use crate::domain::patch::PatchOp;
+#[cfg(feature = "serde")]
+use crate::domain::de::{from_table, NodeDeserializer};
+use crate::env::reload::ChangedKeys;
+use crate::env::standard::{ConfigerEnvironment, get_in_table};
+use crate::error::{ConfigerError, ConversionError, FileError};
+
+// ----------------------------------------------------------------
+
+/// An immutable copy of the effective configuration, see: [`SharedEnvironment::snapshot`].
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigSnapshot {
+    ctx: Table,
+    version: u64,
+}
+
+impl ConfigSnapshot {
+    /// Gets the value at `key`, like [`ConfigerEnvironment::get_key`].
+    pub fn get<K: ToKeyPath + ?Sized>(&self, key: &K) -> Result<&Node, ConfigerError> {
+        let path = key.to_key_path()?;
+        if path.segments().contains(&Segment::Append) {
+            return Err(ConfigerError::InvalidKey(path.to_string()));
+        }
+
+        get_in_table(&self.ctx, &path)
+    }
+
+    /// Converts the value at `key` by `converter`, like [`ConfigerEnvironment::get_with`].
+    pub fn get_with<T, F>(&self, key: &(impl ToKeyPath + ?Sized), converter: F) -> Result<T, ConversionError>
+        where
+            F: FnOnce(Result<&Node, ConfigerError>) -> Result<T, ConversionError>,
+    {
+        let path = key.to_key_path()?;
+        converter(self.get(path.as_ref())).map_err(|err| ConversionError::AtKey(path.to_string(), Box::new(err)))
+    }
+
+    pub fn table(&self) -> &Table {
+        &self.ctx
+    }
+
+    /// The leaves added, removed and changed from this snapshot to `newer`, see: [`diff`].
+    pub fn diff(&self, newer: &ConfigSnapshot) -> Vec<Change> {
+        diff(&self.ctx, &newer.ctx)
+    }
+
+    /// The same as [`ConfigSnapshot::diff`], the leaves are compared by `options`.
+    pub fn diff_with(&self, newer: &ConfigSnapshot, options: &DiffOptions) -> Vec<Change> {
+        diff_with(&self.ctx, &newer.ctx, options)
+    }
+
+    /// Starts at `0` and grows by one with every write, e.g.: to tell whether a cached value is stale.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ConfigSnapshot {
+    /// Binds the subtree at `key` into a `T`, like [`ConfigerEnvironment::get_as`].
+    pub fn get_as<T: DeserializeOwned>(&self, key: &(impl ToKeyPath + ?Sized)) -> Result<T, ConfigerError> {
+        let path = key.to_key_path()?;
+        let node = self.get(path.as_ref())?;
+
+        T::deserialize(NodeDeserializer::with_path(node, path.into_owned())).map_err(ConfigerError::from)
+    }
+
+    /// Binds the whole [`Table`] into a `T`.
+    pub fn bind<T: DeserializeOwned>(&self) -> Result<T, ConfigerError> {
+        from_table(&self.ctx)
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// A [`ConfigerEnvironment`] to share across threads, e.g.: in an `Arc<SharedEnvironment>`.
+///
+/// The readers take a [`ConfigSnapshot`], an `Arc` of the configuration at that time, that no write ever changes.
+/// The writers, e.g.: `set`, `merge_table` or `reload`, are serialized, each one works on the environment
+/// and then publishes a new snapshot. Publishing atomically swaps the `Arc`, without a lock, so a reader never waits for a writer.
+///
+/// The subscribers and the reload listeners are called by the writer, while it holds the writer lock:
+/// a callback may take a [`SharedEnvironment::snapshot`], still the previous one, but a write to the same
+/// [`SharedEnvironment`], e.g.: `set`, waits for the lock and deadlocks.
+///
+/// @since 0.7.0
+pub struct SharedEnvironment {
+    /// The latest snapshot.
+    snapshot: ArcSwap<ConfigSnapshot>,
+    writer: Mutex<ConfigerEnvironment>,
+}
+
+impl SharedEnvironment {
+    pub fn new(configer: ConfigerEnvironment) -> Self {
+        let snapshot = ConfigSnapshot {
+            ctx: configer.ctx().clone(),
+            version: 0,
+        };
+
+        Self {
+            snapshot: ArcSwap::from_pointee(snapshot),
+            writer: Mutex::new(configer),
+        }
+    }
+
+    /// The configuration as of the last finished write.
+    pub fn snapshot(&self) -> Arc<ConfigSnapshot> {
+        self.snapshot.load_full()
+    }
+
+    /// Gets a copy of the value at `key` from the latest snapshot.
+    pub fn get<K: ToKeyPath + ?Sized>(&self, key: &K) -> Result<Node, ConfigerError> {
+        self.snapshot().get(key).cloned()
+    }
+
+    /// Sets the `value` at `key`, like [`ConfigerEnvironment::set_key`].
+    pub fn set<K: ToKeyPath + ?Sized>(&self, key: &K, value: Node) -> Result<(), ConfigerError> {
+        self.update(|configer| configer.set_key(key, value))
+    }
+
+    pub fn set_t<K, T>(&self, key: &K, value: T) -> Result<(), ConfigerError>
+        where
+            K: ToKeyPath + ?Sized,
+            T: Into<Node>,
+    {
+        self.set(key, value.into())
+    }
+
+    /// Merges `table` over the current values, like [`ConfigerEnvironment::merge_table`].
+    pub fn merge_table(&self, table: Table) {
+        self.update(|configer| configer.merge_table(table))
+    }
+
+    /// Merges `table` over the current values by `options`, like [`ConfigerEnvironment::merge_table_with`].
+    pub fn merge_table_with(&self, table: Table, options: &MergeOptions) -> Result<(), ConfigerError> {
+        self.update(|configer| configer.merge_table_with(table, options))
+    }
+
+    /// Applies an `RFC 7386` JSON Merge Patch, like [`ConfigerEnvironment::apply_merge_patch`].
+    pub fn apply_merge_patch(&self, patch: Table) {
+        self.update(|configer| configer.apply_merge_patch(patch))
+    }
+
+    /// Applies the `RFC 6902` JSON Patch `ops`, all or nothing, like [`ConfigerEnvironment::apply_json_patch`].
+    pub fn apply_json_patch(&self, ops: &[PatchOp]) -> Result<(), ConfigerError> {
+        self.update(|configer| configer.apply_json_patch(ops))
+    }
+
+    /// Polls the files, like [`ConfigerEnvironment::poll_reload`], a new snapshot is published only on a change.
+    pub fn poll_reload(&self) -> Option<Result<ChangedKeys, FileError>> {
+        let mut configer = self.lock_writer();
+        let reloaded = configer.poll_reload()?;
+        self.publish(&configer);
+
+        Some(reloaded)
+    }
+
+    /// Reloads, like [`ConfigerEnvironment::reload`].
+    pub fn reload(&self) -> Result<ChangedKeys, FileError> {
+        self.update(ConfigerEnvironment::reload)
+    }
+
+    /// Runs any other write, e.g.: `on_reload` or `resolve_placeholders`, then publishes a new snapshot.
+    ///
+    /// It waits for the other writers, never for the readers. `f` holds the writer lock,
+    /// it must not write to this [`SharedEnvironment`], neither must the callbacks it fires.
+    pub fn update<R, F: FnOnce(&mut ConfigerEnvironment) -> R>(&self, f: F) -> R {
+        let mut configer = self.lock_writer();
+        let rvt = f(&mut configer);
+        self.publish(&configer);
+
+        rvt
+    }
+
+    /// Consumes the handle, e.g.: once every other `Arc` is dropped.
+    pub fn into_inner(self) -> ConfigerEnvironment {
+        self.writer.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// A panicking writer leaves the environment as the write left it, the next writer goes on from there.
+    fn lock_writer(&self) -> MutexGuard<'_, ConfigerEnvironment> {
+        self.writer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// The table is copied while only the writer lock is held.
+    fn publish(&self, configer: &ConfigerEnvironment) {
+        let version = self.snapshot().version + 1;
+        let snapshot = Arc::new(ConfigSnapshot {
+            ctx: configer.ctx().clone(),
+            version,
+        });
+
+        self.snapshot.store(snapshot);
+    }
+}
+
+impl From<ConfigerEnvironment> for SharedEnvironment {
+    fn from(configer: ConfigerEnvironment) -> Self {
+        Self::new(configer)
+    }
+}
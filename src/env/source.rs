@@ -0,0 +1,269 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::env;
+
+use crate::domain::{Node, Table};
+use crate::domain::keypath::KeyPath;
+use crate::env::infer::infer_node;
+use crate::env::layer::PropertySource;
+use crate::reader::insert_nested;
+
+// ----------------------------------------------------------------
+
+const DEFAULT_SEPARATOR: &str = "_";
+const WILDCARD: char = '*';
+const SINGLE_WILDCARD: char = '?';
+
+// ----------------------------------------------------------------
+
+/// Loads the environment variables into a [`Table`].
+///
+/// By default every variable is loaded as a top-level [`Node::String`] keyed by its raw name,
+/// the same as [`try_load_env_variables`](crate::env::try_load_env_variables).
+///
+/// With a prefix, only the variables that start with it are loaded, and they are mapped into nested tables:
+/// the prefix is stripped, the rest is split on the separator and, by default, lowercased.
+/// e.g.: with the prefix `APP_` and the separator `__`, `APP_SERVER__PORT=9090` => `server.port = "9090"`.
+///
+/// The variables are mapped in sorted order, a variable that conflicts with an earlier one,
+/// e.g.: `APP_SERVER` and `APP_SERVER__PORT`, or that has an empty segment, is skipped.
+///
+/// The variables can be filtered by their raw names with `*` and `?` patterns, the denied patterns win,
+/// and a custom map can be injected in place of the process environment, e.g.: for hermetic tests.
+///
+/// With type inference, the values are parsed by [`infer_node`], e.g.: `APP_SERVER__PORT=9090` => [`Node::Int64`],
+/// except the variables whose names match the string patterns, which always stay [`Node::String`].
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvSource {
+    prefix: Option<String>,
+    separator: String,
+    lowercase: bool,
+    enabled: bool,
+    allowed: Vec<String>,
+    denied: Vec<String>,
+    vars: Option<HashMap<String, String>>,
+    infer_types: bool,
+    string_vars: Vec<String>,
+}
+
+impl EnvSource {
+    pub fn new() -> Self {
+        Self {
+            prefix: None,
+            separator: DEFAULT_SEPARATOR.to_string(),
+            lowercase: false,
+            enabled: true,
+            allowed: Vec::new(),
+            denied: Vec::new(),
+            vars: None,
+            infer_types: false,
+            string_vars: Vec::new(),
+        }
+    }
+
+    /// Loads no variables at all.
+    pub fn disabled() -> Self {
+        Self::new().with_enabled(false)
+    }
+
+    /// Loads only the variables that start with `prefix` into nested tables, lowercased, separated by `_`.
+    pub fn prefixed(prefix: &str) -> Self {
+        Self::new().with_prefix(prefix)
+    }
+
+    pub fn with_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = Some(prefix.to_string());
+        self.lowercase = true;
+        self
+    }
+
+    /// e.g.: `_` or `__`.
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    pub fn with_lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Loads only the variables whose names match any of `patterns`, e.g.: `APP_*`.
+    pub fn with_allowed(mut self, patterns: Vec<String>) -> Self {
+        self.allowed = patterns;
+        self
+    }
+
+    /// Skips the variables whose names match any of `patterns`, e.g.: `*_SECRET`.
+    pub fn with_denied(mut self, patterns: Vec<String>) -> Self {
+        self.denied = patterns;
+        self
+    }
+
+    /// Loads `vars` instead of the process environment.
+    pub fn with_vars(mut self, vars: HashMap<String, String>) -> Self {
+        self.vars = Some(vars);
+        self
+    }
+
+    /// Parses the values into booleans, integers, floats, datetimes and lists, when `true`.
+    pub fn with_infer_types(mut self, infer_types: bool) -> Self {
+        self.infer_types = infer_types;
+        self
+    }
+
+    /// Keeps the values of the variables whose names match any of `patterns` as strings, e.g.: `APP_VERSION`.
+    pub fn with_string_vars(mut self, patterns: Vec<String>) -> Self {
+        self.string_vars = patterns;
+        self
+    }
+
+    /// Loads the injected variables, or else the variables of the process environment.
+    pub fn load(&self) -> Table {
+        match self.vars {
+            Some(ref vars) => self.load_from(vars.clone()),
+            None => self.load_from(env::vars()),
+        }
+    }
+
+    /// Loads the given variables.
+    pub fn load_from<I: IntoIterator<Item = (String, String)>>(&self, vars: I) -> Table {
+        self.load_source_from(vars).into_table()
+    }
+
+    /// Loads the variables into a [`PropertySource`] that knows the variable of each key path.
+    ///
+    /// @since 0.7.0
+    pub fn load_source(&self) -> PropertySource {
+        match self.vars {
+            Some(ref vars) => self.load_source_from(vars.clone()),
+            None => self.load_source_from(env::vars()),
+        }
+    }
+
+    /// @since 0.7.0
+    pub fn load_source_from<I: IntoIterator<Item = (String, String)>>(&self, vars: I) -> PropertySource {
+        if !self.enabled {
+            return PropertySource::env(Table::new());
+        }
+
+        let mut vars: Vec<(String, String)> = vars.into_iter().filter(|(name, _)| self.accepts(name)).collect();
+        vars.sort();
+
+        let mut table = Table::new();
+        let mut variables = HashMap::new();
+        for (name, value) in vars {
+            let value = self.to_node(&name, value);
+            let prefix = match self.prefix {
+                Some(ref prefix) => prefix,
+                None => {
+                    variables.insert(KeyPath::default().with_key(&name).to_string(), name.clone());
+                    table.insert(name, value);
+                    continue;
+                }
+            };
+
+            if let Some(keys) = name.strip_prefix(prefix.as_str()).and_then(|rest| self.split(rest)) {
+                let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+                // Conflicting variables are skipped
+                if insert_nested(&mut table, &keys, value).is_ok() {
+                    variables.insert(KeyPath::from_segments(&keys).to_string(), name);
+                }
+            }
+        }
+
+        PropertySource::env(table).with_variables(variables)
+    }
+
+    fn to_node(&self, name: &str, value: String) -> Node {
+        if self.infer_types && !self.string_vars.iter().any(|pattern| matches_pattern(pattern, name)) {
+            return infer_node(&value);
+        }
+
+        Node::String(value)
+    }
+
+    fn accepts(&self, name: &str) -> bool {
+        let allowed = self.allowed.is_empty() || self.allowed.iter().any(|pattern| matches_pattern(pattern, name));
+        allowed && !self.denied.iter().any(|pattern| matches_pattern(pattern, name))
+    }
+
+    fn split(&self, name: &str) -> Option<Vec<String>> {
+        let keys: Vec<&str> = if self.separator.is_empty() {
+            vec![name]
+        } else {
+            name.split(self.separator.as_str()).collect()
+        };
+
+        let keys: Vec<String> = keys
+            .into_iter()
+            .map(|key| if self.lowercase { key.to_lowercase() } else { key.to_string() })
+            .collect();
+
+        if keys.iter().any(String::is_empty) {
+            return None;
+        }
+
+        Some(keys)
+    }
+}
+
+impl Default for EnvSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Matches `name` against `pattern`, `*` matches any run of characters and `?` a single character.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // The position of the last `*` in the pattern, and of the name when it was met
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == SINGLE_WILDCARD || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == WILDCARD {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == WILDCARD)
+}
@@ -16,76 +16,122 @@
 
 // ----------------------------------------------------------------
 
-use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::BTreeSet;
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::Path;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
 
 use crate::domain::{merge_tables, Table};
-use crate::env::{DynamicEnvironment, Environment, Node, try_load_env_variables};
-use crate::error::{ConfigerError, FileError};
-use crate::reader::{ConfigReader, ConfigReaderRegistry, ReaderRegistry};
+use crate::domain::converter::NodeConverter;
+use crate::domain::diff::{Change, diff, diff_with, DiffOptions};
+use crate::domain::keypath::{KeyPath, Segment, ToKeyPath};
+use crate::domain::merge::{ArrayStrategy, merge_tables_with, MergeOptions, remove_tombstones};
+use crate::domain::patch::{apply_json_patch, apply_merge_patch, parse_pointer, PatchOp};
+use crate::domain::units::{ByteSize, ByteUnit, DurationUnit};
+#[cfg(feature = "serde")]
+use crate::domain::de::{from_table, NodeDeserializer};
+use crate::env::{DynamicEnvironment, Environment, Node};
+use crate::env::args::CommandLineArgs;
+use crate::env::layer::{env_leaf_keys, Explanation, key_prefix, MERGE_TABLE_SOURCE_NAME, merge_sources, PropertySource, SourceKind};
+use crate::env::placeholder::PlaceholderResolver;
+use crate::env::reload::{ChangedKeys, FileWatcher, Layer, ReloadEvent, ReloadListener, ReloadPlan};
+use crate::env::shared::SharedEnvironment;
+use crate::env::source::EnvSource;
+use crate::env::subscribe::{KeyChange, Subscribers, Subscription};
+use crate::error::{ConfigerError, ConversionError, FileError};
+use crate::reader::{ConfigReader, ConfigReaderRegistry, file_suffix, ReaderRegistry};
+use crate::reader::dotenv::DotenvConfigReader;
+use crate::reader::ini::IniConfigReader;
+use crate::reader::properties::PropertiesConfigReader;
 #[cfg(feature = "usetoml")]
 use crate::reader::toml::TomlConfigReader;
+#[cfg(feature = "useyaml")]
+use crate::reader::yaml::YamlConfigReader;
+#[cfg(feature = "usejson")]
+use crate::reader::json::JsonConfigReader;
+use crate::writer::{ConfigWriter, ConfigWriterRegistry, WriterRegistry};
+#[cfg(feature = "usetoml")]
+use crate::writer::toml::TomlConfigWriter;
+#[cfg(feature = "useyaml")]
+use crate::writer::yaml::YamlConfigWriter;
+#[cfg(feature = "usejson")]
+use crate::writer::json::JsonConfigWriter;
+
 
 // ----------------------------------------------------------------
 
-const DOT: char = '.';
+/// The most layers added at runtime, e.g.: by `set` and `merge_table`, kept over the others, @since 0.7.0
+const MAX_RUNTIME_SOURCES: usize = 8;
 
 // ----------------------------------------------------------------
 
 pub struct ConfigerEnvironment {
+    /// The effective configuration, i.e.: the `sources` merged.
     ctx: Table,
     registry: Option<Box<dyn ReaderRegistry>>,
+    /// @since 0.7.0
+    writer_registry: Box<dyn WriterRegistry>,
+    /// From the lowest to the highest precedence, @since 0.7.0
+    sources: Vec<PropertySource>,
+    /// @since 0.7.0
+    reload_plan: Option<ReloadPlan>,
+    /// @since 0.7.0
+    watcher: FileWatcher,
+    /// The number of `sources` read by the `reload_plan`, the later ones were added at runtime, @since 0.7.0
+    planned: usize,
+    /// @since 0.7.0
+    reload_listeners: Vec<ReloadListener>,
+    /// @since 0.7.0
+    subscribers: Subscribers,
 }
 
 
 // ----------------------------------------------------------------
 
 impl ConfigerEnvironment {
-    #[cfg(not(feature = "usetoml"))]
     pub fn new() -> Self {
-        let env_table = try_load_env_variables();
-        Self::mixed(Some(env_table), Some(Box::<ConfigReaderRegistry>::default()))
-    }
+        let env_source = EnvSource::default().load_source();
+        let mut configer = Self::layered(env_source.table().clone(), vec![env_source], Some(Box::<ConfigReaderRegistry>::default()));
 
-    #[cfg(feature = "usetoml")]
-    pub fn new() -> Self {
-        let env_table = try_load_env_variables();
-        let mut configer = Self::mixed(Some(env_table), Some(Box::<ConfigReaderRegistry>::default()));
-
-        configer.register_toml_reader();
+        configer.register_default_readers();
         configer
     }
 
     pub fn mixed(table_opt: Option<Table>, registry: Option<Box<dyn ReaderRegistry>>) -> Self {
         if let Some(table) = table_opt {
-            return Self {
-                ctx: table,
-                registry,
-            };
+            return Self::layered(table.clone(), vec![PropertySource::from_table(table)], registry);
         }
 
-        let env_table = try_load_env_variables();
-        Self {
-            ctx: env_table,
-            registry,
-        }
+        let env_source = EnvSource::default().load_source();
+        Self::layered(env_source.table().clone(), vec![env_source], registry)
     }
 
     pub fn mixed_with_env_variables(table_opt: Option<Table>, registry: Option<Box<dyn ReaderRegistry>>) -> Self {
         if let Some(table) = table_opt {
-            let env_table = try_load_env_variables();
-            let merged_table = merge_tables(table, env_table);
-            return Self {
-                ctx: merged_table,
-                registry,
-            };
+            let env_source = EnvSource::default().load_source();
+            let merged_table = merge_tables(table.clone(), env_source.table().clone());
+            return Self::layered(merged_table, vec![PropertySource::from_table(table), env_source], registry);
         }
 
-        let env_table = try_load_env_variables();
+        Self::mixed(None, registry)
+    }
+
+    /// @since 0.7.0
+    fn layered(ctx: Table, sources: Vec<PropertySource>, registry: Option<Box<dyn ReaderRegistry>>) -> Self {
         Self {
-            ctx: env_table,
+            ctx,
             registry,
+            writer_registry: Self::default_writer_registry(),
+            sources,
+            reload_plan: None,
+            watcher: FileWatcher::default(),
+            planned: 0,
+            reload_listeners: Vec::new(),
+            subscribers: Subscribers::default(),
         }
     }
 
@@ -96,37 +142,94 @@ impl ConfigerEnvironment {
     }
 
     /// @since 0.4.0
-    #[cfg(not(feature = "usetoml"))]
-    pub fn table(table: Table) -> Self {
-        Self::mixed_with_env_variables(Some(table), Some(Box::<ConfigReaderRegistry>::default()))
-    }
-
-    /// @since 0.4.0
-    #[cfg(feature = "usetoml")]
     pub fn table(table: Table) -> Self {
         let mut configer = Self::mixed_with_env_variables(Some(table), Some(Box::<ConfigReaderRegistry>::default()));
 
-        configer.register_toml_reader();
+        configer.register_default_readers();
         configer
     }
 
     /// @since 0.4.0
     #[deprecated(since = "0.4.0", note = "use `ConfigerEnvironmentBuilder` instead")]
     pub fn register_table(&mut self, table: Table) {
-        self.ctx = table;
+        let old = mem::replace(&mut self.ctx, table.clone());
+        self.reset_sources(vec![PropertySource::from_table(table)]);
+        self.subscribers.notify_tables(&old, &self.ctx);
     }
 
     /// @since 0.5.0
     pub fn register_table_with_env_variables(&mut self, table: Table) {
-        let env_table = try_load_env_variables();
-        let merged_table = merge_tables(table, env_table);
+        let env_source = EnvSource::default().load_source();
+        let merged_table = merge_tables(table.clone(), env_source.table().clone());
 
-        self.ctx = merged_table;
+        let old = mem::replace(&mut self.ctx, merged_table);
+        self.reset_sources(vec![PropertySource::from_table(table), env_source]);
+        self.subscribers.notify_tables(&old, &self.ctx);
+    }
+
+    /// Replaces the sources, there is nothing to reload anymore.
+    ///
+    /// @since 0.7.0
+    fn reset_sources(&mut self, sources: Vec<PropertySource>) {
+        self.sources = sources;
+        self.reload_plan = None;
+        self.watcher = FileWatcher::default();
+        self.planned = 0;
     }
 
     /// @since 0.4.0
     pub fn merge_table(&mut self, table: Table) {
-        self.ctx = merge_tables(self.ctx.clone(), table)
+        let ctx = merge_tables(self.ctx.clone(), table.clone());
+        let old = mem::replace(&mut self.ctx, ctx);
+        self.record_merged(&table);
+        self.subscribers.notify_tables(&old, &self.ctx);
+    }
+
+    /// Merges `table` over the current values by `options`, all or nothing, see: [`merge_tables_with`].
+    ///
+    /// @since 0.7.0
+    pub fn merge_table_with(&mut self, table: Table, options: &MergeOptions) -> Result<(), ConfigerError> {
+        let mut ctx = merge_tables_with(self.ctx.clone(), table.clone(), options)?;
+        remove_tombstones(&mut ctx, options);
+
+        let old = mem::replace(&mut self.ctx, ctx);
+        self.record_merged(&table);
+        self.subscribers.notify_tables(&old, &self.ctx);
+        Ok(())
+    }
+
+    /// @since 0.7.0
+    fn register_default_readers(&mut self) {
+        self.register_properties_reader();
+        self.register_dotenv_reader();
+        self.register_ini_reader();
+        #[cfg(feature = "usetoml")]
+        self.register_toml_reader();
+        #[cfg(feature = "useyaml")]
+        self.register_yaml_reader();
+        #[cfg(feature = "usejson")]
+        self.register_json_reader();
+    }
+
+    /// @since 0.7.0
+    fn register_properties_reader(&mut self) {
+        if let Some(ref mut registry) = self.registry {
+            registry.register(Box::<PropertiesConfigReader>::default())
+        }
+    }
+
+    /// @since 0.7.0
+    fn register_dotenv_reader(&mut self) {
+        if let Some(ref mut registry) = self.registry {
+            registry.register(Box::<DotenvConfigReader>::default())
+        }
+    }
+
+    /// @since 0.7.0
+    fn register_ini_reader(&mut self) {
+        if let Some(ref mut registry) = self.registry {
+            registry.register(Box::<IniConfigReader>::default())
+        }
     }
 
     /// @since 0.4.0
@@ -136,75 +239,569 @@ impl ConfigerEnvironment {
             registry.register(Box::<TomlConfigReader>::default())
         }
     }
+
+    /// @since 0.7.0
+    #[cfg(feature = "useyaml")]
+    fn register_yaml_reader(&mut self) {
+        if let Some(ref mut registry) = self.registry {
+            registry.register(Box::<YamlConfigReader>::default())
+        }
+    }
+
+    /// @since 0.7.0
+    #[cfg(feature = "usejson")]
+    fn register_json_reader(&mut self) {
+        if let Some(ref mut registry) = self.registry {
+            registry.register(Box::<JsonConfigReader>::default())
+        }
+    }
 }
 
+// ----------------------------------------------------------------
+
 impl ConfigerEnvironment {
-    fn set_nested_recursive(
-        node_ref: &mut Table,
-        keys: Vec<&str>,
-        value: Node,
-    ) -> Result<(), ConfigerError> {
-        if let Some(sentinel) = keys.first() {
-            let key = (*sentinel).to_string();
-
-            if keys.len() > 1 {
-                let nested = node_ref
-                    .entry(key.clone())
-                    .or_insert(Node::Nested(HashMap::new()));
-                return if let Node::Nested(nested_ref) = nested {
-                    Self::set_nested_recursive(nested_ref, keys[1..].to_vec(), value)
+    /// Serializes the effective configuration by the writer registered for `suffix`, e.g.: `toml`.
+    ///
+    /// @since 0.7.0
+    pub fn write_to_string(&self, suffix: &str) -> Result<String, FileError> {
+        match self.writer_registry.try_acquire(suffix) {
+            Some(writer) => writer.write_to_string(&self.ctx),
+            None => Err(FileError::WriterNotFound(suffix.to_string())),
+        }
+    }
+
+    /// Serializes the effective configuration into the file at `path`, the format is picked by [`file_suffix`],
+    /// the same as the reader of `path`, e.g.: `json` for `out/.json`.
+    ///
+    /// @since 0.7.0
+    pub fn write_to_path(&self, path: &str) -> Result<(), FileError> {
+        let suffix = file_suffix(Path::new(path)).ok_or_else(|| FileError::InvalidFile(path.to_string()))?;
+
+        match self.writer_registry.try_acquire(&suffix) {
+            Some(writer) => writer.write_to_path(&self.ctx, path),
+            None => Err(FileError::WriterNotFound(suffix)),
+        }
+    }
+
+    /// @since 0.7.0
+    pub fn register_writer(&mut self, writer: Box<dyn ConfigWriter>) {
+        self.writer_registry.register(writer);
+    }
+
+    /// @since 0.7.0
+    pub fn try_acquire_writer(&self, suffix: &str) -> Option<&dyn ConfigWriter> {
+        self.writer_registry.try_acquire(suffix)
+    }
+
+    /// @since 0.7.0
+    fn default_writer_registry() -> Box<dyn WriterRegistry> {
+        #[allow(unused_mut)]
+        let mut writer_registry = Box::<ConfigWriterRegistry>::default();
+
+        #[cfg(feature = "usetoml")]
+        writer_registry.register(Box::<TomlConfigWriter>::default());
+        #[cfg(feature = "useyaml")]
+        writer_registry.register(Box::<YamlConfigWriter>::default());
+        #[cfg(feature = "usejson")]
+        writer_registry.register(Box::<JsonConfigWriter>::default());
+
+        writer_registry
+    }
+}
+
+/// Creates an empty container for `segment`, a table for keys and an array for indexes.
+fn new_container(segment: &Segment) -> Result<Node, ConfigerError> {
+    match segment {
+        Segment::Key(_) => Ok(Node::Nested(Table::new())),
+        Segment::Index(0) | Segment::Append => Ok(Node::Array(Vec::new())),
+        Segment::Index(index) => Err(ConfigerError::IndexOutOfBounds(*index, 0)),
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigerEnvironment {
+    fn set_in_table(table: &mut Table, key: &str, rest: &[Segment], value: Node) -> Result<(), ConfigerError> {
+        let next = match rest.first() {
+            Some(next) => next,
+            None => {
+                table.insert(key.to_string(), value);
+                return Ok(());
+            }
+        };
+
+        if let Some(child) = table.get_mut(key) {
+            return Self::set_recursive(child, rest, value);
+        }
+
+        // Only insert the intermediate container once the value was set successfully.
+        let mut child = new_container(next)?;
+        Self::set_recursive(&mut child, rest, value)?;
+        table.insert(key.to_string(), child);
+
+        Ok(())
+    }
+
+    fn set_in_array(array: &mut Vec<Node>, index: usize, rest: &[Segment], value: Node) -> Result<(), ConfigerError> {
+        let len = array.len();
+        if index > len {
+            return Err(ConfigerError::IndexOutOfBounds(index, len));
+        }
+
+        let next = match rest.first() {
+            Some(next) => next,
+            None => {
+                if index == len {
+                    array.push(value);
                 } else {
-                    Err(ConfigerError::NonNested)
-                };
+                    array[index] = value;
+                }
+                return Ok(());
             }
+        };
 
-            node_ref.insert(key, value);
+        if index < len {
+            return Self::set_recursive(&mut array[index], rest, value);
         }
 
+        let mut child = new_container(next)?;
+        Self::set_recursive(&mut child, rest, value)?;
+        array.push(child);
+
         Ok(())
     }
+
+    fn set_recursive(node: &mut Node, segments: &[Segment], value: Node) -> Result<(), ConfigerError> {
+        let (segment, rest) = match segments.split_first() {
+            Some(split) => split,
+            None => return Ok(()),
+        };
+
+        match (segment, node) {
+            (Segment::Key(key), Node::Nested(table)) => Self::set_in_table(table, key, rest, value),
+            (Segment::Key(_), _) => Err(ConfigerError::NonNested),
+            (Segment::Index(index), Node::Array(array)) => Self::set_in_array(array, *index, rest, value),
+            (Segment::Append, Node::Array(array)) => {
+                let len = array.len();
+                Self::set_in_array(array, len, rest, value)
+            }
+            (_, _) => Err(ConfigerError::NonArray),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigerEnvironment {
+    fn set_nested(&mut self, path: &KeyPath, value: Node) -> Result<(), ConfigerError> {
+        set_in_table(&mut self.ctx, path, value)
+    }
+
+    fn get_nested(&self, path: &KeyPath) -> Result<&Node, ConfigerError> {
+        get_in_table(&self.ctx, path)
+    }
+}
+
+/// Sets the `value` at `path` in `table`, by the same rules as [`Environment::set`].
+///
+/// @since 0.7.0
+pub(crate) fn set_in_table(table: &mut Table, path: &KeyPath, value: Node) -> Result<(), ConfigerError> {
+    match path.segments().split_first() {
+        Some((Segment::Key(key), rest)) => ConfigerEnvironment::set_in_table(table, key, rest, value),
+        Some(_) => Err(ConfigerError::NonArray),
+        None => Err(ConfigerError::EmptyKey),
+    }
+}
+
+/// Gets the node at `path` in `table`.
+///
+/// @since 0.7.0
+pub(crate) fn get_in_table<'a>(table: &'a Table, path: &KeyPath) -> Result<&'a Node, ConfigerError> {
+    let (key, rest) = match path.segments().split_first() {
+        Some((Segment::Key(key), rest)) => (key, rest),
+        Some(_) => return Err(ConfigerError::NonArray),
+        None => return Err(ConfigerError::EmptyKey),
+    };
+
+    let mut node_ref = table.get(key).ok_or(ConfigerError::NotFound)?;
+
+    for segment in rest {
+        node_ref = match (segment, node_ref) {
+            (Segment::Key(key), Node::Nested(nested)) => nested.get(key).ok_or(ConfigerError::NotFound)?,
+            (Segment::Key(_), _) => return Err(ConfigerError::NonNested),
+            (Segment::Index(index), Node::Array(array)) => array
+                .get(*index)
+                .ok_or(ConfigerError::IndexOutOfBounds(*index, array.len()))?,
+            (Segment::Index(_), _) => return Err(ConfigerError::NonArray),
+            (Segment::Append, _) => return Err(ConfigerError::NotFound),
+        };
+    }
+
+    Ok(node_ref)
 }
 
 // ----------------------------------------------------------------
 
 impl ConfigerEnvironment {
-    fn set_nested(&mut self, keys: Vec<&str>, value: Node) -> Result<(), ConfigerError> {
-        if keys.is_empty() {
-            return Err(ConfigerError::EmptyKey);
+    /// The layers of the configuration, from the lowest to the highest precedence,
+    /// e.g.: `config.toml`, `config-dev.toml`, `env` and `runtime`.
+    ///
+    /// @since 0.7.0
+    pub fn sources(&self) -> &[PropertySource] {
+        &self.sources
+    }
+
+    /// Explains where the value at `key` came from: the winning source and every value it shadows,
+    /// with the file path and line, or the environment variable, where known.
+    ///
+    /// e.g.: `println!("{}", configer.explain("server.port")?)`
+    ///
+    /// The [`Explanation::value`] is the effective value, resolved by [`ConfigerEnvironment::resolve_placeholders`],
+    /// while the winner and the shadowed values are as they are in the sources, e.g.: `${HOST}:8080`.
+    ///
+    /// @since 0.7.0
+    pub fn explain(&self, key: &(impl ToKeyPath + ?Sized)) -> Result<Explanation, ConfigerError> {
+        let path = key.to_key_path()?;
+        let value = self.get_key(path.as_ref()).ok().cloned();
+
+        Ok(Explanation::collect(&path, value, &self.sources))
+    }
+
+    /// Records the value set at `path` into the `runtime` source, the whole array for an indexed `path`.
+    fn record_runtime(&mut self, path: &KeyPath) {
+        self.record_runtime_at(&key_prefix(path));
+    }
+
+    /// Records the value at the key-only `keys` into the `runtime` source, or its removal when it's gone,
+    /// so that a removed key stays removed on reload, with or without a tombstone.
+    fn record_runtime_at<S: AsRef<str>>(&mut self, keys: &[S]) {
+        let keys: Vec<&str> = keys.iter().map(AsRef::as_ref).collect();
+
+        if self.sources.last().map(PropertySource::kind) != Some(SourceKind::Runtime) {
+            self.push_runtime_source(PropertySource::runtime());
+        }
+        if let Some(runtime) = self.sources.last_mut() {
+            runtime.record(&self.ctx, &keys);
         }
+    }
 
-        Self::set_nested_recursive(&mut self.ctx, keys, value)?;
+    /// Records the values at the leaves of the merged `table` into a `merge_table` source, as they were merged,
+    /// so that a reload gives the same values whatever the merge options.
+    fn record_merged(&mut self, table: &Table) {
+        let mut leaves = Vec::new();
+        collect_leaf_keys(table, &mut Vec::new(), &mut leaves);
 
-        Ok(())
+        let mut source = PropertySource::new(MERGE_TABLE_SOURCE_NAME, SourceKind::Table, Table::new());
+        for keys in leaves {
+            let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+            source.record(&self.ctx, &keys);
+        }
+        self.push_runtime_source(source);
     }
 
-    fn get_nested(&self, keys: Vec<&str>) -> Result<&Node, ConfigerError> {
-        if keys.is_empty() {
-            return Err(ConfigerError::EmptyKey);
+    /// Pushes a layer added at runtime, folded into the last layer when that's of the same name and kind,
+    /// e.g.: consecutive `merge_table`s, beyond [`MAX_RUNTIME_SOURCES`] the oldest runtime layers are folded together,
+    /// so that the sources don't grow without bound.
+    fn push_runtime_source(&mut self, source: PropertySource) {
+        if let Some(last) = self.sources.last_mut() {
+            if last.is_runtime() && last.name() == source.name() && last.kind() == source.kind() {
+                last.fold(&source);
+                return;
+            }
         }
 
-        let mut node_ref = &self.ctx;
+        self.sources.push(source);
 
-        for (index, sentinel) in keys.iter().enumerate() {
-            let key = (*sentinel).to_string();
-            match node_ref.get(&key) {
-                Some(next_node) => {
-                    if index == keys.len() - 1 {
-                        return Ok(next_node);
-                    }
+        let start = self.sources.iter().rposition(|source| !source.is_runtime()).map_or(0, |index| index + 1);
+        while self.sources.len() - start > MAX_RUNTIME_SOURCES {
+            let later = self.sources.remove(start + 1);
+            self.sources[start].fold(&later);
+        }
+    }
 
-                    match next_node {
-                        Node::Nested(nested) => {
-                            node_ref = nested;
-                        }
-                        _ => return Err(ConfigerError::NonNested),
-                    }
-                }
-                None => return Err(ConfigerError::NotFound),
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigerEnvironment {
+    /// Applies an `RFC 7386` JSON Merge Patch, a [`Node::None`] removes its key, see: [`apply_merge_patch`].
+    ///
+    /// Like `set`, the patched and the removed keys are recorded in the `runtime` source, they stay over the files on reload.
+    ///
+    /// @since 0.7.0
+    pub fn apply_merge_patch(&mut self, patch: Table) {
+        let mut patched = Vec::new();
+        collect_leaf_keys(&patch, &mut Vec::new(), &mut patched);
+
+        let old = self.ctx.clone();
+        apply_merge_patch(&mut self.ctx, patch);
+        for keys in patched {
+            self.record_runtime_at(&keys);
+        }
+        self.subscribers.notify_tables(&old, &self.ctx);
+    }
+
+    /// Applies the `RFC 6902` JSON Patch `ops`, all or nothing, see: [`apply_json_patch`].
+    ///
+    /// On failure, e.g.: a failed `test` operation, nothing changes.
+    /// Like `set`, the patched and the removed keys are recorded in the `runtime` source, they stay over the files on reload.
+    ///
+    /// @since 0.7.0
+    pub fn apply_json_patch(&mut self, ops: &[PatchOp]) -> Result<(), ConfigerError> {
+        let mut ctx = self.ctx.clone();
+        apply_json_patch(&mut ctx, ops)?;
+        let old = mem::replace(&mut self.ctx, ctx);
+
+        let mut patched = Vec::new();
+        for op in ops {
+            if let PatchOp::Move { from, .. } = op {
+                patched.push(parse_pointer(from)?);
             }
+            patched.push(parse_pointer(op.path())?);
+        }
+        for tokens in patched {
+            // The whole table was replaced
+            if tokens.is_empty() {
+                let keys: BTreeSet<String> = old.keys().chain(self.ctx.keys()).cloned().collect();
+                keys.into_iter().for_each(|key| self.record_runtime_at(&[key]));
+            } else {
+                let keys = self.table_keys(&tokens);
+                self.record_runtime_at(&keys);
+            }
+        }
+        self.subscribers.notify_tables(&old, &self.ctx);
+
+        Ok(())
+    }
+
+    /// The leading `tokens` that are table keys, i.e.: up to the first array.
+    fn table_keys(&self, tokens: &[String]) -> Vec<String> {
+        let mut keys = Vec::new();
+        let mut table = Some(&self.ctx);
+        for token in tokens {
+            let current = match table {
+                Some(current) => current,
+                None => break,
+            };
+
+            keys.push(token.clone());
+            table = match current.get(token) {
+                Some(Node::Nested(nested)) => Some(nested),
+                _ => None,
+            };
+        }
+
+        keys
+    }
+}
+
+/// The key paths of the leaves of a merge patch or a merged table, a table in it is merged and not replaced.
+fn collect_leaf_keys(table: &Table, keys: &mut Vec<String>, leaves: &mut Vec<Vec<String>>) {
+    for (key, node) in table {
+        keys.push(key.clone());
+        match node {
+            Node::Nested(nested) if !nested.is_empty() => collect_leaf_keys(nested, keys, leaves),
+            _ => leaves.push(keys.clone()),
+        }
+        keys.pop();
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigerEnvironment {
+    /// Listens to every reload, see: [`ConfigerEnvironment::reload`].
+    ///
+    /// A panicking listener is skipped, the later listeners are still called and the reload keeps its result.
+    ///
+    /// @since 0.7.0
+    pub fn on_reload<F: Fn(&ReloadEvent) + Send + Sync + 'static>(&mut self, listener: F) {
+        self.reload_listeners.push(Box::new(listener));
+    }
+
+    /// Reloads when any config, profile or `dotenv` file the builder read, or expected, was created, removed or changed,
+    /// by the modified time and the content hash, e.g.: call it on an interval.
+    ///
+    /// Returns `None` when nothing changed, or else the result of [`ConfigerEnvironment::reload`].
+    ///
+    /// @since 0.7.0
+    pub fn poll_reload(&mut self) -> Option<Result<ChangedKeys, FileError>> {
+        if !self.watcher.poll() {
+            return None;
+        }
+
+        Some(self.reload())
+    }
+
+    /// Re-runs the read-and-merge pipeline of [`ConfigerEnvironmentBuilder::build`] and swaps in the new configuration.
+    ///
+    /// The files and the environment variables are read again, the sources added at runtime, e.g.: by `set` or `merge_table`,
+    /// stay over them with the values they set. On failure, e.g.: a parse error, the last good configuration is kept.
+    /// Either way, the listeners get a [`ReloadEvent`].
+    ///
+    /// An environment that was not built by the builder has nothing to reload.
+    ///
+    /// @since 0.7.0
+    pub fn reload(&mut self) -> Result<ChangedKeys, FileError> {
+        let event = match self.try_reload() {
+            Ok(changes) => ReloadEvent::Reloaded(changes),
+            Err(err) => ReloadEvent::Failed(err),
+        };
+
+        for listener in &self.reload_listeners {
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| listener(&event)));
+        }
+
+        match event {
+            ReloadEvent::Reloaded(changes) => Ok(changes),
+            ReloadEvent::Failed(err) => Err(err),
         }
+    }
+
+    fn try_reload(&mut self) -> Result<ChangedKeys, FileError> {
+        let plan = match self.reload_plan {
+            Some(ref plan) => plan,
+            None => return Ok(ChangedKeys::default()),
+        };
+
+        let mut sources = plan.read_sources(self.registry.as_deref(), true)?;
+        let planned = sources.len();
+        sources.extend(self.sources[self.planned..].iter().cloned());
+
+        let mut ctx = merge_sources(&sources, plan.options()).map_err(FileError::MergeFailed)?;
+        if plan.resolve_placeholders() {
+            ctx = resolve_table(&ctx, &sources).map_err(FileError::ResolveFailed)?;
+        }
+
+        let old = mem::replace(&mut self.ctx, ctx);
+        self.sources = sources;
+        self.planned = planned;
+        self.subscribers.notify_tables(&old, &self.ctx);
+
+        Ok(ChangedKeys::between(&old, &self.ctx))
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigerEnvironment {
+    /// Calls `callback` with the old and the new value of every leaf that changed at or under `prefix`,
+    /// e.g.: `log.level`, or `""` for every key.
+    ///
+    /// It fires after `set`, `set_t`, `merge_table`, `merge_table_with`, `register_table_with_env_variables`
+    /// and a successful `reload`. A panicking callback is skipped, the environment keeps the new values.
+    ///
+    /// @since 0.7.0
+    pub fn subscribe<F>(&mut self, prefix: &(impl ToKeyPath + ?Sized), callback: F) -> Result<Subscription, ConfigerError>
+        where
+            F: Fn(&KeyChange) + Send + Sync + 'static,
+    {
+        let path = prefix.to_key_path()?;
+        if path.segments().contains(&Segment::Append) {
+            return Err(ConfigerError::InvalidKey(path.to_string()));
+        }
+
+        Ok(self.subscribers.subscribe(&path, Box::new(callback)))
+    }
+
+    /// Returns whether the `subscription` was still active.
+    ///
+    /// @since 0.7.0
+    pub fn unsubscribe(&mut self, subscription: Subscription) -> bool {
+        self.subscribers.unsubscribe(subscription)
+    }
+}
 
-        Err(ConfigerError::NotFound)
+// ----------------------------------------------------------------
+
+impl ConfigerEnvironment {
+    /// Converts the value at `key` by `converter`, e.g.: `configer.get_with("server.port", NodeConverter::to_u16)`.
+    ///
+    /// On failure, returns [`ConversionError::AtKey`] with the key that failed.
+    ///
+    /// @since 0.7.0
+    pub fn get_with<T, F>(&self, key: &(impl ToKeyPath + ?Sized), converter: F) -> Result<T, ConversionError>
+        where
+            F: FnOnce(Result<&Node, ConfigerError>) -> Result<T, ConversionError>,
+    {
+        let path = key.to_key_path()?;
+        converter(self.get_key(path.as_ref())).map_err(|err| ConversionError::AtKey(path.to_string(), Box::new(err)))
+    }
+
+    /// Gets the value at `key` as a [`Duration`], see: [`NodeConverter::to_duration`].
+    ///
+    /// @since 0.7.0
+    pub fn get_duration(&self, key: &(impl ToKeyPath + ?Sized), default_unit: DurationUnit) -> Result<Duration, ConversionError> {
+        self.get_with(key, |rvt| NodeConverter::to_duration(rvt, default_unit))
+    }
+
+    /// Gets the value at `key` as a [`ByteSize`], see: [`NodeConverter::to_byte_size`].
+    ///
+    /// @since 0.7.0
+    pub fn get_byte_size(&self, key: &(impl ToKeyPath + ?Sized), default_unit: ByteUnit) -> Result<ByteSize, ConversionError> {
+        self.get_with(key, |rvt| NodeConverter::to_byte_size(rvt, default_unit))
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigerEnvironment {
+    /// Gets the value at `key` with its `${...}` placeholders resolved, see: [`PlaceholderResolver`].
+    ///
+    /// @since 0.7.0
+    pub fn get_resolved(&self, key: &(impl ToKeyPath + ?Sized)) -> Result<Node, ConfigerError> {
+        let path = key.to_key_path()?;
+        PlaceholderResolver::new(&self.ctx).resolve_key(&path)
+    }
+
+    /// Resolves the `${...}` placeholders in `text`.
+    ///
+    /// @since 0.7.0
+    pub fn resolve(&self, text: &str) -> Result<String, ConfigerError> {
+        PlaceholderResolver::new(&self.ctx).resolve_str(text)
+    }
+
+    /// Resolves every `${...}` placeholder in place, all or nothing.
+    ///
+    /// The values of the environment variables are kept as they are when they can't be resolved,
+    /// an unrelated variable, e.g.: `PS1`, may hold a `${...}` that isn't a placeholder.
+    ///
+    /// Only the effective values are resolved, the [`sources`](ConfigerEnvironment::sources) keep the placeholders,
+    /// see: [`ConfigerEnvironment::explain`].
+    ///
+    /// @since 0.7.0
+    pub fn resolve_placeholders(&mut self) -> Result<(), ConfigerError> {
+        self.ctx = resolve_table(&self.ctx, &self.sources)?;
+        Ok(())
+    }
+}
+
+/// Resolves every string of `ctx`, leniently for the environment variables of `sources`.
+fn resolve_table(ctx: &Table, sources: &[PropertySource]) -> Result<Table, ConfigerError> {
+    PlaceholderResolver::new(ctx).with_lenient_keys(env_leaf_keys(sources)).resolve_table()
+}
+
+// ----------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+impl ConfigerEnvironment {
+    /// Binds the subtree at `key` into a `T`, e.g.: `configer.get_as::<ServerConfig>("server")`.
+    ///
+    /// On failure, returns [`ConfigerError::BindFailed`] with the full key path of the failing node.
+    ///
+    /// @since 0.7.0
+    pub fn get_as<T: DeserializeOwned>(&self, key: &(impl ToKeyPath + ?Sized)) -> Result<T, ConfigerError> {
+        let path = key.to_key_path()?;
+        let node = self.get_key(path.as_ref())?;
+
+        T::deserialize(NodeDeserializer::with_path(node, path.into_owned())).map_err(ConfigerError::from)
+    }
+
+    /// Binds the whole [`Table`] into a `T`.
+    ///
+    /// @since 0.7.0
+    pub fn bind<T: DeserializeOwned>(&self) -> Result<T, ConfigerError> {
+        from_table(&self.ctx)
     }
 }
 
@@ -218,6 +815,36 @@ impl ConfigerEnvironment {
 
 // ----------------------------------------------------------------
 
+impl ConfigerEnvironment {
+    /// Wraps this environment to share it across threads, see: [`SharedEnvironment`].
+    ///
+    /// @since 0.7.0
+    pub fn into_shared(self) -> SharedEnvironment {
+        SharedEnvironment::new(self)
+    }
+
+    /// The leaves added, removed and changed from this environment to `newer`, see: [`diff`].
+    ///
+    /// @since 0.7.0
+    pub fn diff(&self, newer: &ConfigerEnvironment) -> Vec<Change> {
+        diff(&self.ctx, &newer.ctx)
+    }
+
+    /// The same as [`ConfigerEnvironment::diff`], the leaves are compared by `options`.
+    ///
+    /// @since 0.7.0
+    pub fn diff_with(&self, newer: &ConfigerEnvironment, options: &DiffOptions) -> Vec<Change> {
+        diff_with(&self.ctx, &newer.ctx, options)
+    }
+
+    /// The effective configuration.
+    pub(crate) fn ctx(&self) -> &Table {
+        &self.ctx
+    }
+}
+
+// ----------------------------------------------------------------
+
 impl Default for ConfigerEnvironment {
     fn default() -> Self {
         Self::new()
@@ -226,19 +853,53 @@ impl Default for ConfigerEnvironment {
 
 // ----------------------------------------------------------------
 
-impl Environment for ConfigerEnvironment {
-    fn set(&mut self, key: &str, value: Node) -> Result<(), ConfigerError> {
-        if key.is_empty() {
-            return Err(ConfigerError::EmptyKey);
+impl ConfigerEnvironment {
+    /// Sets the `value` at `key`, a `&str`, `String` or [`KeyPath`], e.g.: a key with a quoted segment.
+    ///
+    /// [`Environment::set`] takes a `&str` only, so that the trait stays object safe.
+    ///
+    /// @since 0.7.0
+    pub fn set_key<K: ToKeyPath + ?Sized>(&mut self, key: &K, value: Node) -> Result<(), ConfigerError> {
+        let path = key.to_key_path()?;
+        // @since 0.7.0: Only the keys up to the first index may change
+        let changing = KeyPath::from_segments(&key_prefix(&path));
+        let old = if self.subscribers.is_empty() {
+            None
+        } else {
+            self.get_nested(&changing).ok().cloned()
+        };
+
+        self.set_nested(&path, value)?;
+        // @since 0.7.0
+        self.record_runtime(&path);
+        self.subscribers.notify_nodes(&changing, old.as_ref(), self.get_nested(&changing).ok());
+
+        Ok(())
+    }
+
+    /// Gets the value at `key`, a `&str`, `String` or [`KeyPath`].
+    ///
+    /// @since 0.7.0
+    pub fn get_key<K: ToKeyPath + ?Sized>(&self, key: &K) -> Result<&Node, ConfigerError> {
+        let path = key.to_key_path()?;
+        // `[+]` only makes sense when setting
+        if path.segments().contains(&Segment::Append) {
+            return Err(ConfigerError::InvalidKey(path.to_string()));
         }
 
-        let keys: Vec<&str> = key.split(DOT).collect();
-        self.set_nested(keys, value)
+        self.get_nested(&path)
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl Environment for ConfigerEnvironment {
+    fn set(&mut self, key: &str, value: Node) -> Result<(), ConfigerError> {
+        self.set_key(key, value)
     }
 
     fn get(&self, key: &str) -> Result<&Node, ConfigerError> {
-        let keys: Vec<&str> = key.split(DOT).collect();
-        self.get_nested(keys)
+        self.get_key(key)
     }
 
     fn try_acquire(&self, name: &str) -> Option<&dyn ConfigReader> {
@@ -269,6 +930,16 @@ pub struct ConfigerEnvironmentBuilder {
     registry: Option<Box<dyn ReaderRegistry>>,
     path: Option<String>,
     profiles: Option<Vec<String>>,
+    /// @since 0.7.0
+    dotenv_files: Option<Vec<String>>,
+    /// @since 0.7.0
+    resolve_placeholders: bool,
+    /// @since 0.7.0
+    env_source: Option<EnvSource>,
+    /// @since 0.7.0
+    merge_options: MergeOptions,
+    /// @since 0.7.0
+    command_line: Option<PropertySource>,
 }
 
 impl ConfigerEnvironmentBuilder {
@@ -278,6 +949,11 @@ impl ConfigerEnvironmentBuilder {
             registry: None,
             path: None,
             profiles: None,
+            dotenv_files: None,
+            resolve_placeholders: false,
+            env_source: None,
+            merge_options: MergeOptions::default(),
+            command_line: None,
         }
     }
 
@@ -301,6 +977,94 @@ impl ConfigerEnvironmentBuilder {
         self
     }
 
+    /// Loads the given dotenv (`.env`) files into the environment variables layer.
+    ///
+    /// Later files override earlier ones, and the real environment variables override all of them.
+    /// Missing files are skipped, the process environment itself is never modified.
+    ///
+    /// Their variables are mapped and filtered by [`with_env_source`](Self::with_env_source), the same as the environment variables,
+    /// e.g.: with `EnvSource::prefixed("APP_").with_separator("__")`, `APP_SERVER__PORT=9090` => `server.port = "9090"`,
+    /// and none is loaded by `with_env_enabled(false)`.
+    ///
+    /// @since 0.7.0
+    pub fn with_dotenv_files(mut self, dotenv_files: Vec<String>) -> Self {
+        self.dotenv_files = Some(dotenv_files);
+        self
+    }
+
+    /// Loads the environment variables by `env_source`, e.g.: `EnvSource::prefixed("APP_").with_separator("__")`.
+    ///
+    /// The variables are loaded over the config files and the `table`, the `table`-only branches load them too,
+    /// which they don't by default.
+    ///
+    /// @since 0.7.0
+    pub fn with_env_source(mut self, env_source: EnvSource) -> Self {
+        self.env_source = Some(env_source);
+        self
+    }
+
+    /// Disables loading the environment variables at all, when `false`, the dotenv files included.
+    ///
+    /// @since 0.7.0
+    pub fn with_env_enabled(mut self, enabled: bool) -> Self {
+        self.env_source = Some(self.env_source.unwrap_or_default().with_enabled(enabled));
+        self
+    }
+
+    /// Stacks the options parsed from the command line over every other layer, e.g.: `--server.port=8080`.
+    ///
+    /// ```rust,ignore
+    /// let args = CommandLineSource::new().load()?;
+    /// let builder = ConfigerEnvironment::builder().with_command_line(&args);
+    /// // args.positionals()
+    /// ```
+    ///
+    /// @since 0.7.0
+    pub fn with_command_line(mut self, args: &CommandLineArgs) -> Self {
+        self.command_line = Some(args.to_source());
+        self
+    }
+
+    /// Merges the layers, i.e.: the config file, `table`, profiles, `dotenv` files and environment variables, by `merge_options`.
+    ///
+    /// @since 0.7.0
+    pub fn with_merge_options(mut self, merge_options: MergeOptions) -> Self {
+        self.merge_options = merge_options;
+        self
+    }
+
+    /// The strategy for every array merged across the layers, see: [`ConfigerEnvironmentBuilder::with_merge_options`].
+    ///
+    /// @since 0.7.0
+    pub fn with_array_strategy(mut self, strategy: ArrayStrategy) -> Self {
+        self.merge_options = self.merge_options.with_array_strategy(strategy);
+        self
+    }
+
+    /// The strategy for the array at `key`, e.g.: `server.allowed_hosts`.
+    ///
+    /// @since 0.7.0
+    pub fn with_array_strategy_at<K: ToKeyPath + ?Sized>(mut self, key: &K, strategy: ArrayStrategy) -> Self {
+        self.merge_options = self.merge_options.with_array_strategy_at(key, strategy);
+        self
+    }
+
+    /// The node that removes a key from the lower layers, e.g.: [`TOMBSTONE`](crate::domain::merge::TOMBSTONE), none by default.
+    ///
+    /// @since 0.7.0
+    pub fn with_tombstone(mut self, tombstone: Option<Node>) -> Self {
+        self.merge_options = self.merge_options.with_tombstone(tombstone);
+        self
+    }
+
+    /// Resolves every `${...}` placeholder eagerly at `build` time, otherwise use [`ConfigerEnvironment::get_resolved`].
+    ///
+    /// @since 0.7.0
+    pub fn with_resolve_placeholders(mut self, resolve_placeholders: bool) -> Self {
+        self.resolve_placeholders = resolve_placeholders;
+        self
+    }
+
     /// Constructs a [`ConfigerEnvironment`] by reading from provided components.
     ///
     /// This method, `build`, takes the current instance's `table`, `registry` and `path` fields into account to generate a
@@ -314,6 +1078,16 @@ impl ConfigerEnvironmentBuilder {
     /// - If only `table` is provided, it constructs an environment with the given table and no registry.
     /// - In the absence of any specific input, it returns a default [`ConfigerEnvironment`].
     ///
+    /// The `dotenv_files`, if any, are read first and loaded beneath the environment variables. `@since 0.7.0`
+    ///
+    /// The layers are merged by the merge options, failing with [`FileError::MergeFailed`]. `@since 0.7.0`
+    ///
+    /// The layers are kept as named [`PropertySource`]s, see: [`ConfigerEnvironment::explain`]. `@since 0.7.0`
+    ///
+    /// The layers are remembered, to re-read them, see: [`ConfigerEnvironment::reload`]. `@since 0.7.0`
+    ///
+    /// The placeholders are resolved last, if enabled, failing with [`FileError::ResolveFailed`]. `@since 0.7.0`
+    ///
     /// # Returns
     ///
     /// A `Result<[`ConfigerEnvironment`], [`FileError`]>` indicating whether the construction was successful or encountered an error.
@@ -325,97 +1099,138 @@ impl ConfigerEnvironmentBuilder {
     /// Since the match branch considers many situations, the implementation of the build method seems more complicated.
     /// Just clarify what each branch does when reading.
     pub fn build(self) -> Result<ConfigerEnvironment, FileError> {
-        match (self.table, self.registry, self.path) {
-            // @since 0.5.0
-            (Some(table_outer), Some(registry), Some(path)) => {
-                let file_path = Path::new(&path);
-
-                if let Some(extension) = file_path.to_str().and_then(|name| Path::new(name).extension()) {
-                    let suffix = extension.to_string_lossy();
-
-                    if let Some(reader) = registry.try_acquire(suffix.as_ref()) {
-                        let rvt = reader.read_from_path(&path[..]);
-
-                        if let Ok(table) = rvt {
-                            let mut merged_table = merge_tables(table, table_outer);
+        let resolve_placeholders = self.resolve_placeholders;
+        let mut configer = self.build_environment()?;
 
-                            if let Some(profiles) = self.profiles {
-                                for profile in profiles {
-                                    let rvt_profile = Self::read_config_profile_file(file_path, &suffix, reader, profile);
-                                    if let Ok(table_profile) = rvt_profile {
-                                        merged_table = merge_tables(merged_table, table_profile);
-                                    }
-                                }
-                            }
+        if resolve_placeholders {
+            configer.resolve_placeholders().map_err(FileError::ResolveFailed)?;
+        }
 
-                            return Ok(ConfigerEnvironment::mixed_with_env_variables(Some(merged_table), Some(registry)));
-                        }
+        Ok(configer)
+    }
 
-                        return Err(FileError::ReaderNotFound(suffix.to_string()));
-                    } // end of registry.try_acquire
+    fn build_environment(self) -> Result<ConfigerEnvironment, FileError> {
+        let mut layers = Vec::new();
+        // The environment variables layers, `dotenv` files beneath the environment variables,
+        // and the command line over them
+        let env_source = self.env_source;
+        let dotenv_source = env_source.clone().unwrap_or_default();
+        let mut env_layers: Vec<Layer> = self
+            .dotenv_files
+            .unwrap_or_default()
+            .into_iter()
+            .map(|path| Layer::Dotenv { path, env_source: dotenv_source.clone() })
+            .collect();
+        let env_layer = |default: Option<EnvSource>| env_source.or(default).map(Layer::Env);
+        let command_line_layer = self.command_line.map(Layer::Fixed);
+
+        // @since 0.7.0: The layers are remembered, see: `ConfigerEnvironment::reload`
+        let (registry, default_readers) = match (self.table, self.registry, self.path) {
+            // @since 0.5.0
+            (Some(table_outer), Some(registry), Some(path)) => {
+                let suffix = Self::acquire_suffix(registry.as_ref(), &path)?;
 
-                    return Err(FileError::ReaderNotFound(suffix.to_string()));
-                } // end of and_then
+                layers.push(Self::config_layer(&path, &suffix));
+                layers.push(Layer::Fixed(PropertySource::from_table(table_outer)));
+                layers.extend(Self::profile_layers(&path, &suffix, self.profiles));
+                env_layers.extend(env_layer(Some(EnvSource::default())));
 
-                Err(FileError::InvalidFile(path))
+                (Some(registry), false)
             }
             (None, Some(registry), Some(path)) => {
-                let file_path = Path::new(&path);
-
-                if let Some(extension) = file_path.to_str().and_then(|name| Path::new(name).extension()) {
-                    let suffix = extension.to_string_lossy();
+                let suffix = Self::acquire_suffix(registry.as_ref(), &path)?;
 
-                    if let Some(reader) = registry.try_acquire(suffix.as_ref()) {
-                        let rvt = reader.read_from_path(&path[..]);
+                layers.push(Self::config_layer(&path, &suffix));
+                layers.extend(Self::profile_layers(&path, &suffix, self.profiles));
+                env_layers.extend(env_layer(Some(EnvSource::default())));
 
-                        if let Ok(table) = rvt {
-                            let mut merged_table = table;
-                            if let Some(profiles) = self.profiles {
-                                for profile in profiles {
-                                    let rvt_profile = Self::read_config_profile_file(file_path, &suffix, reader, profile);
-                                    if let Ok(table_profile) = rvt_profile {
-                                        merged_table = merge_tables(merged_table, table_profile);
-                                    }
-                                }
-                            }
-
-                            return Ok(ConfigerEnvironment::mixed_with_env_variables(Some(merged_table), Some(registry)));
-                        }
-
-                        return Err(FileError::ReaderNotFound(suffix.to_string()));
-                    } // end of registry.try_acquire
-
-                    return Err(FileError::ReaderNotFound(suffix.to_string()));
-                } // end of and_then
-
-                Err(FileError::InvalidFile(path))
+                (Some(registry), false)
             }
             (Some(table), Some(registry), None) => {
-                Ok(ConfigerEnvironment::mixed(Some(table), Some(registry)))
+                layers.push(Layer::Fixed(PropertySource::from_table(table)));
+                env_layers.extend(env_layer(None));
+
+                (Some(registry), false)
             }
             (Some(table), None, None) => {
-                Ok(ConfigerEnvironment::mixed(Some(table), None))
+                layers.push(Layer::Fixed(PropertySource::from_table(table)));
+                env_layers.extend(env_layer(None));
+
+                (None, false)
             }
             _ => {
-                Ok(ConfigerEnvironment::new())
+                env_layers.extend(env_layer(Some(EnvSource::default())));
+                (Some(Box::<ConfigReaderRegistry>::default() as Box<dyn ReaderRegistry>), true)
             }
+        };
+
+        layers.extend(env_layers);
+        layers.extend(command_line_layer);
+
+        let plan = ReloadPlan::new(layers, self.merge_options, self.resolve_placeholders);
+        let sources = plan.read_sources(registry.as_deref(), false)?;
+        let ctx = merge_sources(&sources, plan.options()).map_err(FileError::MergeFailed)?;
+
+        let mut configer = ConfigerEnvironment::layered(ctx, sources, registry);
+        configer.watcher = FileWatcher::watch(&plan);
+        configer.planned = configer.sources.len();
+        configer.reload_plan = Some(plan);
+        if default_readers {
+            configer.register_default_readers();
         }
+
+        Ok(configer)
+    }
+
+    /// The suffix of the config file at `path`, which must have a reader in `registry`.
+    ///
+    /// @since 0.7.0
+    fn acquire_suffix(registry: &dyn ReaderRegistry, path: &str) -> Result<String, FileError> {
+        let suffix = file_suffix(Path::new(path)).ok_or_else(|| FileError::InvalidFile(path.to_string()))?;
+
+        match registry.try_acquire(&suffix) {
+            Some(_) => Ok(suffix),
+            None => Err(FileError::ReaderNotFound(suffix)),
+        }
+    }
+
+    /// @since 0.7.0
+    fn config_layer(path: &str, suffix: &str) -> Layer {
+        Layer::File {
+            path: path.to_string(),
+            suffix: suffix.to_string(),
+            optional: false,
+        }
+    }
+
+    /// @since 0.7.0
+    fn profile_layers(path: &str, suffix: &str, profiles: Option<Vec<String>>) -> Vec<Layer> {
+        profiles
+            .unwrap_or_default()
+            .into_iter()
+            .map(|profile| Layer::File {
+                path: Self::profile_file_path(Path::new(path), suffix, &profile),
+                suffix: suffix.to_string(),
+                optional: true,
+            })
+            .collect()
     }
 
     /// @since 0.6.0
-    fn read_config_profile_file(file_path: &Path, suffix: &Cow<str>, reader: &dyn ConfigReader, profile: String) -> Result<Table, FileError> {
+    ///
+    /// @since 0.7.0 returns the path of the profile file, it is read by the [`ReloadPlan`]
+    fn profile_file_path(file_path: &Path, suffix: &str, profile: &str) -> String {
         let parent_path = file_path.parent().unwrap();
         let file_stem = file_path.file_stem().unwrap();
         // e.g.: config-dev.toml
-        let file_name_with_profile = format!(
-            "{}-{}.{}",
-            file_stem.to_string_lossy(),
-            profile,
-            suffix
-        );
+        let file_name_with_profile = match file_path.extension() {
+            Some(_) => format!("{}-{}.{}", file_stem.to_string_lossy(), profile, suffix),
+            // e.g.: .env-dev
+            None => format!("{}-{}", file_stem.to_string_lossy(), profile),
+        };
 
         let profile_config_file_path = parent_path.join(file_name_with_profile);
-        reader.read_from_path(profile_config_file_path.to_str().unwrap())
+        profile_config_file_path.to_str().unwrap().to_string()
     }
 }
 
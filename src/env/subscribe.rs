@@ -0,0 +1,140 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::domain::{Node, Table};
+use crate::domain::diff::{Change, diff, diff_nodes_at, DiffOptions};
+use crate::domain::keypath::KeyPath;
+
+// ----------------------------------------------------------------
+
+/// A leaf that changed, passed to the callbacks of [`ConfigerEnvironment::subscribe`](crate::env::standard::ConfigerEnvironment::subscribe).
+///
+/// The leaves are the scalars, and the empty tables and arrays, see: [`Change`].
+///
+/// @since 0.7.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyChange {
+    /// The full key path, e.g.: `log.level` or `servers[0].host`.
+    pub key: String,
+    /// `None` when the key was added.
+    pub old: Option<Node>,
+    /// `None` when the key was removed.
+    pub new: Option<Node>,
+}
+
+impl From<Change> for KeyChange {
+    fn from(change: Change) -> Self {
+        match change {
+            Change::Added { path, value } => Self { key: path, old: None, new: Some(value) },
+            Change::Removed { path, value } => Self { key: path, old: Some(value), new: None },
+            Change::Changed { path, old, new } => Self { key: path, old: Some(old), new: Some(new) },
+        }
+    }
+}
+
+pub type KeyChangeListener = Box<dyn Fn(&KeyChange) + Send + Sync>;
+
+/// The handle of a subscription, see: [`ConfigerEnvironment::unsubscribe`](crate::env::standard::ConfigerEnvironment::unsubscribe).
+///
+/// @since 0.7.0
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Subscription(u64);
+
+// ----------------------------------------------------------------
+
+struct Subscriber {
+    id: u64,
+    /// The string form of the prefix key path, empty for every key.
+    prefix: String,
+    callback: KeyChangeListener,
+}
+
+impl Subscriber {
+    /// Whether `key` is the prefix or under it, e.g.: `log` covers `log.level` and `log[0]`, but not `logger`.
+    fn covers(&self, key: &str) -> bool {
+        match key.strip_prefix(&self.prefix) {
+            Some(rest) => self.prefix.is_empty() || rest.is_empty() || rest.starts_with(['.', '[']),
+            None => false,
+        }
+    }
+}
+
+/// The subscribers of a [`ConfigerEnvironment`](crate::env::standard::ConfigerEnvironment).
+///
+/// @since 0.7.0
+#[derive(Default)]
+pub(crate) struct Subscribers {
+    next_id: u64,
+    subscribers: Vec<Subscriber>,
+}
+
+impl Subscribers {
+    pub(crate) fn subscribe(&mut self, prefix: &KeyPath, callback: KeyChangeListener) -> Subscription {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.subscribers.push(Subscriber {
+            id,
+            prefix: prefix.to_string(),
+            callback,
+        });
+
+        Subscription(id)
+    }
+
+    pub(crate) fn unsubscribe(&mut self, subscription: Subscription) -> bool {
+        let len = self.subscribers.len();
+        self.subscribers.retain(|subscriber| subscriber.id != subscription.0);
+
+        self.subscribers.len() != len
+    }
+
+    /// Nothing to diff when nobody listens.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.subscribers.is_empty()
+    }
+
+    /// Notifies the changes of the leaves between the `old` and the `new` table.
+    pub(crate) fn notify_tables(&self, old: &Table, new: &Table) {
+        if self.is_empty() {
+            return;
+        }
+
+        self.notify(diff(old, new));
+    }
+
+    /// Notifies the changes of the leaves under `path`, from the `old` to the `new` node.
+    pub(crate) fn notify_nodes(&self, path: &KeyPath, old: Option<&Node>, new: Option<&Node>) {
+        if self.is_empty() {
+            return;
+        }
+
+        self.notify(diff_nodes_at(path, old, new, &DiffOptions::default()));
+    }
+
+    /// A panicking callback is skipped, the environment and the other callbacks go on.
+    fn notify(&self, changes: Vec<Change>) {
+        let changes: Vec<KeyChange> = changes.into_iter().map(KeyChange::from).collect();
+        for subscriber in &self.subscribers {
+            for change in changes.iter().filter(|change| subscriber.covers(&change.key)) {
+                let _ = panic::catch_unwind(AssertUnwindSafe(|| (subscriber.callback)(change)));
+            }
+        }
+    }
+}
@@ -0,0 +1,303 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::env;
+
+use chrono::{DateTime, NaiveDate, NaiveTime};
+
+use crate::domain::converter::NodeConverter;
+use crate::domain::{Node, Table};
+use crate::env::Environment;
+use crate::env::infer::infer_node;
+use crate::env::source::EnvSource;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::ConfigerError;
+use crate::reader::{ConfigReaderRegistry, ReaderRegistry};
+use crate::reader::properties::PropertiesConfigReader;
+use crate::test_support::string;
+
+// ----------------------------------------------------------------
+
+fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_env_source_default_is_flat() {
+    let table = EnvSource::default().load_from(vars(&[("APP_SERVER_PORT", "9090"), ("HOME", "/root")]));
+
+    assert_eq!(table.get("APP_SERVER_PORT"), Some(&string("9090")));
+    assert_eq!(table.get("HOME"), Some(&string("/root")));
+}
+
+#[test]
+fn test_env_source_prefixed() {
+    let env_vars = vars(&[
+        ("APP_SERVER__PORT", "9090"),
+        ("APP_SERVER__CONTEXT_PATH", "/api"),
+        ("APP_NAME", "configer"),
+        ("APP__EMPTY", "skipped"),
+        ("HOME", "/root"),
+    ]);
+
+    let table = EnvSource::prefixed("APP_").with_separator("__").load_from(env_vars.clone());
+    let configer = ConfigerEnvironment::builder().with_table(table).build().unwrap();
+
+    assert_eq!(configer.get("server.port"), Ok(&string("9090")));
+    assert_eq!(configer.get("server.context_path"), Ok(&string("/api")));
+    assert_eq!(configer.get("name"), Ok(&string("configer")));
+    assert!(configer.get("HOME").is_err());
+    assert!(configer.get("").is_err());
+
+    // `SERVER__PORT` has an empty segment with the `_` separator
+    let table = EnvSource::prefixed("APP_").load_from(vars(&[("APP_SERVER__PORT", "9090"), ("APP_SERVER_CONTEXT_PATH", "/api")]));
+    let configer = ConfigerEnvironment::builder().with_table(table).build().unwrap();
+    assert_eq!(configer.get("server.context.path"), Ok(&string("/api")));
+    assert_eq!(configer.get("server.port"), Err(ConfigerError::NotFound));
+
+    let table = EnvSource::prefixed("APP_").with_separator("__").with_lowercase(false).load_from(env_vars);
+    assert_eq!(
+        ConfigerEnvironment::builder().with_table(table).build().unwrap().get("SERVER.PORT"),
+        Ok(&string("9090"))
+    );
+}
+
+#[test]
+fn test_env_source_conflicts_are_skipped() {
+    let table = EnvSource::prefixed("APP_").load_from(vars(&[("APP_SERVER_PORT", "9090"), ("APP_SERVER", "on")]));
+    let configer = ConfigerEnvironment::builder().with_table(table).build().unwrap();
+
+    // `APP_SERVER` sorts first
+    assert_eq!(configer.get("server"), Ok(&string("on")));
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_build_configer_with_env_source_overrides_file() {
+    env::set_var("CONFIGER_ENV_TESTS_SERVER__PORT", "9090");
+
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<PropertiesConfigReader>::default());
+
+    let configer = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(String::from("resources/testdata/application.properties"))
+        .with_env_source(EnvSource::prefixed("CONFIGER_ENV_TESTS_").with_separator("__"))
+        .build()
+        .unwrap();
+
+    env::remove_var("CONFIGER_ENV_TESTS_SERVER__PORT");
+
+    assert_eq!(configer.get("server.port"), Ok(&string("9090")));
+    assert_eq!(configer.get("server.host"), Ok(&string("127.0.0.1")));
+    // Only the prefixed variables are loaded
+    assert!(configer.get("CARGO_PKG_NAME").is_err());
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_env_source_allowed_and_denied() {
+    let env_vars = vars(&[("APP_PORT", "9090"), ("APP_DB_SECRET", "s3cr3t"), ("APP1", "x"), ("HOME", "/root")]);
+
+    let table = EnvSource::new().with_allowed(vec![String::from("APP_*")]).load_from(env_vars.clone());
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.get("APP_PORT"), Some(&string("9090")));
+
+    // The denied patterns win
+    let table = EnvSource::new()
+        .with_allowed(vec![String::from("APP_*"), String::from("HOME")])
+        .with_denied(vec![String::from("*_SECRET")])
+        .load_from(env_vars.clone());
+    assert_eq!(table.len(), 2);
+    assert!(table.contains_key("HOME"));
+    assert!(!table.contains_key("APP_DB_SECRET"));
+
+    let table = EnvSource::new().with_allowed(vec![String::from("APP?")]).load_from(env_vars);
+    assert_eq!(table.len(), 1);
+    assert!(table.contains_key("APP1"));
+}
+
+#[test]
+fn test_env_source_disabled() {
+    assert!(EnvSource::disabled().load_from(vars(&[("APP_PORT", "9090")])).is_empty());
+    assert!(EnvSource::new().with_enabled(false).load().is_empty());
+}
+
+#[test]
+fn test_env_source_with_vars() {
+    let injected = HashMap::from([
+        (String::from("APP_SERVER__PORT"), String::from("9090")),
+        (String::from("APP_NAME"), String::from("configer")),
+    ]);
+
+    let table = EnvSource::prefixed("APP_").with_separator("__").with_vars(injected).load();
+    let configer = ConfigerEnvironment::builder().with_table(table).build().unwrap();
+
+    assert_eq!(configer.get("server.port"), Ok(&string("9090")));
+    assert_eq!(configer.get("name"), Ok(&string("configer")));
+    assert!(configer.get("PATH").is_err());
+}
+
+#[test]
+fn test_build_configer_without_env_variables() {
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<PropertiesConfigReader>::default());
+
+    let configer = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(String::from("resources/testdata/application.properties"))
+        .with_env_enabled(false)
+        .build()
+        .unwrap();
+
+    assert_eq!(configer.get("server.host"), Ok(&string("127.0.0.1")));
+    assert!(configer.get("PATH").is_err());
+
+    let configer = ConfigerEnvironment::builder().with_env_enabled(false).build().unwrap();
+    assert!(configer.get("PATH").is_err());
+}
+
+#[test]
+fn test_build_configer_with_injected_env_variables() {
+    let injected = HashMap::from([
+        (String::from("CONFIGER_PORT"), String::from("9090")),
+        (String::from("CONFIGER_SECRET"), String::from("s3cr3t")),
+    ]);
+
+    let configer = ConfigerEnvironment::builder()
+        .with_env_source(
+            EnvSource::new()
+                .with_vars(injected)
+                .with_denied(vec![String::from("*_SECRET")]),
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(configer.get("CONFIGER_PORT"), Ok(&string("9090")));
+    assert!(configer.get("CONFIGER_SECRET").is_err());
+    // The process environment is not loaded
+    assert!(configer.get("PATH").is_err());
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_infer_node_scalars() {
+    assert_eq!(infer_node("true"), Node::Boolean(true));
+    assert_eq!(infer_node("FALSE"), Node::Boolean(false));
+    assert_eq!(infer_node("9090"), Node::Int64(9090));
+    assert_eq!(infer_node("-42"), Node::Int64(-42));
+    assert_eq!(infer_node("18446744073709551615"), Node::IntU64(u64::MAX));
+    assert_eq!(infer_node("-9223372036854775809"), Node::Int128(i64::MIN as i128 - 1));
+    assert_eq!(infer_node("340282366920938463463374607431768211455"), Node::IntU128(u128::MAX));
+    assert_eq!(infer_node("3.8848"), Node::Float64(3.8848));
+    assert_eq!(infer_node("1e3"), Node::Float64(1000.0));
+
+    let datetime = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap().and_hms_opt(1, 2, 3).unwrap();
+    assert_eq!(infer_node("2024-03-15T01:02:03"), Node::DateTime(datetime));
+    assert_eq!(infer_node("2024-03-15 01:02:03"), Node::DateTime(datetime));
+    assert_eq!(
+        infer_node("2024-03-15T03:02:03+02:00"),
+        Node::OffsetDateTime(DateTime::parse_from_rfc3339("2024-03-15T03:02:03+02:00").unwrap())
+    );
+    assert_eq!(infer_node("2024-03-15"), Node::Date(NaiveDate::from_ymd_opt(2024, 3, 15).unwrap()));
+    assert_eq!(infer_node("01:02:03"), Node::Time(NaiveTime::from_hms_opt(1, 2, 3).unwrap()));
+
+    assert_eq!(infer_node("inf"), string("inf"));
+    assert_eq!(infer_node("NaN"), string("NaN"));
+    assert_eq!(infer_node("1.2.3"), string("1.2.3"));
+    assert_eq!(infer_node("v1"), string("v1"));
+    assert_eq!(infer_node(""), string(""));
+}
+
+#[test]
+fn test_infer_node_lists() {
+    assert_eq!(
+        infer_node("[a, 2,true]"),
+        Node::Array(vec![string("a"), Node::Int64(2), Node::Boolean(true)])
+    );
+    // Lists need the brackets
+    assert_eq!(infer_node("Hello, world"), string("Hello, world"));
+    assert_eq!(infer_node("a,b"), string("a,b"));
+    assert_eq!(
+        infer_node(r#"[1, "2", "a,b", [3.5]]"#),
+        Node::Array(vec![
+            Node::Int64(1),
+            string("2"),
+            string("a,b"),
+            Node::Array(vec![Node::Float64(3.5)]),
+        ])
+    );
+    assert_eq!(infer_node("[]"), Node::Array(vec![]));
+    // Unbalanced brackets stay strings
+    assert_eq!(infer_node("[1, 2"), string("[1, 2"));
+}
+
+#[test]
+fn test_env_source_infer_types() {
+    let env_vars = vars(&[
+        ("APP_SERVER__PORT", "9090"),
+        ("APP_SERVER__ENABLED", "true"),
+        ("APP_SERVER__HOSTS", "[a.com,b.com]"),
+        ("APP_VERSION", "1.0"),
+    ]);
+
+    let table = EnvSource::prefixed("APP_").with_separator("__").with_infer_types(true).load_from(env_vars.clone());
+    let configer = ConfigerEnvironment::builder().with_table(table).build().unwrap();
+
+    assert_eq!(configer.get("server.port"), Ok(&Node::Int64(9090)));
+    assert_eq!(configer.get("server.enabled"), Ok(&Node::Boolean(true)));
+    assert_eq!(configer.get("server.hosts[1]"), Ok(&string("b.com")));
+    assert_eq!(configer.get("version"), Ok(&Node::Float64(1.0)));
+
+    // Forced strings
+    let table = EnvSource::prefixed("APP_")
+        .with_separator("__")
+        .with_infer_types(true)
+        .with_string_vars(vec![String::from("APP_VERSION")])
+        .load_from(env_vars.clone());
+    let configer = ConfigerEnvironment::builder().with_table(table).build().unwrap();
+    assert_eq!(configer.get("version"), Ok(&string("1.0")));
+    assert_eq!(configer.get("server.port"), Ok(&Node::Int64(9090)));
+
+    // Off by default
+    let table = EnvSource::prefixed("APP_").with_separator("__").load_from(env_vars);
+    assert_eq!(table.get("version"), Some(&string("1.0")));
+}
+
+#[test]
+fn test_build_configer_with_inferred_env_override() {
+    let mut server = Table::new();
+    server.insert(String::from("port"), Node::Int64(8080));
+    let mut table = Table::new();
+    table.insert(String::from("server"), Node::Nested(server));
+
+    let injected = HashMap::from([(String::from("APP_SERVER__PORT"), String::from("9090"))]);
+    let configer = ConfigerEnvironment::builder()
+        .with_table(table)
+        .with_env_source(EnvSource::prefixed("APP_").with_separator("__").with_infer_types(true).with_vars(injected))
+        .build()
+        .unwrap();
+
+    assert_eq!(NodeConverter::try_int_i64(configer.get("server.port")), Some(&9090));
+}
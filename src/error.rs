@@ -26,11 +26,55 @@ pub enum ConfigerError {
     EmptyKey,
     NonNested,
     NotFound,
+    /// @since 0.7.0
+    NonArray,
+    /// @since 0.7.0
+    InvalidKey(String),
+    /// (index, len)
+    ///
+    /// @since 0.7.0
+    IndexOutOfBounds(usize, usize),
+    /// (path, message)
+    ///
+    /// @since 0.7.0
+    BindFailed(String, String),
+    /// @since 0.7.0
+    SerializeFailed(String),
+    /// The unresolvable key.
+    ///
+    /// @since 0.7.0
+    PlaceholderNotFound(String),
+    /// @since 0.7.0
+    InvalidPlaceholder(String),
+    /// The cycle path, e.g.: `a -> b -> a`.
+    ///
+    /// @since 0.7.0
+    PlaceholderCycle(String),
+    /// (path, lower variant, higher variant)
+    ///
+    /// @since 0.7.0
+    MergeConflict(String, String, String),
+    /// A malformed JSON Pointer, e.g.: `server/port` or `/a~2b`.
+    ///
+    /// @since 0.7.0
+    InvalidPointer(String),
+    /// A malformed JSON Patch operation.
+    ///
+    /// @since 0.7.0
+    InvalidPatch(String),
+    /// The JSON Pointer of the `test` operation that failed.
+    ///
+    /// @since 0.7.0
+    PatchTestFailed(String),
+    /// (pointer, error)
+    ///
+    /// @since 0.7.0
+    PatchFailed(String, Box<ConfigerError>),
 }
 
 impl fmt::Display for ConfigerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
+        match self {
             ConfigerError::EmptyKey => write!(f, "Key can't be empty"),
             ConfigerError::NonNested => {
                 write!(
@@ -39,6 +83,26 @@ impl fmt::Display for ConfigerError {
                 )
             }
             ConfigerError::NotFound => write!(f, "Not found"),
+            ConfigerError::NonArray => {
+                write!(
+                    f,
+                    "Attempted to set/get an indexed value on a non-array node"
+                )
+            }
+            ConfigerError::InvalidKey(key) => write!(f, "Invalid key:[{}]", key),
+            ConfigerError::IndexOutOfBounds(index, len) => {
+                write!(f, "Index out of bounds: the len is {} but the index is {}", len, index)
+            }
+            ConfigerError::BindFailed(path, message) => write!(f, "Failed to bind key:[{}], message: {}", path, message),
+            ConfigerError::SerializeFailed(message) => write!(f, "Failed to serialize, message: {}", message),
+            ConfigerError::PlaceholderNotFound(key) => write!(f, "Could not resolve placeholder key:[{}]", key),
+            ConfigerError::InvalidPlaceholder(placeholder) => write!(f, "Invalid placeholder:[{}]", placeholder),
+            ConfigerError::PlaceholderCycle(cycle) => write!(f, "Circular placeholder reference:[{}]", cycle),
+            ConfigerError::MergeConflict(path, dst, src) => write!(f, "Can't merge {} into {} at key:[{}]", src, dst, path),
+            ConfigerError::InvalidPointer(pointer) => write!(f, "Invalid JSON pointer:[{}]", pointer),
+            ConfigerError::InvalidPatch(message) => write!(f, "Invalid JSON patch, message: {}", message),
+            ConfigerError::PatchTestFailed(pointer) => write!(f, "JSON patch test failed at pointer:[{}]", pointer),
+            ConfigerError::PatchFailed(pointer, error) => write!(f, "Failed to patch pointer:[{}], {}", pointer, error),
         }
     }
 }
@@ -47,6 +111,45 @@ impl Error for ConfigerError {}
 
 // ----------------------------------------------------------------
 
+/// The failures of the lenient `NodeConverter::to_*` conversions.
+///
+/// @since 0.7.0
+#[derive(Debug, PartialEq)]
+pub enum ConversionError {
+    /// The node could not be looked up.
+    Lookup(ConfigerError),
+    /// (found variant, target type)
+    Unsupported(String, String),
+    /// (found variant, target type)
+    Overflow(String, String),
+    /// (found variant, target type, message)
+    InvalidValue(String, String, String),
+    /// (key, error)
+    AtKey(String, Box<ConversionError>),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConversionError::Lookup(error) => write!(f, "Failed to look up the node, message: {}", error),
+            ConversionError::Unsupported(found, target) => write!(f, "Can't convert {} to {}", found, target),
+            ConversionError::Overflow(found, target) => write!(f, "Can't convert {} to {}: out of range", found, target),
+            ConversionError::InvalidValue(found, target, message) => write!(f, "Can't convert {} to {}: {}", found, target, message),
+            ConversionError::AtKey(key, error) => write!(f, "Failed to convert key:[{}], {}", key, error),
+        }
+    }
+}
+
+impl Error for ConversionError {}
+
+impl From<ConfigerError> for ConversionError {
+    fn from(error: ConfigerError) -> Self {
+        ConversionError::Lookup(error)
+    }
+}
+
+// ----------------------------------------------------------------
+
 #[derive(Debug, PartialEq)]
 pub enum FileError {
     InvalidPath(String),
@@ -55,6 +158,16 @@ pub enum FileError {
     ReadFailed(String),
     IncorrectFormat(String),
     ParseFailed(String, String),
+    /// @since 0.7.0
+    WriterNotFound(String),
+    /// @since 0.7.0
+    WriteFailed(String),
+    /// @since 0.7.0
+    SerializeFailed(String, String),
+    /// @since 0.7.0
+    ResolveFailed(ConfigerError),
+    /// @since 0.7.0
+    MergeFailed(ConfigerError),
 }
 
 impl fmt::Display for FileError {
@@ -66,6 +179,11 @@ impl fmt::Display for FileError {
             FileError::ReadFailed(path) => write!(f, "Failed to read config file, path:[{}]", path),
             FileError::IncorrectFormat(reader_type) => write!(f, "Incorrect {} format: Missing table data.", reader_type),
             FileError::ParseFailed(reader_type, message) => write!(f, "Failed to parse {} file, message: {}", reader_type, message),
+            FileError::WriterNotFound(suffix) => write!(f, "Writer not found, suffix: {}", suffix),
+            FileError::WriteFailed(path) => write!(f, "Failed to write config file, path:[{}]", path),
+            FileError::SerializeFailed(writer_type, message) => write!(f, "Failed to serialize {} file, message: {}", writer_type, message),
+            FileError::ResolveFailed(error) => write!(f, "Failed to resolve placeholders, message: {}", error),
+            FileError::MergeFailed(error) => write!(f, "Failed to merge config layers, message: {}", error),
         }
     }
 }
@@ -0,0 +1,102 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use crate::domain::Node;
+use crate::env::Environment;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::FileError;
+use crate::reader::{ConfigReader, ConfigReaderRegistry, ReaderRegistry};
+use crate::reader::ini::{DuplicateKeyPolicy, INI, IniConfigReader};
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_ini_reader_read_from_path() {
+    let ini_reader = IniConfigReader::default();
+
+    let table = ini_reader.read_from_path("resources/testdata/configer.ini").unwrap();
+    let configer = ConfigerEnvironment::table(table);
+
+    assert_eq!(configer.get("name"), Ok(&Node::String("configer".to_string())));
+    assert_eq!(configer.get("server.host"), Ok(&Node::String("127.0.0.1".to_string())));
+    assert_eq!(configer.get("server.port"), Ok(&Node::String("8080".to_string())));
+    assert_eq!(configer.get("server.banner"), Ok(&Node::String("Hello; configer # not a comment".to_string())));
+    assert_eq!(configer.get("server.literal"), Ok(&Node::String("C:\\configer".to_string())));
+    assert_eq!(configer.get("server.tls.enabled"), Ok(&Node::String("true".to_string())));
+    assert_eq!(configer.get("server.tls.cert"), Ok(&Node::String("/etc/configer/cert.pem#fragment".to_string())));
+    assert_eq!(configer.get("database.url"), Ok(&Node::String("postgres://localhost:5432/configer".to_string())));
+}
+
+#[test]
+fn test_ini_reader_duplicate_key_policy() {
+    let content = "[server]\nport = 8080\n[database]\nport = 5432\n[server]\nport = 9090";
+
+    let last_wins = IniConfigReader::default().read_from_str(content).unwrap();
+    let configer = ConfigerEnvironment::table(last_wins);
+    assert_eq!(configer.get("server.port"), Ok(&Node::String("9090".to_string())));
+    assert_eq!(configer.get("database.port"), Ok(&Node::String("5432".to_string())));
+
+    let error_rvt = IniConfigReader::default()
+        .with_duplicate_key_policy(DuplicateKeyPolicy::Error)
+        .read_from_str(content);
+    assert_eq!(
+        error_rvt,
+        Err(FileError::ParseFailed(INI.to_string(), "line 6: duplicate key:[server.port]".to_string()))
+    );
+}
+
+#[test]
+fn test_ini_reader_parse_failed() {
+    let ini_reader = IniConfigReader::default();
+
+    assert_eq!(
+        ini_reader.read_from_str("[server\nport = 8080"),
+        Err(FileError::ParseFailed(INI.to_string(), "line 1: unterminated section header:[[server]".to_string()))
+    );
+    assert_eq!(
+        ini_reader.read_from_str("[server]\n\nport 8080"),
+        Err(FileError::ParseFailed(INI.to_string(), "line 3: missing `=` in line:[port 8080]".to_string()))
+    );
+    assert_eq!(
+        ini_reader.read_from_str("[server]\nbanner = \"unterminated"),
+        Err(FileError::ParseFailed(INI.to_string(), "line 2: unterminated quoted value:[\"unterminated]".to_string()))
+    );
+    assert_eq!(
+        ini_reader.read_from_str("[server]\ntls = off\n[server.tls]"),
+        Err(FileError::ParseFailed(INI.to_string(), "line 3: conflicting section:[server.tls]".to_string()))
+    );
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_build_configer_builder_with_cfg_path() {
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<IniConfigReader>::default());
+
+    assert!(registry.try_acquire("ini").is_some());
+    assert!(registry.try_acquire("cfg").is_some());
+
+    let configer = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(String::from("resources/testdata/configer.cfg"))
+        .build()
+        .unwrap();
+
+    assert_eq!(configer.get("server.port"), Ok(&Node::String("9090".to_string())));
+}
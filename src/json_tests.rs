@@ -0,0 +1,171 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use chrono::NaiveDateTime;
+
+use crate::domain::{Node, Table};
+use crate::domain::converter::NodeConverter;
+use crate::env::Environment;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::FileError;
+use crate::reader::{ConfigReader, ConfigReaderRegistry, ReaderRegistry};
+use crate::reader::json::{JSON, JsonConfigReader};
+use crate::writer::ConfigWriter;
+use crate::writer::json::JsonConfigWriter;
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_json_reader_read_from_path() {
+    let json_reader = JsonConfigReader::default();
+
+    let path = "resources/testdata/configer-dev.json";
+    let json_from_path_rvt = json_reader.read_from_path(path);
+
+    if let Ok(table) = json_from_path_rvt {
+        assert!(table.contains_key("string_value"));
+        assert!(table.contains_key("floats"));
+        assert!(table.contains_key("table"));
+        assert!(table.contains_key("database"));
+
+        return;
+    }
+
+    panic!("Failed to read config file")
+}
+
+#[test]
+fn test_json_reader_node_mapping() {
+    let json_reader = JsonConfigReader::default();
+    let table = json_reader.read_from_path("resources/testdata/configer-dev.json").unwrap();
+    let configer = ConfigerEnvironment::table(table);
+
+    assert_eq!(configer.get("integer_value"), Ok(&Node::Int64(9527)));
+    assert_eq!(configer.get("float_value"), Ok(&Node::Float64(3.8848)));
+    assert_eq!(configer.get("none_value"), Ok(&Node::None));
+    assert_eq!(configer.get("table.table_bool"), Ok(&Node::Boolean(true)));
+
+    assert_eq!(configer.get("numbers.i64_min"), Ok(&Node::Int64(i64::MIN)));
+    assert_eq!(configer.get("numbers.u64_max"), Ok(&Node::IntU64(u64::MAX)));
+    assert_eq!(configer.get("numbers.i128"), Ok(&Node::Int128(i64::MIN as i128 - 1)));
+    assert_eq!(configer.get("numbers.u128"), Ok(&Node::IntU128(u128::MAX)));
+    assert_eq!(configer.get("numbers.exponent"), Ok(&Node::Float64(1000.0)));
+
+    match NodeConverter::try_array(configer.get("endpoints")) {
+        Some(endpoints) => {
+            assert_eq!(endpoints.len(), 2);
+            assert!(matches!(endpoints[1], Node::Nested(_)));
+        }
+        _ => panic!("Failed to get key:[endpoints]")
+    }
+}
+
+#[test]
+fn test_json_reader_large_integers() {
+    let json_reader = JsonConfigReader::default();
+    let data = r#"{"key-1": "\"2\" 3", "big": [-0, 18446744073709551616, 1.5e3, "4"], "1e2": -170141183460469231731687303715884105729}"#;
+    let table = json_reader.read_from_str(data).unwrap();
+    let configer = ConfigerEnvironment::table(table);
+
+    assert_eq!(configer.get("key-1"), Ok(&Node::String("\"2\" 3".to_string())));
+    assert_eq!(configer.get("big[0]"), Ok(&Node::Int64(0)));
+    assert_eq!(configer.get("big[1]"), Ok(&Node::Int128(u64::MAX as i128 + 1)));
+    assert_eq!(configer.get("big[2]"), Ok(&Node::Float64(1500.0)));
+    assert_eq!(configer.get("big[3]"), Ok(&Node::String("4".to_string())));
+    // Beyond `i128`
+    assert_eq!(configer.get("1e2"), Ok(&Node::Float64(-1.7014118346046923e38)));
+}
+
+#[test]
+fn test_json_reader_incorrect_format() {
+    let json_reader = JsonConfigReader::default();
+
+    assert_eq!(
+        json_reader.read_from_str("[1, 2, 3]"),
+        Err(FileError::IncorrectFormat(JSON.to_string()))
+    );
+    assert_eq!(
+        json_reader.read_from_str("\"configer\""),
+        Err(FileError::IncorrectFormat(JSON.to_string()))
+    );
+    assert!(matches!(
+        json_reader.read_from_str("{\"unclosed\": "),
+        Err(FileError::ParseFailed(_, _))
+    ));
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_build_configer_builder_with_registry_path_and_profiles() {
+    let path = "resources/testdata/config.json";
+
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<JsonConfigReader>::default());
+
+    let builder_rvt = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(path.to_string())
+        .with_profiles(vec![String::from("dev")])
+        .build();
+
+    if let Ok(configer) = builder_rvt {
+        // config-dev.json
+        assert!(NodeConverter::try_array(configer.get("database.servers")).is_some());
+        assert_eq!(configer.get("integer_value"), Ok(&Node::Int64(9528)));
+        // config.json
+        assert_eq!(configer.get("boolean_value"), Ok(&Node::Boolean(true)));
+
+        return;
+    }
+
+    panic!("Failed to read config-dev.json file")
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_json_writer_round_trip() {
+    let json_reader = JsonConfigReader::default();
+    let table = json_reader.read_from_path("resources/testdata/configer-dev.json").unwrap();
+
+    let json_writer = JsonConfigWriter::default();
+    let content = json_writer.write_to_string(&table).unwrap();
+
+    // Every digit of `Int128` and `IntU128` survives
+    assert_eq!(json_reader.read_from_str(&content), Ok(table));
+}
+
+#[test]
+fn test_json_writer_node_mapping() {
+    let now = NaiveDateTime::parse_from_str("2024-03-11 22:50:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+    let mut table = Table::new();
+    table.insert("none".to_string(), Node::None);
+    table.insert("nan".to_string(), Node::Float64(f64::NAN));
+    table.insert("time".to_string(), Node::DateTime(now));
+    table.insert("array".to_string(), Node::Array(vec![Node::Int32(1), Node::None]));
+
+    let content = JsonConfigWriter::default().write_to_string(&table).unwrap();
+    let written = JsonConfigReader::default().read_from_str(&content).unwrap();
+
+    assert_eq!(written.get("none"), Some(&Node::None));
+    assert_eq!(written.get("nan"), Some(&Node::None));
+    assert_eq!(written.get("time"), Some(&Node::String("2024-03-11T22:50:00".to_string())));
+    assert_eq!(written.get("array"), Some(&Node::Array(vec![Node::Int64(1), Node::None])));
+}
@@ -0,0 +1,119 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use crate::domain::{Node, Table};
+use crate::domain::converter::NodeConverter;
+use crate::domain::keypath::{KeyPath, Segment};
+use crate::env::{DynamicEnvironment, Environment};
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::ConfigerError;
+
+// ----------------------------------------------------------------
+
+fn key(key: &str) -> Segment {
+    Segment::Key(key.to_string())
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_key_path_parse() {
+    assert_eq!(
+        KeyPath::parse("io.github.photowey"),
+        Ok(KeyPath::new(vec![key("io"), key("github"), key("photowey")]))
+    );
+    assert_eq!(
+        KeyPath::parse("log.level.\"my.crate\""),
+        Ok(KeyPath::new(vec![key("log"), key("level"), key("my.crate")]))
+    );
+    assert_eq!(
+        KeyPath::parse("a.\"b.c\"[1][+].d"),
+        Ok(KeyPath::new(vec![key("a"), key("b.c"), Segment::Index(1), Segment::Append, key("d")]))
+    );
+    assert_eq!(
+        KeyPath::parse("\"say \\\"hi\\\"\".back\\\\slash.my\\.crate"),
+        Ok(KeyPath::new(vec![key("say \"hi\""), key("back\\slash"), key("my.crate")]))
+    );
+    assert_eq!(KeyPath::parse(""), Ok(KeyPath::default()));
+}
+
+#[test]
+fn test_key_path_parse_invalid() {
+    for path in ["\"unclosed", "\"a\"b", "a\"b\"", "a]", "a\\", "a[1", "a[x]", "[0]"] {
+        assert_eq!(KeyPath::parse(path), Err(ConfigerError::InvalidKey(path.to_string())));
+    }
+}
+
+#[test]
+fn test_key_path_display_round_trip() {
+    let path = KeyPath::from_segments(&["log", "level", "my.crate", "say \"hi\"", ""])
+        .with_index(0)
+        .with_append();
+
+    let text = path.to_string();
+    assert_eq!(text, "log.level.\"my.crate\".\"say \\\"hi\\\"\".\"\"[0][+]");
+    assert_eq!(text.parse::<KeyPath>(), Ok(path));
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_get_set_by_key_path() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+
+    let path = KeyPath::from_segments(&["hosts", "example.com", "port"]);
+    configer.set_key(&path, 8080.into()).unwrap();
+    configer.set_t("hosts.\"api.example.com\".port", 9090).unwrap();
+
+    assert_eq!(configer.get("hosts.\"example.com\".port"), Ok(&Node::Int32(8080)));
+    assert_eq!(configer.get("hosts.example\\.com.port"), Ok(&Node::Int32(8080)));
+    assert_eq!(configer.get(&String::from("hosts.\"example.com\".port")), Ok(&Node::Int32(8080)));
+    assert_eq!(configer.get_key(&path), Ok(&Node::Int32(8080)));
+
+    let api = KeyPath::from_segments(&["hosts", "api.example.com"]).with_key("port");
+    assert_eq!(configer.get_key(&api), Ok(&Node::Int32(9090)));
+
+    // Not nested under `example` => `com`
+    assert_eq!(configer.get("hosts.example.com.port"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.set("hosts.\"unclosed", 1.into()), Err(ConfigerError::InvalidKey("hosts.\"unclosed".to_string())));
+}
+
+#[test]
+fn test_set_key_get_key_quoted_segment_round_trip() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+
+    configer.set_key("a.\"b.c\"", Node::Int32(1)).unwrap();
+    assert_eq!(configer.get_key("a.\"b.c\""), Ok(&Node::Int32(1)));
+    assert_eq!(configer.get_key(&KeyPath::from_segments(&["a", "b.c"])), Ok(&Node::Int32(1)));
+    assert_eq!(configer.get_key("a.b.c"), Err(ConfigerError::NotFound));
+
+    // Stored under the single key `b.c`, and recorded so in the `runtime` source
+    let a = NodeConverter::try_nested(configer.get_key("a")).unwrap();
+    assert_eq!(a.get("b.c"), Some(&Node::Int32(1)));
+    let path: KeyPath = "a.\"b.c\"".parse().unwrap();
+    assert_eq!(configer.explain(&path).unwrap().winner.unwrap().value, Node::Int32(1));
+}
+
+#[test]
+fn test_dyn_environment() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    let environment: &mut dyn Environment = &mut configer;
+
+    environment.set("hosts.\"example.com\".port", 8080.into()).unwrap();
+    assert_eq!(environment.get("hosts.\"example.com\".port"), Ok(&Node::Int32(8080)));
+}
@@ -0,0 +1,231 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use crate::domain::{Node, Table};
+use crate::env::Environment;
+use crate::env::layer::{Origin, SourceKind};
+use crate::env::source::EnvSource;
+use crate::env::standard::ConfigerEnvironment;
+use crate::reader::{ConfigReader, ConfigReaderRegistry, ReaderRegistry};
+use crate::reader::dotenv::DotenvConfigReader;
+use crate::reader::ini::IniConfigReader;
+use crate::reader::properties::PropertiesConfigReader;
+use crate::test_support::string;
+
+// ----------------------------------------------------------------
+
+fn file_origin(path: &str, line: usize) -> Origin {
+    Origin {
+        source: path.to_string(),
+        kind: SourceKind::File,
+        line: Some(line),
+        variable: None,
+    }
+}
+
+fn properties_configer(env_vars: &[(&str, &str)]) -> ConfigerEnvironment {
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<PropertiesConfigReader>::default());
+
+    let env_vars: HashMap<String, String> = env_vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+
+    ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(String::from("resources/testdata/application.properties"))
+        .with_profiles(vec![String::from("dev")])
+        .with_env_source(EnvSource::prefixed("APP_").with_separator("__").with_vars(env_vars))
+        .build()
+        .unwrap()
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_explain_file_profile_and_env() {
+    let configer = properties_configer(&[("APP_SERVER__PORT", "7070")]);
+
+    let names: Vec<&str> = configer.sources().iter().map(|source| source.name()).collect();
+    assert_eq!(
+        names,
+        vec!["resources/testdata/application.properties", "resources/testdata/application-dev.properties", "env"]
+    );
+
+    let explanation = configer.explain("server.port").unwrap();
+    assert_eq!(explanation.value, Some(string("7070")));
+
+    let winner = explanation.winner.clone().unwrap();
+    assert_eq!(winner.value, string("7070"));
+    assert_eq!(winner.origin.kind, SourceKind::Env);
+    assert_eq!(winner.origin.variable, Some(String::from("APP_SERVER__PORT")));
+
+    let shadowed: Vec<(Node, Origin)> = explanation.shadowed.iter().map(|v| (v.value.clone(), v.origin.clone())).collect();
+    assert_eq!(
+        shadowed,
+        vec![
+            (string("9090"), file_origin("resources/testdata/application-dev.properties", 1)),
+            (string("8080"), file_origin("resources/testdata/application.properties", 3)),
+        ]
+    );
+    assert_eq!(shadowed[0].1.path(), Some("resources/testdata/application-dev.properties"));
+
+    assert_eq!(
+        explanation.to_string(),
+        "server.port = String(\"7070\")\n\
+         \x20 from env[APP_SERVER__PORT] => String(\"7070\")\n\
+         \x20 shadows resources/testdata/application-dev.properties:1 => String(\"9090\")\n\
+         \x20 shadows resources/testdata/application.properties:3 => String(\"8080\")"
+    );
+
+    // Not shadowed, the continued line is on the line it starts on
+    let explanation = configer.explain("spring.datasource.url").unwrap();
+    assert_eq!(explanation.winner.unwrap().origin, file_origin("resources/testdata/application.properties", 8));
+    assert!(explanation.shadowed.is_empty());
+
+    let explanation = configer.explain("missing").unwrap();
+    assert_eq!(explanation.value, None);
+    assert_eq!(explanation.winner, None);
+}
+
+#[test]
+fn test_explain_runtime_set_and_merge_table() {
+    let mut configer = properties_configer(&[]);
+
+    configer.set("server.port", Node::Int64(6060)).unwrap();
+    configer.set("server.hosts", Node::Array(vec![string("a"), string("b")])).unwrap();
+    configer.set("server.hosts[1]", string("c")).unwrap();
+
+    let explanation = configer.explain("server.port").unwrap();
+    let winner = explanation.winner.unwrap();
+    assert_eq!(winner.value, Node::Int64(6060));
+    assert_eq!(winner.origin.source, "runtime");
+    assert_eq!(winner.origin.kind, SourceKind::Runtime);
+    assert_eq!(explanation.shadowed.len(), 2);
+
+    // The whole array is recorded for an indexed key
+    let explanation = configer.explain("server.hosts[1]").unwrap();
+    assert_eq!(explanation.winner.unwrap().value, string("c"));
+    assert_eq!(configer.explain("server.hosts[0]").unwrap().winner.unwrap().value, string("a"));
+
+    let mut table = Table::new();
+    table.insert(String::from("logging"), Node::Nested(Table::from([(String::from("level"), string("warn"))])));
+    configer.merge_table(table);
+    configer.set("server.port", Node::Int64(5050)).unwrap();
+
+    let names: Vec<&str> = configer.sources().iter().skip(2).map(|source| source.name()).collect();
+    assert_eq!(names, vec!["env", "runtime", "merge_table", "runtime"]);
+
+    let explanation = configer.explain("logging.level").unwrap();
+    assert_eq!(explanation.winner.unwrap().origin.source, "merge_table");
+    assert_eq!(explanation.shadowed[0].origin, file_origin("resources/testdata/application-dev.properties", 2));
+
+    let explanation = configer.explain("server.port").unwrap();
+    let values: Vec<Node> = std::iter::once(explanation.winner.unwrap()).chain(explanation.shadowed).map(|v| v.value).collect();
+    assert_eq!(values, vec![Node::Int64(5050), Node::Int64(6060), string("9090"), string("8080")]);
+}
+
+#[test]
+fn test_runtime_sources_are_folded() {
+    let mut configer = properties_configer(&[]);
+    let planned = configer.sources().len();
+
+    let level = |value: &str| Table::from([(String::from("logging"), Node::Nested(Table::from([(String::from("level"), string(value))])))]);
+
+    // Consecutive `merge_table`s are one layer
+    configer.merge_table(level("warn"));
+    configer.merge_table(Table::from([(String::from("name"), string("configer"))]));
+    assert_eq!(configer.sources().len(), planned + 1);
+    assert_eq!(configer.explain("name").unwrap().winner.unwrap().origin.source, "merge_table");
+
+    // Interleaved with `set`, capped
+    for port in 0..100 {
+        configer.merge_table(level(&port.to_string()));
+        configer.set("server.port", Node::Int64(port)).unwrap();
+    }
+    assert!(configer.sources().len() <= planned + 8);
+    assert_eq!(configer.get("server.port"), Ok(&Node::Int64(99)));
+    assert_eq!(configer.get("logging.level"), Ok(&string("99")));
+    assert_eq!(configer.get("name"), Ok(&string("configer")));
+
+    assert_eq!(configer.reload(), Ok(Default::default()));
+    assert_eq!(configer.get("server.port"), Ok(&Node::Int64(99)));
+    assert_eq!(configer.get("logging.level"), Ok(&string("99")));
+}
+
+#[test]
+fn test_explain_resolved_placeholders() {
+    let mut table = Table::new();
+    table.insert(String::from("host"), string("localhost"));
+    table.insert(String::from("url"), string("${host}:8080"));
+
+    let mut configer = ConfigerEnvironment::builder().with_table(table).with_env_enabled(false).build().unwrap();
+    configer.resolve_placeholders().unwrap();
+
+    // The effective value is resolved, the sources keep the placeholder
+    let explanation = configer.explain("url").unwrap();
+    assert_eq!(explanation.value, Some(string("localhost:8080")));
+    assert_eq!(explanation.winner.unwrap().value, string("${host}:8080"));
+}
+
+#[test]
+fn test_explain_table_and_default_env() {
+    let mut table = Table::new();
+    table.insert(String::from("CONFIGER_LAYER_TEST_VAR"), string("table"));
+
+    std::env::set_var("CONFIGER_LAYER_TEST_VAR", "env");
+    let configer = ConfigerEnvironment::table(table);
+
+    let explanation = configer.explain("CONFIGER_LAYER_TEST_VAR").unwrap();
+    let winner = explanation.winner.unwrap();
+    assert_eq!(winner.origin.to_string(), "env[CONFIGER_LAYER_TEST_VAR]");
+    assert_eq!(explanation.shadowed[0].origin.kind, SourceKind::Table);
+    assert_eq!(explanation.shadowed[0].origin.to_string(), "table");
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_read_lines_from_str() {
+    let lines = PropertiesConfigReader::default().read_lines_from_str("# comment\na.b=1\nc=2\\\n  3\n\nd=4");
+    assert_eq!(
+        lines,
+        HashMap::from([(String::from("a.b"), 2), (String::from("c"), 3), (String::from("d"), 6)])
+    );
+
+    let lines = DotenvConfigReader::default().read_lines_from_str("A=1\nB=\"multi\nline\"\n# comment\nC.D=3");
+    assert_eq!(
+        lines,
+        HashMap::from([(String::from("A"), 1), (String::from("B"), 2), (String::from("\"C.D\""), 5)])
+    );
+
+    let lines = IniConfigReader::default().read_lines_from_str("root=1\n[server]\nport=8080\n[server.tls]\nenabled=true");
+    assert_eq!(
+        lines,
+        HashMap::from([
+            (String::from("root"), 1),
+            (String::from("server"), 2),
+            (String::from("server.port"), 3),
+            (String::from("server.tls"), 4),
+            (String::from("server.tls.enabled"), 5),
+        ])
+    );
+
+    // Unparsable content has no lines
+    assert!(IniConfigReader::default().read_lines_from_str("[server").is_empty());
+}
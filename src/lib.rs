@@ -24,13 +24,56 @@ pub mod env;
 pub mod error;
 /// @since 0.3.0
 pub mod reader;
+/// @since 0.7.0
+pub mod writer;
 
 // ----------------------------------------------------------------
 
+#[cfg(test)]
+mod test_support;
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
 mod converter_tests;
 #[cfg(test)]
+mod keypath_tests;
+#[cfg(test)]
+mod properties_tests;
+#[cfg(test)]
+mod dotenv_tests;
+#[cfg(test)]
+mod ini_tests;
+#[cfg(test)]
+mod placeholder_tests;
+#[cfg(test)]
+mod env_tests;
+#[cfg(test)]
+mod units_tests;
+#[cfg(test)]
+mod merge_tests;
+#[cfg(test)]
+mod layer_tests;
+#[cfg(test)]
+mod args_tests;
+#[cfg(test)]
+mod reload_tests;
+#[cfg(test)]
+mod shared_tests;
+#[cfg(test)]
+mod subscribe_tests;
+#[cfg(test)]
+mod diff_tests;
+#[cfg(test)]
+mod patch_tests;
+#[cfg(test)]
 #[cfg(feature = "usetoml")]
 mod toml_tests;
+#[cfg(test)]
+#[cfg(feature = "useyaml")]
+mod yaml_tests;
+#[cfg(test)]
+#[cfg(feature = "usejson")]
+mod json_tests;
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod serde_tests;
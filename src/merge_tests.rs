@@ -0,0 +1,217 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use crate::domain::{Node, Table};
+use crate::domain::merge::{ArrayStrategy, ConflictPolicy, merge_tables_with, MergeOptions, remove_tombstones, TOMBSTONE};
+use crate::env::Environment;
+use crate::env::source::EnvSource;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::{ConfigerError, FileError};
+use crate::test_support::{nested, string, table};
+
+// ----------------------------------------------------------------
+
+fn strings(values: &[&str]) -> Node {
+    Node::Array(values.iter().map(|v| Node::String(v.to_string())).collect())
+}
+
+fn hosts(options: &MergeOptions) -> Node {
+    let dst = table(vec![("hosts", strings(&["a", "b"]))]);
+    let src = table(vec![("hosts", strings(&["b", "c"]))]);
+
+    merge_tables_with(dst, src, options).unwrap().remove("hosts").unwrap()
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_merge_array_strategies() {
+    assert_eq!(hosts(&MergeOptions::default()), strings(&["a", "b", "b", "c"]));
+    assert_eq!(hosts(&MergeOptions::new().with_array_strategy(ArrayStrategy::Replace)), strings(&["b", "c"]));
+    assert_eq!(hosts(&MergeOptions::new().with_array_strategy(ArrayStrategy::Prepend)), strings(&["b", "c", "a", "b"]));
+    assert_eq!(hosts(&MergeOptions::new().with_array_strategy(ArrayStrategy::DedupeAppend)), strings(&["a", "b", "c"]));
+    assert_eq!(hosts(&MergeOptions::new().with_array_strategy(ArrayStrategy::MergeByIndex)), strings(&["b", "c"]));
+
+    // Per key path
+    let options = MergeOptions::new()
+        .with_array_strategy(ArrayStrategy::Replace)
+        .with_array_strategy_at("hosts", ArrayStrategy::DedupeAppend);
+    assert_eq!(hosts(&options), strings(&["a", "b", "c"]));
+}
+
+#[test]
+fn test_merge_by_index_merges_nested_elements() {
+    let dst = table(vec![(
+        "servers",
+        Node::Array(vec![
+            nested(vec![("host", Node::String("a".to_string())), ("port", Node::Int64(80))]),
+            nested(vec![("host", Node::String("b".to_string()))]),
+        ]),
+    )]);
+    let src = table(vec![(
+        "servers",
+        Node::Array(vec![
+            nested(vec![("port", Node::Int64(8080))]),
+            nested(vec![("host", Node::String(TOMBSTONE.to_string()))]),
+            nested(vec![("host", Node::String("c".to_string()))]),
+        ]),
+    )]);
+
+    let options = MergeOptions::new()
+        .with_array_strategy_at("servers", ArrayStrategy::MergeByIndex)
+        .with_tombstone(Some(Node::String(TOMBSTONE.to_string())));
+    let merged = merge_tables_with(dst, src, &options).unwrap();
+
+    assert_eq!(
+        merged.get("servers"),
+        Some(&Node::Array(vec![
+            nested(vec![("host", Node::String("a".to_string())), ("port", Node::Int64(8080))]),
+            nested(vec![]),
+            nested(vec![("host", Node::String("c".to_string()))]),
+        ]))
+    );
+}
+
+#[test]
+fn test_merge_tombstone() {
+    let dst = table(vec![
+        ("tls", Node::Nested(table(vec![("enabled", Node::Boolean(true))]))),
+        ("port", Node::Int64(80)),
+    ]);
+    let src = table(vec![
+        ("tls", Node::String(TOMBSTONE.to_string())),
+        ("missing", Node::String(TOMBSTONE.to_string())),
+        ("log", Node::Nested(table(vec![("file", Node::String(TOMBSTONE.to_string()))]))),
+    ]);
+
+    let options = MergeOptions::new().with_tombstone(Some(Node::String(TOMBSTONE.to_string())));
+
+    let mut merged = merge_tables_with(dst.clone(), src.clone(), &options).unwrap();
+    assert!(!merged.contains_key("tls"));
+    // Kept for the lower layers
+    assert_eq!(merged.get("missing"), Some(&Node::String(TOMBSTONE.to_string())));
+
+    remove_tombstones(&mut merged, &options);
+    assert_eq!(
+        merged,
+        table(vec![("port", Node::Int64(80)), ("log", Node::Nested(Table::new()))])
+    );
+
+    // Associative, the tombstone passes through an intermediate layer
+    let middle = merge_tables_with(Table::new(), src.clone(), &options).unwrap();
+    let mut merged = merge_tables_with(dst.clone(), middle, &options).unwrap();
+    remove_tombstones(&mut merged, &options);
+    assert!(!merged.contains_key("tls"));
+
+    // Custom and disabled tombstones
+    let src_none = table(vec![("port", Node::None)]);
+    let merged = merge_tables_with(dst.clone(), src_none, &MergeOptions::new().with_tombstone(Some(Node::None))).unwrap();
+    assert!(!merged.contains_key("port"));
+
+    // None by default
+    let merged = merge_tables_with(dst, src, &MergeOptions::default()).unwrap();
+    assert_eq!(merged.get("tls"), Some(&Node::String(TOMBSTONE.to_string())));
+}
+
+#[test]
+fn test_merge_tombstone_array_elements() {
+    let tombstone = || Node::String(TOMBSTONE.to_string());
+    let hosts = |hosts: Vec<Node>| table(vec![("hosts", Node::Array(hosts))]);
+    let dst = hosts(vec![string("a"), string("b"), string("c")]);
+    let src = hosts(vec![string("x"), tombstone()]);
+
+    let merge = |strategy: ArrayStrategy| {
+        let options = MergeOptions::new().with_array_strategy(strategy).with_tombstone(Some(tombstone()));
+        let mut merged = merge_tables_with(dst.clone(), src.clone(), &options).unwrap();
+        remove_tombstones(&mut merged, &options);
+        merged
+    };
+
+    // Dropped
+    assert_eq!(merge(ArrayStrategy::Append), hosts(vec![string("a"), string("b"), string("c"), string("x")]));
+    assert_eq!(merge(ArrayStrategy::Replace), hosts(vec![string("x")]));
+    // Removes the element at its index
+    assert_eq!(merge(ArrayStrategy::MergeByIndex), hosts(vec![string("x"), string("c")]));
+
+    // Nested in the elements too
+    let mut nested = table(vec![("servers", Node::Array(vec![hosts(vec![tombstone(), string("a")]).into()]))]);
+    remove_tombstones(&mut nested, &MergeOptions::new().with_tombstone(Some(tombstone())));
+    assert_eq!(nested, table(vec![("servers", Node::Array(vec![hosts(vec![string("a")]).into()]))]));
+}
+
+#[test]
+fn test_merge_conflict_policy() {
+    let dst = table(vec![("server", Node::Nested(table(vec![("port", Node::Int64(80))])))]);
+    let src = table(vec![("server", Node::Nested(table(vec![("port", Node::Array(vec![]))])))]);
+
+    let merged = merge_tables_with(dst.clone(), src.clone(), &MergeOptions::default()).unwrap();
+    assert_eq!(
+        merged,
+        table(vec![("server", Node::Nested(table(vec![("port", Node::Array(vec![]))])))])
+    );
+
+    let options = MergeOptions::new().with_conflict_policy(ConflictPolicy::Error);
+    assert_eq!(
+        merge_tables_with(dst.clone(), src, &options),
+        Err(ConfigerError::MergeConflict("server.port".to_string(), "Int64".to_string(), "Array".to_string()))
+    );
+
+    // Scalars of different variants don't conflict
+    let src = table(vec![("server", Node::Nested(table(vec![("port", Node::String("9090".to_string()))])))]);
+    assert!(merge_tables_with(dst, src, &options).is_ok());
+}
+
+#[test]
+fn test_build_configer_with_merge_options() {
+    let base = table(vec![("server", Node::Nested(table(vec![
+        ("port", Node::Int64(80)),
+        ("secret", Node::String("s3cr3t".to_string())),
+    ])))]);
+
+    // A higher layer removes a key by the tombstone
+    let env_vars = HashMap::from([(String::from("APP_SERVER__SECRET"), String::from(TOMBSTONE))]);
+    let configer = ConfigerEnvironment::builder()
+        .with_table(base.clone())
+        .with_env_source(EnvSource::prefixed("APP_").with_separator("__").with_vars(env_vars.clone()))
+        .with_tombstone(Some(Node::String(TOMBSTONE.to_string())))
+        .build()
+        .unwrap();
+
+    assert_eq!(configer.get("server.port"), Ok(&Node::Int64(80)));
+    assert_eq!(configer.get("server.secret"), Err(ConfigerError::NotFound));
+
+    // Only an opted-in tombstone removes a key
+    let configer = ConfigerEnvironment::builder()
+        .with_table(base.clone())
+        .with_env_source(EnvSource::prefixed("APP_").with_separator("__").with_vars(env_vars))
+        .build()
+        .unwrap();
+
+    assert_eq!(configer.get("server.secret"), Ok(&Node::String(TOMBSTONE.to_string())));
+
+    let env_vars = HashMap::from([(String::from("APP_SERVER__PORT__HTTP"), String::from("8080"))]);
+    let rvt = ConfigerEnvironment::builder()
+        .with_table(base)
+        .with_env_source(EnvSource::prefixed("APP_").with_separator("__").with_vars(env_vars))
+        .with_merge_options(MergeOptions::new().with_conflict_policy(ConflictPolicy::Error))
+        .build();
+
+    assert!(matches!(rvt, Err(FileError::MergeFailed(ConfigerError::MergeConflict(_, _, _)))));
+}
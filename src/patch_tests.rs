@@ -0,0 +1,239 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use crate::domain::{Node, Table};
+use crate::domain::merge::TOMBSTONE;
+use crate::domain::patch::{apply_json_patch, apply_merge_patch, parse_json_patch, PatchOp};
+use crate::env::Environment;
+use crate::env::layer::SourceKind;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::ConfigerError;
+use crate::test_support::{string, table};
+
+// ----------------------------------------------------------------
+
+fn config() -> Table {
+    table(vec![
+        ("server", Node::Nested(table(vec![("port", Node::Int64(8080)), ("host", string("localhost"))]))),
+        ("hosts", Node::Array(vec![string("a"), string("b")])),
+        ("a/b", Node::Int64(1)),
+        ("m~n", Node::Int64(2)),
+    ])
+}
+
+fn add(path: &str, value: Node) -> PatchOp {
+    PatchOp::Add { path: path.to_string(), value }
+}
+
+fn remove(path: &str) -> PatchOp {
+    PatchOp::Remove { path: path.to_string() }
+}
+
+fn replace(path: &str, value: Node) -> PatchOp {
+    PatchOp::Replace { path: path.to_string(), value }
+}
+
+fn test(path: &str, value: Node) -> PatchOp {
+    PatchOp::Test { path: path.to_string(), value }
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_apply_merge_patch() {
+    let mut target = config();
+    let patch = table(vec![
+        ("server", Node::Nested(table(vec![("port", Node::Int64(9090)), ("host", Node::None)]))),
+        ("hosts", Node::Array(vec![string("c")])),
+        ("a/b", Node::Nested(table(vec![("c", Node::Int64(3)), ("d", Node::None)]))),
+        ("missing", Node::None),
+    ]);
+
+    apply_merge_patch(&mut target, patch);
+
+    assert_eq!(
+        target,
+        table(vec![
+            ("server", Node::Nested(table(vec![("port", Node::Int64(9090))]))),
+            ("hosts", Node::Array(vec![string("c")])),
+            ("a/b", Node::Nested(table(vec![("c", Node::Int64(3))]))),
+            ("m~n", Node::Int64(2)),
+        ])
+    );
+}
+
+#[test]
+fn test_apply_json_patch() {
+    let mut target = config();
+    let ops = vec![
+        test("/server/port", Node::Int32(8080)),
+        replace("/server/port", Node::Int64(9090)),
+        add("/hosts/-", string("c")),
+        add("/hosts/0", string("z")),
+        remove("/hosts/1"),
+        PatchOp::Move { from: "/server/host".to_string(), path: "/host".to_string() },
+        PatchOp::Copy { from: "/hosts".to_string(), path: "/server/hosts".to_string() },
+        remove("/a~1b"),
+        replace("/m~0n", Node::Int64(3)),
+    ];
+
+    apply_json_patch(&mut target, &ops).unwrap();
+
+    assert_eq!(
+        target,
+        table(vec![
+            (
+                "server",
+                Node::Nested(table(vec![
+                    ("port", Node::Int64(9090)),
+                    ("hosts", Node::Array(vec![string("z"), string("b"), string("c")])),
+                ])),
+            ),
+            ("hosts", Node::Array(vec![string("z"), string("b"), string("c")])),
+            ("host", string("localhost")),
+            ("m~n", Node::Int64(3)),
+        ])
+    );
+}
+
+#[test]
+fn test_apply_json_patch_is_atomic() {
+    let mut target = config();
+    let ops = vec![replace("/server/port", Node::Int64(9090)), test("/server/host", string("example.com"))];
+
+    assert_eq!(
+        apply_json_patch(&mut target, &ops),
+        Err(ConfigerError::PatchTestFailed("/server/host".to_string()))
+    );
+    assert_eq!(target, config());
+}
+
+#[test]
+fn test_apply_json_patch_errors() {
+    let mut target = config();
+    let failed = |pointer: &str, err: ConfigerError| Err(ConfigerError::PatchFailed(pointer.to_string(), Box::new(err)));
+
+    assert_eq!(apply_json_patch(&mut target, &[replace("/server/missing", Node::Int64(1))]), failed("/server/missing", ConfigerError::NotFound));
+    assert_eq!(apply_json_patch(&mut target, &[add("/missing/key", Node::Int64(1))]), failed("/missing/key", ConfigerError::NotFound));
+    assert_eq!(apply_json_patch(&mut target, &[add("/hosts/3", string("x"))]), failed("/hosts/3", ConfigerError::IndexOutOfBounds(3, 2)));
+    assert_eq!(apply_json_patch(&mut target, &[remove("/hosts/-")]), failed("/hosts/-", ConfigerError::IndexOutOfBounds(2, 2)));
+    assert_eq!(
+        apply_json_patch(&mut target, &[remove("/hosts/01")]),
+        failed("/hosts/01", ConfigerError::InvalidKey("01".to_string()))
+    );
+    assert_eq!(apply_json_patch(&mut target, &[add("/server/port/x", Node::Int64(1))]), failed("/server/port/x", ConfigerError::NonNested));
+    assert_eq!(apply_json_patch(&mut target, &[remove("")]), failed("", ConfigerError::EmptyKey));
+    assert_eq!(apply_json_patch(&mut target, &[replace("", Node::Int64(1))]), failed("", ConfigerError::NonNested));
+    assert_eq!(
+        apply_json_patch(&mut target, &[remove("server")]),
+        Err(ConfigerError::InvalidPointer("server".to_string()))
+    );
+    assert_eq!(
+        apply_json_patch(&mut target, &[remove("/a~2b")]),
+        Err(ConfigerError::InvalidPointer("/a~2b".to_string()))
+    );
+    assert!(matches!(
+        apply_json_patch(&mut target, &[PatchOp::Move { from: "/server".to_string(), path: "/server/inner".to_string() }]),
+        Err(ConfigerError::InvalidPatch(_))
+    ));
+    assert_eq!(target, config());
+
+    // The whole table
+    apply_json_patch(&mut target, &[replace("", Node::Nested(table(vec![("k", Node::Int64(1))])))]).unwrap();
+    assert_eq!(target, table(vec![("k", Node::Int64(1))]));
+}
+
+#[test]
+fn test_parse_json_patch() {
+    let document = Node::Array(vec![
+        Node::Nested(table(vec![("op", string("add")), ("path", string("/a")), ("value", Node::None)])),
+        Node::Nested(table(vec![("op", string("move")), ("from", string("/a")), ("path", string("/b"))])),
+    ]);
+
+    assert_eq!(
+        parse_json_patch(&document),
+        Ok(vec![
+            add("/a", Node::None),
+            PatchOp::Move { from: "/a".to_string(), path: "/b".to_string() },
+        ])
+    );
+
+    let missing_value = Node::Array(vec![Node::Nested(table(vec![("op", string("replace")), ("path", string("/a"))]))]);
+    assert!(matches!(parse_json_patch(&missing_value), Err(ConfigerError::InvalidPatch(_))));
+
+    let unknown_op = Node::Array(vec![Node::Nested(table(vec![("op", string("merge")), ("path", string("/a"))]))]);
+    assert!(matches!(parse_json_patch(&unknown_op), Err(ConfigerError::InvalidPatch(_))));
+
+    assert!(matches!(parse_json_patch(&Node::Nested(Table::new())), Err(ConfigerError::InvalidPatch(_))));
+}
+
+#[test]
+#[cfg(feature = "usejson")]
+fn test_parse_json_patch_str() {
+    use crate::domain::patch::parse_json_patch_str;
+
+    let ops = parse_json_patch_str(r#"[{"op": "test", "path": "/server/port", "value": 8080}, {"op": "remove", "path": "/hosts/0"}]"#).unwrap();
+
+    assert_eq!(ops.len(), 2);
+    assert_eq!(ops[1], remove("/hosts/0"));
+    assert!(matches!(parse_json_patch_str("[{"), Err(ConfigerError::InvalidPatch(_))));
+}
+
+#[test]
+fn test_environment_apply_patches() {
+    let mut configer = ConfigerEnvironment::builder()
+        .with_table(config())
+        .with_tombstone(Some(string(TOMBSTONE)))
+        .with_env_enabled(false)
+        .build()
+        .unwrap();
+
+    configer.apply_json_patch(&[replace("/server/port", Node::Int64(9090)), remove("/server/host"), add("/hosts/-", string("c"))]).unwrap();
+    assert_eq!(configer.get("server.port"), Ok(&Node::Int64(9090)));
+    assert_eq!(configer.get("server.host"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.get("hosts[2]"), Ok(&string("c")));
+
+    // Atomic
+    let rvt = configer.apply_json_patch(&[replace("/server/port", Node::Int64(1)), test("/server/port", Node::Int64(2))]);
+    assert_eq!(rvt, Err(ConfigerError::PatchTestFailed("/server/port".to_string())));
+    assert_eq!(configer.get("server.port"), Ok(&Node::Int64(9090)));
+
+    configer.apply_merge_patch(table(vec![("server", Node::Nested(table(vec![("port", Node::None), ("ssl", Node::Boolean(true))])))]));
+    assert_eq!(configer.get("server.port"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.get("server.ssl"), Ok(&Node::Boolean(true)));
+
+    // Recorded like `set`, a removed key as a removal
+    let runtime = configer.sources().last().unwrap();
+    assert_eq!(runtime.kind(), SourceKind::Runtime);
+    assert_eq!(
+        runtime.table(),
+        &table(vec![
+            ("server", Node::Nested(table(vec![("ssl", Node::Boolean(true))]))),
+            ("hosts", Node::Array(vec![string("a"), string("b"), string("c")])),
+        ])
+    );
+    let keys = |keys: &[&str]| keys.iter().map(|key| key.to_string()).collect::<Vec<String>>();
+    assert_eq!(runtime.removals(), &[keys(&["server", "host"]), keys(&["server", "port"])]);
+
+    // Without a tombstone too
+    let mut configer = ConfigerEnvironment::builder().with_table(config()).with_env_enabled(false).build().unwrap();
+    configer.apply_json_patch(&[remove("/server/host")]).unwrap();
+    assert_eq!(configer.get("server.host"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.sources().last().unwrap().table(), &Table::new());
+    assert_eq!(configer.sources().last().unwrap().removals(), &[keys(&["server", "host"])]);
+}
@@ -0,0 +1,143 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::env;
+
+use crate::domain::{Node, Table};
+use crate::env::{DynamicEnvironment, Environment};
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::{ConfigerError, FileError};
+use crate::reader::{ConfigReaderRegistry, ReaderRegistry};
+use crate::reader::properties::PropertiesConfigReader;
+use crate::test_support::string;
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_get_resolved() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    configer.set_t("server.host", "localhost").unwrap();
+    configer.set_t("server.url", "http://${server.host}:${server.port:8080}").unwrap();
+    configer.set_t("server.tls", true).unwrap();
+    configer.set_t("server.banner", "tls=${server.tls}, port=${server.port:${default.port:80}}").unwrap();
+    configer.set_t("servers[0]", "${server.host}").unwrap();
+
+    // Lazy, the stored value is untouched
+    assert_eq!(configer.get("server.url"), Ok(&string("http://${server.host}:${server.port:8080}")));
+    assert_eq!(configer.get_resolved("server.url"), Ok(string("http://localhost:8080")));
+    assert_eq!(configer.get_resolved("server.banner"), Ok(string("tls=true, port=80")));
+    assert_eq!(configer.get_resolved("servers"), Ok(Node::Array(vec![string("localhost")])));
+    assert_eq!(configer.get_resolved("server.tls"), Ok(Node::Boolean(true)));
+
+    configer.set_t("server.port", 9090).unwrap();
+    assert_eq!(configer.get_resolved("server.url"), Ok(string("http://localhost:9090")));
+}
+
+#[test]
+fn test_resolve() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    configer.set_t("profile", "dev").unwrap();
+    configer.set_t("db.dev.url", "postgres://dev").unwrap();
+    configer.set_t("hosts.\"example.com\"", "93.184.216.34").unwrap();
+
+    assert_eq!(configer.resolve("${db.${profile}.url}"), Ok("postgres://dev".to_string()));
+    assert_eq!(configer.resolve("${hosts.\"example.com\"}"), Ok("93.184.216.34".to_string()));
+    assert_eq!(configer.resolve("\\${profile} is ${profile}"), Ok("${profile} is dev".to_string()));
+    assert_eq!(configer.resolve("${absent:}"), Ok("".to_string()));
+    assert_eq!(configer.resolve("${absent:a:b}"), Ok("a:b".to_string()));
+
+    assert_eq!(configer.resolve("${absent}"), Err(ConfigerError::PlaceholderNotFound("absent".to_string())));
+    assert_eq!(configer.resolve("${db}"), Err(ConfigerError::InvalidPlaceholder("${db}".to_string())));
+    assert_eq!(configer.resolve("${profile"), Err(ConfigerError::InvalidPlaceholder("${profile".to_string())));
+}
+
+#[test]
+fn test_placeholder_cycle() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    configer.set_t("a", "${b}").unwrap();
+    configer.set_t("b", "x${c.d}").unwrap();
+    configer.set_t("c.d", "${a}").unwrap();
+    configer.set_t("self", "${self}").unwrap();
+
+    assert_eq!(configer.get_resolved("a"), Err(ConfigerError::PlaceholderCycle("a -> b -> c.d -> a".to_string())));
+    assert_eq!(configer.get_resolved("c"), Err(ConfigerError::PlaceholderCycle("c.d -> a -> b -> c.d".to_string())));
+    assert_eq!(configer.get_resolved("self"), Err(ConfigerError::PlaceholderCycle("self -> self".to_string())));
+    assert_eq!(configer.resolve("${self}"), Err(ConfigerError::PlaceholderCycle("self -> self".to_string())));
+
+    // All or nothing
+    assert!(configer.resolve_placeholders().is_err());
+    assert_eq!(configer.get("a"), Ok(&string("${b}")));
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_build_configer_with_resolve_placeholders() {
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<PropertiesConfigReader>::default());
+
+    let configer = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(String::from("resources/testdata/placeholder.properties"))
+        .with_resolve_placeholders(true)
+        .build()
+        .unwrap();
+
+    assert_eq!(configer.get("server.url"), Ok(&string("http://127.0.0.1:9090")));
+    assert_eq!(configer.get("server.admin.url"), Ok(&string("http://127.0.0.1:9090/admin")));
+    assert_eq!(configer.get("server.timeout"), Ok(&string("30000ms")));
+    // Environment variables, set by cargo
+    assert_eq!(configer.get("app.name"), Ok(&string("configer")));
+}
+
+#[test]
+fn test_build_configer_with_resolve_placeholders_keeps_env_variables() {
+    env::set_var("CONFIGER_PLACEHOLDER_UNRELATED", "${CONFIGER_PLACEHOLDER_ABSENT}");
+    env::set_var("CONFIGER_PLACEHOLDER_HOST", "${server.host}");
+
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<PropertiesConfigReader>::default());
+
+    let configer = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(String::from("resources/testdata/placeholder.properties"))
+        .with_resolve_placeholders(true)
+        .build()
+        .unwrap();
+
+    // Kept as it is, instead of failing the build
+    assert_eq!(configer.get("CONFIGER_PLACEHOLDER_UNRELATED"), Ok(&string("${CONFIGER_PLACEHOLDER_ABSENT}")));
+    assert_eq!(configer.get("CONFIGER_PLACEHOLDER_HOST"), Ok(&string("127.0.0.1")));
+    assert_eq!(configer.get("server.url"), Ok(&string("http://127.0.0.1:9090")));
+}
+
+#[test]
+fn test_build_configer_with_resolve_placeholders_failed() {
+    let mut table = Table::new();
+    table.insert("url".to_string(), string("${absent.host}"));
+
+    let builder_rvt = ConfigerEnvironment::builder()
+        .with_table(table)
+        .with_resolve_placeholders(true)
+        .build();
+
+    assert!(matches!(
+        builder_rvt,
+        Err(FileError::ResolveFailed(ConfigerError::PlaceholderNotFound(key))) if key == "absent.host"
+    ));
+}
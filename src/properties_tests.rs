@@ -0,0 +1,126 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use crate::domain::{Node, Table};
+use crate::env::Environment;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::FileError;
+use crate::reader::{ConfigReader, ConfigReaderRegistry, ReaderRegistry};
+use crate::reader::properties::{PROPERTIES, PropertiesConfigReader};
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_properties_reader_read_from_path() {
+    let properties_reader = PropertiesConfigReader::default();
+
+    let path = "resources/testdata/application.properties";
+    let table = properties_reader.read_from_path(path).unwrap();
+    let configer = ConfigerEnvironment::table(table);
+
+    assert_eq!(configer.get("server.port"), Ok(&Node::String("8080".to_string())));
+    assert_eq!(configer.get("server.host"), Ok(&Node::String("127.0.0.1".to_string())));
+    assert_eq!(configer.get("server.context-path"), Ok(&Node::String("/api".to_string())));
+    assert_eq!(configer.get("spring.application.name"), Ok(&Node::String("configer".to_string())));
+    assert_eq!(
+        configer.get("spring.datasource.url"),
+        Ok(&Node::String("jdbc:mysql://localhost:3306/configer?useSSL=false".to_string()))
+    );
+    assert_eq!(configer.get("message.greeting"), Ok(&Node::String("Hello, 世界!".to_string())));
+    assert_eq!(configer.get("message.escaped"), Ok(&Node::String("tab\there".to_string())));
+    assert_eq!(configer.get("path.windows"), Ok(&Node::String("C:\\Program Files\\configer".to_string())));
+    assert_eq!(configer.get("key with spaces"), Ok(&Node::String("spaced".to_string())));
+}
+
+#[test]
+fn test_properties_reader_read_from_str() {
+    let properties_reader = PropertiesConfigReader::default();
+
+    let table = properties_reader
+        .read_from_str("empty=\nemoji=\\uD83D\\uDE00\nlast=1\nlast=2\n  # indented comment\ntrailing=value\\")
+        .unwrap();
+
+    assert_eq!(table.get("empty"), Some(&Node::String("".to_string())));
+    assert_eq!(table.get("emoji"), Some(&Node::String("😀".to_string())));
+    assert_eq!(table.get("last"), Some(&Node::String("2".to_string())));
+    assert_eq!(table.get("trailing"), Some(&Node::String("value".to_string())));
+}
+
+#[test]
+fn test_properties_reader_later_key_wins() {
+    let properties_reader = PropertiesConfigReader::default();
+
+    let table = properties_reader.read_from_str("a=1\na.b=2\nc.d=3\nc=4").unwrap();
+    let mut a = Table::new();
+    a.insert("b".to_string(), Node::String("2".to_string()));
+    assert_eq!(table.get("a"), Some(&Node::Nested(a)));
+    assert_eq!(table.get("c"), Some(&Node::String("4".to_string())));
+
+    let lines = properties_reader.read_lines_from_str("a=1\na.b=2\nc.d=3\nc=4");
+    assert_eq!(lines.get("a.b"), Some(&2));
+    assert_eq!(lines.get("c"), Some(&4));
+    assert_eq!(lines.get("c.d"), None);
+}
+
+#[test]
+fn test_properties_reader_parse_failed() {
+    let properties_reader = PropertiesConfigReader::default();
+
+    assert_eq!(
+        properties_reader.read_from_str("# comment\n\nbad=\\u12G4"),
+        Err(FileError::ParseFailed(PROPERTIES.to_string(), "line 3: malformed \\uxxxx encoding:[\\u12G4]".to_string()))
+    );
+    assert_eq!(
+        properties_reader.read_from_str("server..port=8080"),
+        Err(FileError::ParseFailed(PROPERTIES.to_string(), "line 1: invalid key:[server..port]".to_string()))
+    );
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_build_configer_builder_with_registry_path_and_profiles() {
+    let path = "resources/testdata/application.properties";
+
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<PropertiesConfigReader>::default());
+
+    let builder_rvt = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(path.to_string())
+        .with_profiles(vec![String::from("dev")])
+        .build();
+
+    if let Ok(configer) = builder_rvt {
+        // application-dev.properties
+        assert_eq!(configer.get("server.port"), Ok(&Node::String("9090".to_string())));
+        assert_eq!(configer.get("logging.level"), Ok(&Node::String("debug".to_string())));
+        // application.properties
+        assert_eq!(configer.get("server.host"), Ok(&Node::String("127.0.0.1".to_string())));
+
+        return;
+    }
+
+    panic!("Failed to read application-dev.properties file")
+}
+
+#[test]
+fn test_default_configer_acquires_properties_reader() {
+    let configer = ConfigerEnvironment::new();
+    assert!(configer.try_acquire("properties").is_some());
+}
@@ -18,19 +18,40 @@
 
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::domain::Table;
-use crate::error::FileError;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::domain::{Node, Table};
+use crate::env::layer::PropertySource;
+use crate::error::{ConfigerError, FileError};
+use crate::writer::{DATE_PATTERN, TIME_PATTERN};
 
 // ----------------------------------------------------------------
 
 #[cfg(feature = "usetoml")]
 pub mod toml;
+/// @since 0.7.0
+#[cfg(feature = "useyaml")]
+pub mod yaml;
+/// @since 0.7.0
+#[cfg(feature = "usejson")]
+pub mod json;
+/// @since 0.7.0
+pub mod properties;
+/// @since 0.7.0
+pub mod dotenv;
+/// @since 0.7.0
+pub mod ini;
 
 // ----------------------------------------------------------------
 
-pub trait ConfigReader {
+const TIMESTAMP_PATTERNS: [&str; 2] = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"];
+
+// ----------------------------------------------------------------
+
+/// @since 0.7.0 `Send + Sync`, to share a [`ConfigerEnvironment`](crate::env::standard::ConfigerEnvironment) across threads.
+pub trait ConfigReader: Send + Sync {
     fn name(&self) -> String;
     fn suffix(&self) -> String;
     fn supports(&self, suffix: &str) -> bool;
@@ -42,11 +63,30 @@ pub trait ConfigReader {
         let content = fs::read_to_string(canon).map_err(|_| FileError::ReadFailed(path.to_string()))?;
         self.read_from_str(&content)
     }
+
+    /// The 1-based line of each key path in `data`, e.g.: `server.port` => `3`, empty if the reader can't tell.
+    ///
+    /// @since 0.7.0
+    fn read_lines_from_str(&self, _data: &str) -> HashMap<String, usize> {
+        HashMap::new()
+    }
+
+    /// Reads the file at `path` into a [`PropertySource`] named by `path`, with the lines of its keys.
+    ///
+    /// @since 0.7.0
+    fn read_source_from_path(&self, path: &str) -> Result<PropertySource, FileError> {
+        let canon = PathBuf::from(path).canonicalize().map_err(|_| FileError::InvalidPath(path.to_string()))?;
+        let content = fs::read_to_string(canon).map_err(|_| FileError::ReadFailed(path.to_string()))?;
+        let table = self.read_from_str(&content)?;
+
+        Ok(PropertySource::file(path, table).with_lines(self.read_lines_from_str(&content)))
+    }
 }
 
 // ----------------------------------------------------------------
 
-pub trait ReaderRegistry {
+/// @since 0.7.0 `Send + Sync`
+pub trait ReaderRegistry: Send + Sync {
     fn register(&mut self, reader: Box<dyn ConfigReader>);
     fn try_acquire(&self, suffix: &str) -> Option<&dyn ConfigReader>;
     fn try_acquires(&self) -> Vec<&dyn ConfigReader>;
@@ -78,10 +118,92 @@ impl ReaderRegistry for ConfigReaderRegistry {
     }
 
     fn try_acquire(&self, suffix: &str) -> Option<&dyn ConfigReader> {
-        self.readers.get(suffix).map(|r| r.as_ref())
+        match self.readers.get(suffix) {
+            Some(reader) => Some(reader.as_ref()),
+            // @since 0.7.0: A reader may support more than one suffix, e.g.: `yaml` and `yml`.
+            None => self.readers.values().find(|r| r.supports(suffix)).map(|r| r.as_ref()),
+        }
     }
 
     fn try_acquires(&self) -> Vec<&dyn ConfigReader> {
         self.readers.values().map(|r| r.as_ref() as &dyn ConfigReader).collect()
     }
-}
\ No newline at end of file
+}
+
+// ----------------------------------------------------------------
+
+/// The suffix of the reader of the file at `path`: the extension, or the name of a dotfile that has none,
+/// e.g.: `toml` for `config.toml`, and `env` for `.env`.
+///
+/// @since 0.7.0
+pub fn file_suffix(path: &Path) -> Option<String> {
+    if let Some(extension) = path.extension() {
+        return Some(extension.to_string_lossy().to_string());
+    }
+
+    path.file_name()
+        .and_then(|name| name.to_string_lossy().strip_prefix('.').map(str::to_string))
+        .filter(|suffix| !suffix.is_empty())
+}
+
+/// Tries to parse an `ISO-8601` timestamp into its [`Node`], shared by the `YAML` reader and the inferred
+/// environment variables, like the `TOML` datetimes:
+///
+/// - offset date-time, e.g.: `2024-03-15T01:00:00Z` => [`Node::OffsetDateTime`]
+/// - local date-time, e.g.: `2024-03-15 01:00:00` => [`Node::DateTime`]
+/// - local date, e.g.: `2024-03-15` => [`Node::Date`]
+/// - local time, e.g.: `01:00:00` => [`Node::Time`]
+///
+/// @since 0.7.0
+pub fn try_parse_timestamp(value: &str) -> Option<Node> {
+    // Cheap guards, times always start with `HH:`, the others with `YYYY-`
+    let bytes = value.as_bytes();
+    if bytes.len() >= 8 && bytes[..2].iter().all(u8::is_ascii_digit) && bytes[2] == b':' {
+        return NaiveTime::parse_from_str(value, TIME_PATTERN).ok().map(Node::Time);
+    }
+    if bytes.len() < 10 || !bytes[..4].iter().all(u8::is_ascii_digit) || bytes[4] != b'-' {
+        return None;
+    }
+
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(Node::OffsetDateTime(datetime));
+    }
+
+    for pattern in TIMESTAMP_PATTERNS {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(value, pattern) {
+            return Some(Node::DateTime(datetime));
+        }
+    }
+
+    NaiveDate::parse_from_str(value, DATE_PATTERN).ok().map(Node::Date)
+}
+
+// ----------------------------------------------------------------
+
+/// Inserts `value` into `table` at the nested position described by `keys`, creating the intermediate tables.
+///
+/// Unlike a plain overwrite, a leaf never replaces a nested table and a nested table never replaces a leaf,
+/// both cases are reported as [`ConfigerError::NonNested`].
+///
+/// @since 0.7.0
+pub(crate) fn insert_nested(table: &mut Table, keys: &[&str], value: Node) -> Result<(), ConfigerError> {
+    match keys {
+        [] => Err(ConfigerError::EmptyKey),
+        [key] => match table.get(*key) {
+            Some(Node::Nested(_)) => Err(ConfigerError::NonNested),
+            _ => {
+                table.insert(key.to_string(), value);
+                Ok(())
+            }
+        },
+        [key, rest @ ..] => {
+            let nested = table
+                .entry(key.to_string())
+                .or_insert_with(|| Node::Nested(Table::new()));
+            match nested {
+                Node::Nested(nested_ref) => insert_nested(nested_ref, rest, value),
+                _ => Err(ConfigerError::NonNested),
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+
+use crate::domain::{Node, Table};
+use crate::domain::keypath::KeyPath;
+use crate::error::FileError;
+use crate::reader::ConfigReader;
+
+// ----------------------------------------------------------------
+
+pub const DOTENV: &str = "DOTENV";
+const DOTENV_READER_NAME: &str = "dotenv";
+const DOTENV_SUFFIX: &str = "env";
+const EXPORT_PREFIX: &str = "export ";
+
+// ----------------------------------------------------------------
+
+/// A [`ConfigReader`] for dotenv (`.env`) files.
+///
+/// Reads `KEY=VALUE` lines into a flat table of [`Node::String`], the same shape as the environment variables.
+///
+/// - `export KEY=VALUE` is accepted, the `export` prefix is dropped.
+/// - `#` starts a comment, in unquoted values only when preceded by whitespace.
+/// - Single-quoted values are taken literally.
+/// - Double-quoted values may span lines, and support `\n`, `\r`, `\t`, `\"`, `\\` and `\$` escapes.
+/// - `${KEY}` in unquoted and double-quoted values is replaced by the value of an earlier key in the same file,
+///   unknown keys are replaced by an empty string.
+///
+/// @since 0.7.0
+pub struct DotenvConfigReader {
+    name: String,
+    suffix: String,
+}
+
+impl DotenvConfigReader {
+    fn new() -> Self {
+        Self {
+            name: DOTENV_READER_NAME.to_string(),
+            suffix: DOTENV_SUFFIX.to_string(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl Default for DotenvConfigReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigReader for DotenvConfigReader {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn suffix(&self) -> String {
+        self.suffix.clone()
+    }
+
+    fn supports(&self, suffix: &str) -> bool {
+        self.suffix.eq(suffix)
+    }
+
+    fn read_from_str(&self, data: &str) -> Result<Table, FileError> {
+        let vars = parse_dotenv(data)?;
+        Ok(vars.into_iter().map(|(k, v)| (k, Node::String(v))).collect())
+    }
+
+    /// @since 0.7.0
+    fn read_lines_from_str(&self, data: &str) -> HashMap<String, usize> {
+        let mut parser = DotenvParser::new(data);
+        match parser.parse() {
+            Ok(()) => parser.lines,
+            Err(_) => HashMap::new(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Parses dotenv content into `KEY => VALUE` pairs.
+pub fn parse_dotenv(data: &str) -> Result<HashMap<String, String>, FileError> {
+    let mut parser = DotenvParser::new(data);
+
+    parser.parse()?;
+    Ok(parser.vars)
+}
+
+struct DotenvParser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    vars: HashMap<String, String>,
+    /// Key path => the line the key is on, @since 0.7.0
+    lines: HashMap<String, usize>,
+}
+
+impl<'a> DotenvParser<'a> {
+    fn new(data: &'a str) -> Self {
+        Self {
+            chars: data.chars().peekable(),
+            line: 1,
+            vars: HashMap::new(),
+            lines: HashMap::new(),
+        }
+    }
+
+    fn parse(&mut self) -> Result<(), FileError> {
+        loop {
+            self.skip_while(|c| c.is_whitespace());
+            match self.chars.peek() {
+                None => return Ok(()),
+                Some('#') => self.skip_line(),
+                Some(_) => self.parse_pair()?,
+            }
+        }
+    }
+
+    fn parse_pair(&mut self) -> Result<(), FileError> {
+        let line = self.line;
+        let mut key = self.take_while(|c| !c.is_whitespace() && c != '=' && c != '#');
+
+        if key == EXPORT_PREFIX.trim_end() && self.chars.peek().is_some_and(|c| *c == ' ' || *c == '\t') {
+            self.skip_while(|c| c == ' ' || c == '\t');
+            key = self.take_while(|c| !c.is_whitespace() && c != '=' && c != '#');
+        }
+
+        if !is_valid_key(&key) {
+            return Err(parse_failed(line, &format!("invalid key:[{}]", key)));
+        }
+
+        self.skip_while(|c| c == ' ' || c == '\t');
+        if self.chars.next_if_eq(&'=').is_none() {
+            return Err(parse_failed(line, &format!("missing `=` after key:[{}]", key)));
+        }
+        self.skip_while(|c| c == ' ' || c == '\t');
+
+        let value = match self.chars.peek() {
+            Some('\'') => {
+                self.chars.next();
+                let value = self.take_quoted('\'', line)?;
+                self.expect_line_end(line)?;
+                value
+            }
+            Some('"') => {
+                self.chars.next();
+                let value = self.take_quoted('"', line)?;
+                self.expect_line_end(line)?;
+                value
+            }
+            _ => self.take_unquoted(),
+        };
+
+        self.lines.insert(KeyPath::default().with_key(&key).to_string(), line);
+        self.vars.insert(key, value);
+        Ok(())
+    }
+
+    fn take_quoted(&mut self, quote: char, line: usize) -> Result<String, FileError> {
+        let mut value = String::new();
+
+        while let Some(c) = self.next_char() {
+            match c {
+                c if c == quote => return Ok(value),
+                '\\' if quote == '"' => match self.next_char() {
+                    Some('n') => value.push('\n'),
+                    Some('r') => value.push('\r'),
+                    Some('t') => value.push('\t'),
+                    Some(other) => value.push(other),
+                    None => break,
+                },
+                '$' if quote == '"' && self.chars.next_if_eq(&'{').is_some() => {
+                    let name = self.take_while(|c| c != '}' && c != '"' && c != '\n');
+                    if self.chars.next_if_eq(&'}').is_none() {
+                        value.push_str("${");
+                        value.push_str(&name);
+                        continue;
+                    }
+                    value.push_str(self.vars.get(&name).map(String::as_str).unwrap_or_default());
+                }
+                _ => value.push(c),
+            }
+        }
+
+        Err(parse_failed(line, "unterminated quoted value"))
+    }
+
+    fn take_unquoted(&mut self) -> String {
+        let mut raw = String::new();
+
+        while let Some(c) = self.chars.peek().copied() {
+            if c == '\n' || (c == '#' && raw.ends_with(|p: char| p.is_whitespace())) {
+                break;
+            }
+            raw.push(c);
+            self.chars.next();
+        }
+
+        self.skip_line();
+        self.expand(raw.trim())
+    }
+
+    fn expect_line_end(&mut self, line: usize) -> Result<(), FileError> {
+        self.skip_while(|c| c == ' ' || c == '\t' || c == '\r');
+        match self.chars.peek() {
+            None | Some('\n') | Some('#') => {
+                self.skip_line();
+                Ok(())
+            }
+            Some(_) => Err(parse_failed(line, "unexpected characters after quoted value")),
+        }
+    }
+
+    /// Expands `${KEY}` references to earlier keys.
+    fn expand(&self, raw: &str) -> String {
+        let mut out = String::with_capacity(raw.len());
+        let mut rest = raw;
+
+        while let Some(start) = rest.find("${") {
+            out.push_str(&rest[..start]);
+            match rest[start + 2..].find('}') {
+                Some(end) => {
+                    let name = &rest[start + 2..start + 2 + end];
+                    if let Some(value) = self.vars.get(name) {
+                        out.push_str(value);
+                    }
+                    rest = &rest[start + 2 + end + 1..];
+                }
+                None => {
+                    out.push_str(&rest[start..]);
+                    rest = "";
+                }
+            }
+        }
+        out.push_str(rest);
+
+        out
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let next = self.chars.next();
+        if next == Some('\n') {
+            self.line += 1;
+        }
+        next
+    }
+
+    fn skip_line(&mut self) {
+        while let Some(c) = self.next_char() {
+            if c == '\n' {
+                break;
+            }
+        }
+    }
+
+    fn skip_while(&mut self, predicate: impl Fn(char) -> bool) {
+        while self.chars.peek().is_some_and(|c| predicate(*c)) {
+            self.next_char();
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut taken = String::new();
+        while let Some(c) = self.chars.next_if(|c| predicate(*c)) {
+            taken.push(c);
+        }
+        taken
+    }
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-')
+        }
+        _ => false,
+    }
+}
+
+fn parse_failed(line: usize, message: &str) -> FileError {
+    FileError::ParseFailed(DOTENV.to_string(), format!("line {}: {}", line, message))
+}
@@ -0,0 +1,256 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::{HashMap, HashSet};
+
+use crate::domain::{Node, Table};
+use crate::domain::keypath::KeyPath;
+use crate::error::FileError;
+use crate::reader::ConfigReader;
+
+// ----------------------------------------------------------------
+
+pub const INI: &str = "INI";
+const INI_READER_NAME: &str = "ini";
+const CFG_SUFFIX: &str = "cfg";
+
+// ----------------------------------------------------------------
+
+/// How [`IniConfigReader`] treats a key that is defined twice in the same section.
+///
+/// @since 0.7.0
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum DuplicateKeyPolicy {
+    /// The last definition wins.
+    #[default]
+    LastWins,
+    /// Reading fails with [`FileError::ParseFailed`].
+    Error,
+}
+
+// ----------------------------------------------------------------
+
+/// A [`ConfigReader`] for `INI` files.
+///
+/// Registered under the `ini` suffix, and also [`supports`](ConfigReader::supports) the `cfg` suffix.
+///
+/// - `[section]` and `[section.sub]` headers are read into nested tables, keys before the first header go to the root.
+/// - `key = value` and `key: value` pairs, values are read as [`Node::String`].
+/// - `;` and `#` start a comment, in unquoted values only when preceded by whitespace.
+/// - Values may be single- or double-quoted, double-quoted values support `\"`, `\\`, `\n` and `\t` escapes.
+///
+/// @since 0.7.0
+pub struct IniConfigReader {
+    name: String,
+    suffix: String,
+    duplicate_key_policy: DuplicateKeyPolicy,
+}
+
+impl IniConfigReader {
+    fn new() -> Self {
+        Self {
+            name: INI_READER_NAME.to_string(),
+            suffix: INI_READER_NAME.to_string(),
+            duplicate_key_policy: DuplicateKeyPolicy::default(),
+        }
+    }
+
+    pub fn with_duplicate_key_policy(mut self, duplicate_key_policy: DuplicateKeyPolicy) -> Self {
+        self.duplicate_key_policy = duplicate_key_policy;
+        self
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl Default for IniConfigReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigReader for IniConfigReader {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn suffix(&self) -> String {
+        self.suffix.clone()
+    }
+
+    fn supports(&self, suffix: &str) -> bool {
+        self.suffix.eq(suffix) || CFG_SUFFIX.eq(suffix)
+    }
+
+    fn read_from_str(&self, data: &str) -> Result<Table, FileError> {
+        self.parse_ini(data).map(|(ctx, _)| ctx)
+    }
+
+    /// @since 0.7.0
+    fn read_lines_from_str(&self, data: &str) -> HashMap<String, usize> {
+        self.parse_ini(data).map(|(_, lines)| lines).unwrap_or_default()
+    }
+}
+
+impl IniConfigReader {
+    /// Parses `data` into a table, with the line of each section and key path.
+    fn parse_ini(&self, data: &str) -> Result<(Table, HashMap<String, usize>), FileError> {
+        let mut ctx = Table::new();
+        let mut lines = HashMap::new();
+        let mut section: Vec<String> = Vec::new();
+        let mut seen: HashSet<(Vec<String>, String)> = HashSet::new();
+
+        for (index, raw_line) in data.lines().enumerate() {
+            let line_number = index + 1;
+            let line = raw_line.trim();
+
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                section = parse_section(header).map_err(|message| parse_failed(line_number, &message))?;
+                section_table(&mut ctx, &section).map_err(|message| parse_failed(line_number, &message))?;
+                lines.entry(KeyPath::from_segments(&section).to_string()).or_insert(line_number);
+                continue;
+            }
+
+            let (key, value) = parse_pair(line).map_err(|message| parse_failed(line_number, &message))?;
+
+            if !seen.insert((section.clone(), key.clone())) && self.duplicate_key_policy == DuplicateKeyPolicy::Error {
+                return Err(parse_failed(line_number, &format!("duplicate key:[{}]", qualified(&section, &key))));
+            }
+
+            let table = section_table(&mut ctx, &section).map_err(|message| parse_failed(line_number, &message))?;
+            if let Some(Node::Nested(_)) = table.get(&key) {
+                return Err(parse_failed(line_number, &format!("conflicting key:[{}]", qualified(&section, &key))));
+            }
+            lines.insert(KeyPath::from_segments(&section).with_key(&key).to_string(), line_number);
+            table.insert(key, Node::String(value));
+        }
+
+        Ok((ctx, lines))
+    }
+}
+
+// ----------------------------------------------------------------
+
+fn parse_failed(line_number: usize, message: &str) -> FileError {
+    FileError::ParseFailed(INI.to_string(), format!("line {}: {}", line_number, message))
+}
+
+fn qualified(section: &[String], key: &str) -> String {
+    if section.is_empty() {
+        return key.to_string();
+    }
+
+    format!("{}.{}", section.join("."), key)
+}
+
+fn parse_section(header: &str) -> Result<Vec<String>, String> {
+    let (name, rest) = header
+        .split_once(']')
+        .ok_or_else(|| format!("unterminated section header:[[{}]", header))?;
+
+    let rest = rest.trim_start();
+    if !(rest.is_empty() || rest.starts_with(';') || rest.starts_with('#')) {
+        return Err(format!("unexpected characters after section header:[{}]", rest));
+    }
+
+    let section: Vec<String> = name.split('.').map(|s| s.trim().to_string()).collect();
+    if section.iter().any(String::is_empty) {
+        return Err(format!("invalid section name:[{}]", name));
+    }
+
+    Ok(section)
+}
+
+/// Returns the table of `section`, creating the intermediate tables.
+fn section_table<'a>(ctx: &'a mut Table, section: &[String]) -> Result<&'a mut Table, String> {
+    let mut table = ctx;
+    for (index, name) in section.iter().enumerate() {
+        let node = table
+            .entry(name.clone())
+            .or_insert_with(|| Node::Nested(Table::new()));
+        table = match node {
+            Node::Nested(nested) => nested,
+            _ => return Err(format!("conflicting section:[{}]", section[..=index].join("."))),
+        };
+    }
+
+    Ok(table)
+}
+
+fn parse_pair(line: &str) -> Result<(String, String), String> {
+    let separator = line
+        .find(['=', ':'])
+        .ok_or_else(|| format!("missing `=` in line:[{}]", line))?;
+
+    let key = line[..separator].trim();
+    if key.is_empty() {
+        return Err(format!("empty key in line:[{}]", line));
+    }
+
+    let value = parse_value(line[separator + 1..].trim())?;
+    Ok((key.to_string(), value))
+}
+
+fn parse_value(raw: &str) -> Result<String, String> {
+    let mut chars = raw.chars();
+
+    let quote = match chars.next() {
+        Some(q @ ('"' | '\'')) => q,
+        _ => return Ok(strip_inline_comment(raw).to_string()),
+    };
+
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c == quote => {
+                let rest = chars.as_str().trim_start();
+                if rest.is_empty() || rest.starts_with(';') || rest.starts_with('#') {
+                    return Ok(value);
+                }
+                return Err(format!("unexpected characters after quoted value:[{}]", rest));
+            }
+            '\\' if quote == '"' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some(other) => value.push(other),
+                None => break,
+            },
+            _ => value.push(c),
+        }
+    }
+
+    Err(format!("unterminated quoted value:[{}]", raw))
+}
+
+fn strip_inline_comment(raw: &str) -> &str {
+    let mut previous_whitespace = false;
+    for (index, c) in raw.char_indices() {
+        if (c == ';' || c == '#') && previous_whitespace {
+            return raw[..index].trim_end();
+        }
+        previous_whitespace = c.is_whitespace();
+    }
+
+    raw
+}
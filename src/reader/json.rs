@@ -0,0 +1,237 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::cell::Cell;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+
+use crate::domain::{Node, Table};
+use crate::error::FileError;
+use crate::reader::ConfigReader;
+
+// ----------------------------------------------------------------
+
+pub const JSON: &str = "JSON";
+const JSON_READER_NAME: &str = "json";
+
+// ----------------------------------------------------------------
+
+/// A [`ConfigReader`] for `JSON` files.
+///
+/// @since 0.7.0
+pub struct JsonConfigReader {
+    name: String,
+    suffix: String,
+}
+
+impl JsonConfigReader {
+    fn new() -> Self {
+        Self {
+            name: JSON_READER_NAME.to_string(),
+            suffix: JSON_READER_NAME.to_string(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl Default for JsonConfigReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigReader for JsonConfigReader {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn suffix(&self) -> String {
+        self.suffix.clone()
+    }
+
+    fn supports(&self, suffix: &str) -> bool {
+        self.suffix.eq(suffix)
+    }
+
+    fn read_from_str(&self, data: &str) -> Result<Table, FileError> {
+        match parse_json_node(data) {
+            Ok(Node::Nested(table)) => Ok(table),
+            Ok(_) => Err(FileError::IncorrectFormat(JSON.to_string())),
+            Err(err) => Err(FileError::ParseFailed(JSON.to_string(), err.to_string())),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Parses a `JSON` document into a [`Node`].
+///
+/// Integers are mapped onto the narrowest fitting variant, in the order of
+/// [`Node::Int64`], [`Node::IntU64`], [`Node::Int128`] and [`Node::IntU128`].
+/// Numbers with a fraction or an exponent, and integers beyond `u128`, are mapped onto [`Node::Float64`].
+pub(crate) fn parse_json_node(data: &str) -> Result<Node, serde_json::Error> {
+    let literals = number_literals(data);
+    let next = Cell::new(0);
+
+    let mut deserializer = serde_json::Deserializer::from_str(data);
+    let node = NodeSeed { literals: &literals, next: &next }.deserialize(&mut deserializer)?;
+    deserializer.end()?;
+
+    Ok(node)
+}
+
+fn integer_literal_to_node(literal: &str) -> Option<Node> {
+    if let Ok(i) = literal.parse::<i64>() {
+        return Some(Node::Int64(i));
+    }
+    if let Ok(u) = literal.parse::<u64>() {
+        return Some(Node::IntU64(u));
+    }
+    if let Ok(i) = literal.parse::<i128>() {
+        return Some(Node::Int128(i));
+    }
+
+    literal.parse::<u128>().ok().map(Node::IntU128)
+}
+
+// ----------------------------------------------------------------
+
+/// The number literals of `data`, in the order of the document.
+///
+/// `serde_json` reads an integer beyond `u64` as an `f64`, the literal keeps every digit.
+fn number_literals(data: &str) -> Vec<&str> {
+    let mut literals = Vec::new();
+    let mut chars = data.char_indices().peekable();
+    let mut in_string = false;
+
+    while let Some((start, c)) = chars.next() {
+        if in_string {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == '-' || c.is_ascii_digit() {
+            let mut end = start + c.len_utf8();
+            while let Some(&(index, c)) = chars.peek() {
+                if !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E') {
+                    break;
+                }
+                end = index + c.len_utf8();
+                chars.next();
+            }
+            literals.push(&data[start..end]);
+        }
+    }
+
+    literals
+}
+
+/// Builds a [`Node`] while `serde_json` walks the document, the numbers are visited in the order of `literals`.
+#[derive(Clone, Copy)]
+struct NodeSeed<'a, 'b> {
+    literals: &'b [&'a str],
+    next: &'b Cell<usize>,
+}
+
+impl NodeSeed<'_, '_> {
+    fn next_literal(&self) -> Option<&str> {
+        let index = self.next.get();
+        self.next.set(index + 1);
+
+        self.literals.get(index).copied()
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for NodeSeed<'_, '_> {
+    type Value = Node;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<Node, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for NodeSeed<'_, '_> {
+    type Value = Node;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Node, E> {
+        Ok(Node::Boolean(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Node, E> {
+        self.next_literal();
+        Ok(Node::Int64(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Node, E> {
+        self.next_literal();
+        Ok(i64::try_from(v).map_or(Node::IntU64(v), Node::Int64))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Node, E> {
+        let integer = self
+            .next_literal()
+            .filter(|literal| !literal.contains(['.', 'e', 'E']))
+            .and_then(integer_literal_to_node);
+
+        Ok(integer.unwrap_or(Node::Float64(v)))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Node, E> {
+        Ok(Node::String(v.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Node, E> {
+        Ok(Node::String(v))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Node, E> {
+        Ok(Node::None)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Node, A::Error> {
+        let mut array = Vec::new();
+        while let Some(node) = seq.next_element_seed(self)? {
+            array.push(node);
+        }
+
+        Ok(Node::Array(array))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Node, A::Error> {
+        let mut table = Table::new();
+        while let Some((key, node)) = map.next_entry_seed(PhantomData::<String>, self)? {
+            table.insert(key, node);
+        }
+
+        Ok(Node::Nested(table))
+    }
+}
@@ -0,0 +1,253 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::str::Chars;
+
+use crate::domain::{Node, Table};
+use crate::domain::keypath::KeyPath;
+use crate::error::FileError;
+use crate::reader::ConfigReader;
+
+// ----------------------------------------------------------------
+
+pub const PROPERTIES: &str = "PROPERTIES";
+const PROPERTIES_READER_NAME: &str = "properties";
+
+// ----------------------------------------------------------------
+
+/// A [`ConfigReader`] for Java-style `.properties` files.
+///
+/// Supports `key=value`, `key: value` and `key value` pairs, `#`/`!` comments,
+/// backslash line continuations and `\uXXXX` escapes.
+/// Dotted keys, e.g.: `server.port`, are read into nested tables.
+/// A later key wins over an earlier one it conflicts with, e.g.: `a=1` then `a.b=2` leaves only `a.b`.
+///
+/// @since 0.7.0
+pub struct PropertiesConfigReader {
+    name: String,
+    suffix: String,
+}
+
+impl PropertiesConfigReader {
+    fn new() -> Self {
+        Self {
+            name: PROPERTIES_READER_NAME.to_string(),
+            suffix: PROPERTIES_READER_NAME.to_string(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl Default for PropertiesConfigReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigReader for PropertiesConfigReader {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn suffix(&self) -> String {
+        self.suffix.clone()
+    }
+
+    fn supports(&self, suffix: &str) -> bool {
+        self.suffix.eq(suffix)
+    }
+
+    fn read_from_str(&self, data: &str) -> Result<Table, FileError> {
+        parse_properties(data).map(|(ctx, _)| ctx)
+    }
+
+    /// @since 0.7.0
+    fn read_lines_from_str(&self, data: &str) -> HashMap<String, usize> {
+        parse_properties(data).map(|(_, lines)| lines).unwrap_or_default()
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Parses `data` into a table, with the line of each key path.
+fn parse_properties(data: &str) -> Result<(Table, HashMap<String, usize>), FileError> {
+    let mut ctx = Table::new();
+    let mut lines = HashMap::new();
+
+    for (line_number, line) in logical_lines(data) {
+        let (raw_key, raw_value) = split_key_value(&line);
+        let key = unescape(raw_key).map_err(|message| parse_failed(line_number, &message))?;
+        let value = unescape(raw_value).map_err(|message| parse_failed(line_number, &message))?;
+
+        let keys: Vec<&str> = key.split('.').collect();
+        if keys.iter().any(|k| k.is_empty()) {
+            return Err(parse_failed(line_number, &format!("invalid key:[{}]", key)));
+        }
+
+        let key_path = KeyPath::from_segments(&keys).to_string();
+        // The lines of the keys it replaced, e.g.: `a.b` when `a=1` comes last
+        lines.retain(|line_key: &String, _| !line_key.strip_prefix(&key_path).is_some_and(|rest| rest.starts_with('.')));
+        insert_last_wins(&mut ctx, &keys, Node::String(value));
+        lines.insert(key_path, line_number);
+    }
+
+    Ok((ctx, lines))
+}
+
+/// Inserts `value` at `keys`, replacing whatever is in the way, a leaf or a nested table.
+fn insert_last_wins(table: &mut Table, keys: &[&str], value: Node) {
+    match keys {
+        [] => {}
+        [key] => {
+            table.insert(key.to_string(), value);
+        }
+        [key, rest @ ..] => {
+            let nested = table.entry(key.to_string()).or_insert_with(|| Node::Nested(Table::new()));
+            if !matches!(nested, Node::Nested(_)) {
+                *nested = Node::Nested(Table::new());
+            }
+            if let Node::Nested(nested) = nested {
+                insert_last_wins(nested, rest, value);
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+fn parse_failed(line_number: usize, message: &str) -> FileError {
+    FileError::ParseFailed(PROPERTIES.to_string(), format!("line {}: {}", line_number, message))
+}
+
+/// Joins continued lines, and drops blank and comment lines.
+///
+/// Returns the logical lines, with the number of the natural line they start on.
+fn logical_lines(data: &str) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+
+    for (index, natural) in data.lines().enumerate() {
+        let trimmed = natural.trim_start();
+
+        let (line_number, mut logical) = match current.take() {
+            Some(continued) => continued,
+            None => {
+                if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('!') {
+                    continue;
+                }
+                (index + 1, String::new())
+            }
+        };
+
+        if ends_with_continuation(trimmed) {
+            logical.push_str(&trimmed[..trimmed.len() - 1]);
+            current = Some((line_number, logical));
+        } else {
+            logical.push_str(trimmed);
+            lines.push((line_number, logical));
+        }
+    }
+
+    if let Some(continued) = current {
+        lines.push(continued);
+    }
+
+    lines
+}
+
+/// A line is continued when it ends with an odd number of backslashes.
+fn ends_with_continuation(line: &str) -> bool {
+    line.chars().rev().take_while(|c| *c == '\\').count() % 2 == 1
+}
+
+/// Splits a logical line at the first unescaped `=`, `:` or whitespace.
+fn split_key_value(line: &str) -> (&str, &str) {
+    let mut escaped = false;
+
+    for (index, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' => escaped = true,
+            '=' | ':' => return (&line[..index], line[index + 1..].trim_start()),
+            c if c.is_whitespace() => {
+                let rest = line[index..].trim_start();
+                let rest = rest
+                    .strip_prefix('=')
+                    .or_else(|| rest.strip_prefix(':'))
+                    .unwrap_or(rest);
+                return (&line[..index], rest.trim_start());
+            }
+            _ => {}
+        }
+    }
+
+    (line, "")
+}
+
+fn unescape(raw: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('f') => out.push('\u{000C}'),
+            Some('u') => {
+                let mut code = read_utf16_unit(&mut chars)?;
+                // A surrogate pair, e.g.: `\uD83D\uDE00`
+                if (0xD800..0xDC00).contains(&code) && chars.as_str().starts_with("\\u") {
+                    let mut lookahead = chars.clone();
+                    lookahead.nth(1);
+                    let low = read_utf16_unit(&mut lookahead)?;
+                    if (0xDC00..0xE000).contains(&low) {
+                        code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                        chars = lookahead;
+                    }
+                }
+                out.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+
+    Ok(out)
+}
+
+fn read_utf16_unit(chars: &mut Chars) -> Result<u32, String> {
+    let hex: String = chars.by_ref().take(4).collect();
+    u32::from_str_radix(&hex, 16)
+        .ok()
+        .filter(|_| hex.len() == 4)
+        .ok_or_else(|| format!("malformed \\uxxxx encoding:[\\u{}]", hex))
+}
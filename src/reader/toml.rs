@@ -16,12 +16,17 @@
 
 // ----------------------------------------------------------------
 
-use chrono::NaiveDateTime;
+use std::collections::HashMap;
+use std::ops::Range;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use toml::de::Error;
 use toml::Value;
-use toml::value::{Date, Datetime, Time};
+use toml::value::{Date, Datetime, Offset, Time};
+use toml_edit::{ImDocument, Item};
 
 use crate::domain::{Node, Table};
+use crate::domain::keypath::KeyPath;
 use crate::error::FileError;
 use crate::reader::ConfigReader;
 
@@ -87,6 +92,72 @@ impl ConfigReader for TomlConfigReader {
             Err(err) => Err(FileError::ParseFailed(TOML.to_string(), err.message().to_string()))
         }
     }
+
+    /// @since 0.7.0
+    fn read_lines_from_str(&self, data: &str) -> HashMap<String, usize> {
+        let mut lines = HashMap::new();
+        if let Ok(document) = ImDocument::parse(data) {
+            collect_table_lines(data, document.as_table(), &KeyPath::default(), &mut lines);
+        }
+
+        lines
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Collects the line of each key in `table`, by the span of the key, or else of its value.
+///
+/// The implicit tables, e.g.: `a` of `[a.b]`, have no span, they are on no line.
+fn collect_table_lines(data: &str, table: &toml_edit::Table, path: &KeyPath, lines: &mut HashMap<String, usize>) {
+    for (key, item) in table.iter() {
+        let child = path.clone().with_key(key);
+        insert_line(data, &child, table.key(key).and_then(|key| key.span()).or_else(|| item.span()), lines);
+
+        match item {
+            Item::Table(nested) => collect_table_lines(data, nested, &child, lines),
+            Item::ArrayOfTables(array) => {
+                for (index, nested) in array.iter().enumerate() {
+                    let element = child.clone().with_index(index);
+                    insert_line(data, &element, nested.span(), lines);
+                    collect_table_lines(data, nested, &element, lines);
+                }
+            }
+            Item::Value(value) => collect_value_lines(data, value, &child, lines),
+            Item::None => {}
+        }
+    }
+}
+
+fn collect_value_lines(data: &str, value: &toml_edit::Value, path: &KeyPath, lines: &mut HashMap<String, usize>) {
+    match value {
+        toml_edit::Value::InlineTable(table) => {
+            for (key, nested) in table.iter() {
+                let child = path.clone().with_key(key);
+                insert_line(data, &child, table.key(key).and_then(|key| key.span()).or_else(|| nested.span()), lines);
+                collect_value_lines(data, nested, &child, lines);
+            }
+        }
+        toml_edit::Value::Array(array) => {
+            for (index, element) in array.iter().enumerate() {
+                let child = path.clone().with_index(index);
+                insert_line(data, &child, element.span(), lines);
+                collect_value_lines(data, element, &child, lines);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn insert_line(data: &str, path: &KeyPath, span: Option<Range<usize>>, lines: &mut HashMap<String, usize>) {
+    if let Some(span) = span {
+        lines.insert(path.to_string(), line_at(data, span.start));
+    }
+}
+
+/// The 1-based line of the byte `offset` in `data`.
+fn line_at(data: &str, offset: usize) -> usize {
+    data[..offset.min(data.len())].matches('\n').count() + 1
 }
 
 // ----------------------------------------------------------------
@@ -97,9 +168,7 @@ pub fn toml_value_to_node(value: Value) -> Node {
         Value::Integer(i) => Node::Int64(i),
         Value::Float(f) => Node::Float64(f),
         Value::Boolean(b) => Node::Boolean(b),
-        Value::Datetime(datetime) => {
-            Node::DateTime(datetime_to_chrono_naive_date_time(datetime).unwrap())
-        }
+        Value::Datetime(datetime) => toml_datetime_to_node(datetime),
         Value::Array(arr) => Node::Array(arr.into_iter().map(toml_value_to_node).collect()),
         Value::Table(table) => {
             Node::Nested(table.into_iter().map(|(k, v)| (k, toml_value_to_node(v))).collect())
@@ -107,32 +176,58 @@ pub fn toml_value_to_node(value: Value) -> Node {
     }
 }
 
+/// Maps every `TOML` datetime form onto its [`Node`]:
+///
+/// - offset date-time => [`Node::OffsetDateTime`]
+/// - local date-time => [`Node::DateTime`]
+/// - local date => [`Node::Date`]
+/// - local time => [`Node::Time`]
+///
+/// Values `chrono` can't represent, e.g.: a leap second, are kept as their `TOML` string.
+///
+/// @since 0.7.0
+pub fn toml_datetime_to_node(datetime: Datetime) -> Node {
+    let node = match (datetime.date, datetime.time, datetime.offset) {
+        (Some(date), Some(time), Some(offset)) => datetime_to_offset_date_time(date, time, offset).map(Node::OffsetDateTime),
+        (Some(date), Some(time), None) => datetime_to_naive_time(date, time).map(Node::DateTime),
+        (Some(date), None, None) => date_to_naive(date).map(Node::Date),
+        (None, Some(time), None) => time_to_naive(time).map(Node::Time),
+        _ => None,
+    };
+
+    node.unwrap_or_else(|| Node::String(datetime.to_string()))
+}
+
+/// Converts a `TOML` local date-time or local date, `None` for the other forms.
 pub fn datetime_to_chrono_naive_date_time(datetime: Datetime) -> Option<NaiveDateTime> {
     match (datetime.date, datetime.time, datetime.offset) {
-        (Some(date), Some(time), _) => Some(datetime_to_naive_time(date, time)),
-        (Some(date), None, None) => Some(date_to_naive(date)),
-        (None, Some(time), None) => Some(time_to_naive(time)),
+        (Some(date), Some(time), None) => datetime_to_naive_time(date, time),
+        (Some(date), None, None) => date_to_naive(date).and_then(|date| date.and_hms_opt(0, 0, 0)),
         _ => None,
     }
 }
 
-pub fn date_to_naive(date: Date) -> NaiveDateTime {
-    NaiveDateTime::new(
-        chrono::NaiveDate::from_ymd_opt(date.year as i32, date.month as u32, date.day as u32).unwrap(),
-        chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
-    )
+/// @since 0.7.0 returns a [`NaiveDate`]
+pub fn date_to_naive(date: Date) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(date.year as i32, date.month as u32, date.day as u32)
+}
+
+/// @since 0.7.0 returns an `Option`
+pub fn datetime_to_naive_time(date: Date, time: Time) -> Option<NaiveDateTime> {
+    Some(NaiveDateTime::new(date_to_naive(date)?, time_to_naive(time)?))
 }
 
-pub fn datetime_to_naive_time(date: Date, time: Time) -> NaiveDateTime {
-    NaiveDateTime::new(
-        chrono::NaiveDate::from_ymd_opt(date.year as i32, date.month as u32, date.day as u32).unwrap(),
-        chrono::NaiveTime::from_hms_opt(time.hour as u32, time.minute as u32, time.second as u32).unwrap(),
-    )
+/// @since 0.7.0 returns a [`NaiveTime`]
+pub fn time_to_naive(time: Time) -> Option<NaiveTime> {
+    NaiveTime::from_hms_nano_opt(time.hour as u32, time.minute as u32, time.second as u32, time.nanosecond)
 }
 
-pub fn time_to_naive(time: Time) -> NaiveDateTime {
-    NaiveDateTime::new(
-        chrono::NaiveDate::from_ymd_opt(0, 0, 0).unwrap(),
-        chrono::NaiveTime::from_hms_opt(time.hour as u32, time.minute as u32, time.second as u32).unwrap(),
-    )
+/// @since 0.7.0
+pub fn datetime_to_offset_date_time(date: Date, time: Time, offset: Offset) -> Option<DateTime<FixedOffset>> {
+    let offset = match offset {
+        Offset::Z => FixedOffset::east_opt(0)?,
+        Offset::Custom { minutes } => FixedOffset::east_opt(minutes as i32 * 60)?,
+    };
+
+    datetime_to_naive_time(date, time)?.and_local_timezone(offset).single()
 }
\ No newline at end of file
@@ -0,0 +1,245 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::fmt;
+
+use serde::de::{self, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor};
+
+use crate::domain::{Node, Table};
+use crate::error::FileError;
+use crate::reader::{try_parse_timestamp, ConfigReader};
+
+// ----------------------------------------------------------------
+
+pub const YAML: &str = "YAML";
+const YAML_READER_NAME: &str = "yaml";
+const YML_SUFFIX: &str = "yml";
+
+// ----------------------------------------------------------------
+
+/// A [`ConfigReader`] for `YAML` files.
+///
+/// Registered under the `yaml` suffix, and also [`supports`](ConfigReader::supports) the `yml` suffix.
+///
+/// @since 0.7.0
+pub struct YamlConfigReader {
+    name: String,
+    suffix: String,
+}
+
+impl YamlConfigReader {
+    fn new() -> Self {
+        Self {
+            name: YAML_READER_NAME.to_string(),
+            suffix: YAML_READER_NAME.to_string(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl Default for YamlConfigReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigReader for YamlConfigReader {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn suffix(&self) -> String {
+        self.suffix.clone()
+    }
+
+    fn supports(&self, suffix: &str) -> bool {
+        self.suffix.eq(suffix) || YML_SUFFIX.eq(suffix)
+    }
+
+    fn read_from_str(&self, data: &str) -> Result<Table, FileError> {
+        let parsed_rvt = NodeSeed { data }.deserialize(serde_yaml::Deserializer::from_str(data));
+        match parsed_rvt {
+            Ok(Node::Nested(table)) => Ok(table),
+            // An empty document
+            Ok(Node::None) => Ok(Table::new()),
+            Ok(_) => Err(FileError::IncorrectFormat(YAML.to_string())),
+            Err(err) => Err(FileError::ParseFailed(YAML.to_string(), err.to_string())),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Builds a [`Node`] while `serde_yaml` walks `data`.
+///
+/// A plain scalar is borrowed from `data` as it is, a quoted one is borrowed without its quotes,
+/// so only the plain ones are read as timestamps, e.g.: `2024-03-15`, but not `"2024-03-15"`.
+#[derive(Clone, Copy)]
+struct NodeSeed<'de> {
+    data: &'de str,
+}
+
+impl<'de> NodeSeed<'de> {
+    fn is_quoted(&self, scalar: &str) -> bool {
+        let offset = (scalar.as_ptr() as usize).wrapping_sub(self.data.as_ptr() as usize);
+        match offset.checked_sub(1).and_then(|before| self.data.as_bytes().get(before)) {
+            Some(b'"' | b'\'') => offset <= self.data.len(),
+            _ => false,
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for NodeSeed<'de> {
+    type Value = Node;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<Node, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for NodeSeed<'de> {
+    type Value = Node;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a YAML value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Node, E> {
+        Ok(Node::Boolean(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Node, E> {
+        Ok(Node::Int64(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Node, E> {
+        Ok(i64::try_from(v).map_or(Node::IntU64(v), Node::Int64))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Node, E> {
+        Ok(Node::Int128(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Node, E> {
+        Ok(Node::IntU128(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Node, E> {
+        Ok(Node::Float64(v))
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Node, E> {
+        if self.is_quoted(v) {
+            return Ok(Node::String(v.to_string()));
+        }
+
+        Ok(try_parse_timestamp(v).unwrap_or_else(|| Node::String(v.to_string())))
+    }
+
+    /// An escaped, or a multi-line, scalar, never a timestamp.
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Node, E> {
+        Ok(Node::String(v.to_string()))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Node, E> {
+        Ok(Node::None)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Node, A::Error> {
+        let mut array = Vec::new();
+        while let Some(node) = seq.next_element_seed(self)? {
+            array.push(node);
+        }
+
+        Ok(Node::Array(array))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Node, A::Error> {
+        let mut table = Table::new();
+        while let Some((key, node)) = map.next_entry_seed(KeySeed, self)? {
+            table.insert(key, node);
+        }
+
+        Ok(Node::Nested(table))
+    }
+
+    /// A tagged value, e.g.: `!secret value`, is converted by its inner value.
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Node, A::Error> {
+        let (_tag, variant): (String, _) = data.variant()?;
+        variant.newtype_variant_seed(self)
+    }
+}
+
+/// Reads a mapping key, which must be a scalar.
+struct KeySeed;
+
+impl<'de> DeserializeSeed<'de> for KeySeed {
+    type Value = String;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<String, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for KeySeed {
+    type Value = String;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a scalar mapping key")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<String, E> {
+        Ok("~".to_string())
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<String, A::Error> {
+        let (_tag, variant): (String, _) = data.variant()?;
+        variant.newtype_variant_seed(self)
+    }
+}
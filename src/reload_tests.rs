@@ -0,0 +1,293 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::domain::{Node, Table};
+use crate::domain::merge::ArrayStrategy;
+use crate::domain::patch::PatchOp;
+use crate::env::Environment;
+use crate::env::reload::{ChangedKeys, ReloadEvent};
+use crate::env::standard::ConfigerEnvironment;
+use crate::env::subscribe::KeyChange;
+use crate::error::{ConfigerError, FileError};
+use crate::reader::{ConfigReaderRegistry, ReaderRegistry};
+use crate::reader::properties::PropertiesConfigReader;
+use crate::test_support::string;
+
+// ----------------------------------------------------------------
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+/// A fresh directory per test.
+fn test_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("configer-reload-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn build(dir: &Path) -> ConfigerEnvironment {
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<PropertiesConfigReader>::default());
+
+    ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(dir.join("config.properties").to_str().unwrap().to_string())
+        .with_profiles(vec![String::from("dev")])
+        .with_env_enabled(false)
+        .build()
+        .unwrap()
+}
+
+fn record_events(configer: &mut ConfigerEnvironment) -> Arc<Mutex<Vec<ReloadEvent>>> {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = events.clone();
+    configer.on_reload(move |event| {
+        recorded.lock().unwrap().push(match event {
+            ReloadEvent::Reloaded(changes) => ReloadEvent::Reloaded(changes.clone()),
+            ReloadEvent::Failed(err) => ReloadEvent::Failed(FileError::ReadFailed(err.to_string())),
+        })
+    });
+
+    events
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_poll_reload_config_and_profile() {
+    let dir = test_dir("poll");
+    fs::write(dir.join("config.properties"), "server.port=8080\nserver.host=localhost\nlog.level=info\n").unwrap();
+
+    let mut configer = build(&dir);
+    let events = record_events(&mut configer);
+    configer.set("runtime.key", string("kept")).unwrap();
+
+    assert_eq!(configer.poll_reload(), None);
+
+    // Rewriting the same content is not a change
+    fs::write(dir.join("config.properties"), "server.port=8080\nserver.host=localhost\nlog.level=info\n").unwrap();
+    assert_eq!(configer.poll_reload(), None);
+
+    fs::write(dir.join("config.properties"), "server.port=9090\nlog.level=info\nlog.file=app.log\n").unwrap();
+    let changes = ChangedKeys {
+        added: strings(&["log.file"]),
+        removed: strings(&["server.host"]),
+        modified: strings(&["server.port"]),
+    };
+    assert_eq!(configer.poll_reload(), Some(Ok(changes.clone())));
+    assert_eq!(configer.get("server.port"), Ok(&string("9090")));
+    assert_eq!(configer.get("server.host"), Err(ConfigerError::NotFound));
+    // Set at runtime, stays over the files
+    assert_eq!(configer.get("runtime.key"), Ok(&string("kept")));
+    assert_eq!(events.lock().unwrap().as_slice(), &[ReloadEvent::Reloaded(changes)]);
+
+    // A profile that didn't exist at build time
+    fs::write(dir.join("config-dev.properties"), "log.level=debug\n").unwrap();
+    let rvt = configer.poll_reload();
+    assert_eq!(rvt, Some(Ok(ChangedKeys { modified: strings(&["log.level"]), ..ChangedKeys::default() })));
+    assert_eq!(configer.get("log.level"), Ok(&string("debug")));
+
+    let names: Vec<&str> = configer.sources().iter().map(|source| source.name().rsplit('/').next().unwrap()).collect();
+    assert_eq!(names, vec!["config.properties", "config-dev.properties", "env", "runtime"]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_reload_failure_keeps_last_good() {
+    let dir = test_dir("failure");
+    fs::write(dir.join("config.properties"), "server.port=8080\n").unwrap();
+
+    let mut configer = build(&dir);
+    let events = record_events(&mut configer);
+
+    fs::write(dir.join("config.properties"), "server.port=9090\nserver..host=broken\n").unwrap();
+    assert!(matches!(configer.poll_reload(), Some(Err(FileError::ParseFailed(_, _)))));
+    assert_eq!(configer.get("server.port"), Ok(&string("8080")));
+    assert!(matches!(events.lock().unwrap()[0], ReloadEvent::Failed(_)));
+
+    // Not reported again until the file changes
+    assert_eq!(configer.poll_reload(), None);
+
+    fs::write(dir.join("config.properties"), "server.port=9090\n").unwrap();
+    assert!(matches!(configer.poll_reload(), Some(Ok(_))));
+    assert_eq!(configer.get("server.port"), Ok(&string("9090")));
+    assert_eq!(events.lock().unwrap().len(), 2);
+
+    // A removed config file fails too
+    fs::remove_file(dir.join("config.properties")).unwrap();
+    assert_eq!(
+        configer.poll_reload(),
+        Some(Err(FileError::InvalidPath(dir.join("config.properties").to_str().unwrap().to_string())))
+    );
+    assert_eq!(configer.get("server.port"), Ok(&string("9090")));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_reload_notifies_subscribers() {
+    let dir = test_dir("subscribe");
+    fs::write(dir.join("config.properties"), "server.port=8080\nlog.level=info\n").unwrap();
+
+    let mut configer = build(&dir);
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = changes.clone();
+    configer
+        .subscribe("log", move |change| recorded.lock().unwrap().push(change.clone()))
+        .unwrap();
+
+    fs::write(dir.join("config.properties"), "server.port=9090\nlog.level=debug\n").unwrap();
+    assert!(matches!(configer.poll_reload(), Some(Ok(_))));
+    assert_eq!(
+        *changes.lock().unwrap(),
+        vec![KeyChange {
+            key: String::from("log.level"),
+            old: Some(string("info")),
+            new: Some(string("debug")),
+        }]
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_reload_keeps_patches() {
+    let dir = test_dir("patch");
+    fs::write(dir.join("config.properties"), "server.port=8080\nserver.host=localhost\n").unwrap();
+
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<PropertiesConfigReader>::default());
+
+    // The removed keys are recorded without a tombstone
+    let mut configer = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(dir.join("config.properties").to_str().unwrap().to_string())
+        .with_env_enabled(false)
+        .build()
+        .unwrap();
+    configer
+        .apply_json_patch(&[
+            PatchOp::Replace { path: String::from("/server/port"), value: string("9090") },
+            PatchOp::Remove { path: String::from("/server/host") },
+        ])
+        .unwrap();
+
+    fs::write(dir.join("config.properties"), "server.port=7070\nserver.host=example.com\nlog.level=info\n").unwrap();
+    assert!(matches!(configer.poll_reload(), Some(Ok(_))));
+    assert_eq!(configer.get("server.port"), Ok(&string("9090")));
+    assert_eq!(configer.get("server.host"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.get("log.level"), Ok(&string("info")));
+
+    // A `Node::None` in a merge patch removes its key, the same
+    let patch = Table::from([(String::from("log"), Node::Nested(Table::from([(String::from("level"), Node::None)])))]);
+    configer.apply_merge_patch(patch);
+    assert_eq!(configer.reload().map(|changes| changes.is_empty()), Ok(true));
+    assert_eq!(configer.get("log.level"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.explain("log.level").unwrap().winner, None);
+
+    // Set again after the removal
+    configer.set("server.host", string("localhost")).unwrap();
+    assert_eq!(configer.reload().map(|changes| changes.is_empty()), Ok(true));
+    assert_eq!(configer.get("server.host"), Ok(&string("localhost")));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_reload_keeps_merged_tables() {
+    let hosts = |hosts: &[&str]| Table::from([(String::from("hosts"), Node::Array(hosts.iter().map(|host| string(host)).collect()))]);
+
+    for strategy in [ArrayStrategy::Append, ArrayStrategy::Replace] {
+        let mut configer = ConfigerEnvironment::builder()
+            .with_table(hosts(&["a"]))
+            .with_array_strategy(strategy)
+            .with_env_enabled(false)
+            .build()
+            .unwrap();
+
+        configer.merge_table(hosts(&["b"]));
+        assert_eq!(configer.get("hosts"), Ok(&Node::Array(vec![string("a"), string("b")])));
+        assert_eq!(configer.reload(), Ok(ChangedKeys::default()));
+
+        // Interleaved with `set`, beyond the cap of the runtime sources
+        for port in 0..10 {
+            configer.merge_table(hosts(&[&port.to_string()]));
+            configer.set("port", Node::Int64(port)).unwrap();
+        }
+        configer.set("hosts[0]", string("z")).unwrap();
+        let expected = configer.get("hosts").unwrap().clone();
+
+        assert_eq!(configer.reload(), Ok(ChangedKeys::default()));
+        assert_eq!(configer.get("hosts"), Ok(&expected));
+    }
+}
+
+#[test]
+fn test_reload_panicking_listener() {
+    let dir = test_dir("listener");
+    fs::write(dir.join("config.properties"), "server.port=8080\n").unwrap();
+
+    let mut configer = build(&dir);
+    configer.on_reload(|_| panic!("listener"));
+    let events = record_events(&mut configer);
+
+    fs::write(dir.join("config.properties"), "server.port=9090\n").unwrap();
+    // The later listeners are still called, and the reload keeps its result
+    assert!(matches!(configer.reload(), Ok(changes) if changes.modified == vec![String::from("server.port")]));
+    assert_eq!(configer.get("server.port"), Ok(&string("9090")));
+    assert_eq!(events.lock().unwrap().len(), 1);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_reload_without_plan() {
+    let mut configer = ConfigerEnvironment::builder().with_table(Table::new()).build().unwrap();
+    configer.register_table_with_env_variables(Table::from([(String::from("a"), string("b"))]));
+
+    assert_eq!(configer.reload(), Ok(ChangedKeys::default()));
+    assert_eq!(configer.poll_reload(), None);
+    assert_eq!(configer.get("a"), Ok(&string("b")));
+}
+
+#[test]
+fn test_changed_keys_between() {
+    let old = Table::from([
+        (String::from("hosts"), Node::Array(vec![string("a"), string("b")])),
+        (String::from("empty"), Node::Nested(Table::new())),
+    ]);
+    let new = Table::from([
+        (String::from("hosts"), Node::Array(vec![string("a"), string("c"), string("d")])),
+        (String::from("empty"), Node::Nested(Table::from([(String::from("k"), Node::Int64(1))]))),
+    ]);
+
+    let changes = ChangedKeys::between(&old, &new);
+    assert_eq!(changes.added, strings(&["empty.k", "hosts[2]"]));
+    assert_eq!(changes.removed, strings(&["empty"]));
+    assert_eq!(changes.modified, strings(&["hosts[1]"]));
+    assert!(!changes.is_empty());
+    assert!(ChangedKeys::between(&old, &old).is_empty());
+}
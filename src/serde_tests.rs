@@ -0,0 +1,382 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{merge_tables, Node, Table};
+use crate::domain::de::from_node;
+use crate::domain::ser::{FromSerialize, to_node, to_table};
+use crate::domain::units::ByteSize;
+use crate::env::{DynamicEnvironment, Environment};
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::ConfigerError;
+use crate::reader::ConfigReader;
+use crate::reader::properties::PropertiesConfigReader;
+
+// ----------------------------------------------------------------
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct ServerConfig {
+    host: String,
+    port: u16,
+    ports: Vec<u64>,
+    timeout: Option<f64>,
+    banner: Option<String>,
+    tls: TlsConfig,
+    mode: Mode,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct TlsConfig {
+    enabled: bool,
+    cert: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Mode {
+    Standalone,
+    Cluster { nodes: u8 },
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct AppConfig {
+    name: String,
+    server: ServerConfig,
+}
+
+// ----------------------------------------------------------------
+
+fn populate(configer: &mut ConfigerEnvironment) {
+    configer.set_t("name", "configer").unwrap();
+    configer.set_t("server.host", "127.0.0.1").unwrap();
+    configer.set_t("server.port", 8080_i64).unwrap();
+    configer.set_t("server.ports[0]", 8080_i32).unwrap();
+    configer.set_t("server.ports[1]", 8081_u32).unwrap();
+    configer.set_t("server.ports[2]", 8082_i128).unwrap();
+    configer.set_t("server.timeout", 3_i32).unwrap();
+    configer.set("server.banner", Node::None).unwrap();
+    configer.set_t("server.tls.enabled", true).unwrap();
+    configer.set_t("server.mode", "standalone").unwrap();
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_get_as() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    populate(&mut configer);
+
+    let server: ServerConfig = configer.get_as("server").unwrap();
+    assert_eq!(
+        server,
+        ServerConfig {
+            host: "127.0.0.1".to_string(),
+            port: 8080,
+            ports: vec![8080, 8081, 8082],
+            timeout: Some(3.0),
+            banner: None,
+            tls: TlsConfig { enabled: true, cert: None },
+            mode: Mode::Standalone,
+        }
+    );
+
+    assert!(configer.get_as::<u8>("server.ports").is_err());
+    assert_eq!(configer.get_as::<i32>("server.ports[1]"), Ok(8081));
+    assert_eq!(configer.get_as::<HashMap<String, bool>>("server.tls"), Ok(HashMap::from([("enabled".to_string(), true)])));
+    assert_eq!(configer.get_as::<String>("server.absent"), Err(ConfigerError::NotFound));
+}
+
+#[test]
+fn test_bind() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    populate(&mut configer);
+    configer.set_t("server.mode.cluster.nodes", 3_u64).unwrap_err();
+    configer.set("server.mode", Node::Nested(Table::new())).unwrap();
+    configer.set_t("server.mode.cluster.nodes", 3_u64).unwrap();
+
+    let app: AppConfig = configer.bind().unwrap();
+    assert_eq!(app.name, "configer");
+    assert_eq!(app.server.mode, Mode::Cluster { nodes: 3 });
+}
+
+#[test]
+fn test_bind_failed_reports_key_path() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    populate(&mut configer);
+
+    configer.set_t("server.ports[1]", -1_i32).unwrap();
+    assert!(matches!(
+        configer.get_as::<ServerConfig>("server"),
+        Err(ConfigerError::BindFailed(path, _)) if path == "server.ports[1]"
+    ));
+
+    configer.set_t("server.ports[1]", 8081_i32).unwrap();
+    configer.set_t("server.port", u32::MAX).unwrap();
+    assert!(matches!(
+        configer.bind::<AppConfig>(),
+        Err(ConfigerError::BindFailed(path, _)) if path == "server.port"
+    ));
+
+    configer.set_t("server.port", 8080_i32).unwrap();
+    configer.set_t("server.tls", "off").unwrap();
+    assert!(matches!(
+        configer.get_as::<ServerConfig>("server"),
+        Err(ConfigerError::BindFailed(path, _)) if path == "server.tls"
+    ));
+
+    // Missing fields are reported on the enclosing table
+    assert_eq!(
+        from_node::<TlsConfig>(&Node::Nested(Table::new())),
+        Err(ConfigerError::BindFailed("".to_string(), "missing field `enabled`".to_string()))
+    );
+}
+
+#[test]
+fn test_bind_string_values() {
+    let properties_reader = PropertiesConfigReader::default();
+    let table = properties_reader
+        .read_from_str("server.host=0.0.0.0\nserver.port=9090\nserver.tls.enabled=true\nserver.mode=standalone")
+        .unwrap();
+    let configer = ConfigerEnvironment::table(table);
+
+    #[derive(Debug, Deserialize)]
+    struct Server {
+        port: u16,
+        tls: TlsConfig,
+        mode: Mode,
+    }
+
+    let server: Server = configer.get_as("server").unwrap();
+    assert_eq!(server.port, 9090);
+    assert!(server.tls.enabled);
+    assert_eq!(server.mode, Mode::Standalone);
+}
+
+#[test]
+fn test_get_as_datetime() {
+    let now = NaiveDateTime::parse_from_str("2024-03-11 22:50:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    configer.set_t("time", now).unwrap();
+
+    assert_eq!(configer.get_as::<String>("time"), Ok("2024-03-11T22:50:00".to_string()));
+}
+
+// ----------------------------------------------------------------
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Point,
+    Circle(f64),
+    Line(i32, i32),
+    Rect { width: u8, height: u8 },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Defaults {
+    name: String,
+    port: u16,
+    workers: u64,
+    offset: i8,
+    big: u128,
+    ratio: f32,
+    banner: Option<String>,
+    started: NaiveDateTime,
+    tags: Vec<String>,
+    shapes: Vec<Shape>,
+    limits: HashMap<u32, bool>,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Self {
+            name: "configer".to_string(),
+            port: 8080,
+            workers: 4,
+            offset: -1,
+            big: u128::MAX,
+            ratio: 0.5,
+            banner: None,
+            started: NaiveDateTime::parse_from_str("2024-03-11 22:50:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            tags: vec!["rust".to_string()],
+            shapes: vec![Shape::Point, Shape::Circle(1.5), Shape::Line(1, 2), Shape::Rect { width: 3, height: 4 }],
+            limits: HashMap::from([(1, true)]),
+        }
+    }
+}
+
+#[test]
+fn test_table_from_serialize() {
+    let defaults = Defaults::default();
+    let table = Table::from_serialize(&defaults).unwrap();
+    let configer = ConfigerEnvironment::table(table);
+
+    assert_eq!(configer.get("name"), Ok(&Node::String("configer".to_string())));
+    assert_eq!(configer.get("port"), Ok(&Node::IntU32(8080)));
+    assert_eq!(configer.get("workers"), Ok(&Node::IntU64(4)));
+    assert_eq!(configer.get("offset"), Ok(&Node::Int32(-1)));
+    assert_eq!(configer.get("big"), Ok(&Node::IntU128(u128::MAX)));
+    assert_eq!(configer.get("ratio"), Ok(&Node::Float32(0.5)));
+    assert_eq!(configer.get("banner"), Ok(&Node::None));
+    assert_eq!(configer.get("started"), Ok(&Node::DateTime(defaults.started)));
+    assert_eq!(configer.get("tags[0]"), Ok(&Node::String("rust".to_string())));
+    assert_eq!(configer.get("shapes[0]"), Ok(&Node::String("Point".to_string())));
+    assert_eq!(configer.get("shapes[1].Circle"), Ok(&Node::Float64(1.5)));
+    assert_eq!(configer.get("shapes[2].Line[1]"), Ok(&Node::Int32(2)));
+    assert_eq!(configer.get("shapes[3].Rect.height"), Ok(&Node::IntU32(4)));
+    assert_eq!(configer.get("limits.\"1\""), Ok(&Node::Boolean(true)));
+
+    // And back
+    assert_eq!(configer.bind::<Defaults>(), Ok(defaults));
+}
+
+#[test]
+fn test_with_table_from_serialize_as_lowest_layer() {
+    let defaults = Table::from_serialize(&Defaults::default()).unwrap();
+
+    let mut table = Table::new();
+    table.insert("port".to_string(), Node::Int64(9090));
+
+    let configer = ConfigerEnvironment::builder()
+        .with_table(merge_tables(defaults, table))
+        .build()
+        .unwrap();
+
+    assert_eq!(configer.get("port"), Ok(&Node::Int64(9090)));
+    assert_eq!(configer.get("workers"), Ok(&Node::IntU64(4)));
+}
+
+#[test]
+fn test_node_serialize_round_trip() {
+    let defaults = Defaults::default();
+    let node = to_node(&defaults).unwrap();
+
+    assert_eq!(to_node(&node), Ok(node.clone()));
+
+    // `DateTime` is deserialized as a string
+    let started = to_node(&defaults.started).unwrap();
+    assert_eq!(started, Node::DateTime(defaults.started));
+    assert_eq!(from_node::<Node>(&started), Ok(Node::String("2024-03-11T22:50:00".to_string())));
+
+    let shapes = to_node(&defaults.shapes).unwrap();
+    assert_eq!(from_node::<Node>(&shapes), Ok(shapes));
+
+    assert_eq!(
+        to_table(&vec![1, 2]),
+        Err(ConfigerError::SerializeFailed("expected a struct or a map".to_string()))
+    );
+}
+
+// ----------------------------------------------------------------
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct CacheConfig {
+    #[serde(with = "crate::domain::units::duration")]
+    ttl: Duration,
+    #[serde(with = "crate::domain::units::duration")]
+    refresh: Duration,
+    limit: ByteSize,
+    chunk: ByteSize,
+}
+
+#[test]
+fn test_bind_duration_and_byte_size() {
+    let mut configer = ConfigerEnvironment::new();
+    configer.set("cache.ttl", "1m30s".into()).unwrap();
+    configer.set("cache.refresh", 30_i64.into()).unwrap();
+    configer.set("cache.limit", "512MiB".into()).unwrap();
+    configer.set("cache.chunk", 4096_i64.into()).unwrap();
+
+    let cache: CacheConfig = configer.get_as("cache").unwrap();
+    assert_eq!(
+        cache,
+        CacheConfig {
+            ttl: Duration::from_secs(90),
+            refresh: Duration::from_secs(30),
+            limit: ByteSize(512 * 1024 * 1024),
+            chunk: ByteSize(4096),
+        }
+    );
+
+    let table = to_table(&cache).unwrap();
+    assert_eq!(table.get("ttl"), Some(&Node::String("90s".to_string())));
+    assert_eq!(table.get("limit"), Some(&Node::String("512MiB".to_string())));
+
+    configer.set("cache.ttl", "1x".into()).unwrap();
+    match configer.get_as::<CacheConfig>("cache") {
+        Err(ConfigerError::BindFailed(path, message)) => {
+            assert_eq!(path, "cache.ttl");
+            assert!(message.contains("[1x]"), "{}", message);
+        }
+        rvt => panic!("unexpected: {:?}", rvt),
+    }
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct TimeoutConfig {
+    #[serde(with = "crate::domain::units::duration::millis")]
+    timeout_ms: Duration,
+    #[serde(with = "crate::domain::units::duration::millis")]
+    connect_ms: Duration,
+    limit: ByteSize,
+    chunk: ByteSize,
+}
+
+#[test]
+fn test_bind_duration_units_and_large_byte_sizes() {
+    let mut configer = ConfigerEnvironment::new();
+    configer.set("timeouts.timeout_ms", 500_i64.into()).unwrap();
+    configer.set("timeouts.connect_ms", "2s".into()).unwrap();
+    configer.set("timeouts.limit", Node::IntU128(1024)).unwrap();
+    configer.set("timeouts.chunk", Node::Float64(4096.0)).unwrap();
+
+    let timeouts: TimeoutConfig = configer.get_as("timeouts").unwrap();
+    assert_eq!(
+        timeouts,
+        TimeoutConfig {
+            timeout_ms: Duration::from_millis(500),
+            connect_ms: Duration::from_secs(2),
+            limit: ByteSize(1024),
+            chunk: ByteSize(4096),
+        }
+    );
+
+    configer.set("timeouts.limit", Node::Int128(-1)).unwrap();
+    assert!(matches!(configer.get_as::<TimeoutConfig>("timeouts"), Err(ConfigerError::BindFailed(_, _))));
+    configer.set("timeouts.limit", Node::Float64(1.5)).unwrap();
+    assert!(matches!(configer.get_as::<TimeoutConfig>("timeouts"), Err(ConfigerError::BindFailed(_, _))));
+}
+
+#[test]
+fn test_date_time_variants_round_trip() {
+    let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    let time = NaiveTime::from_hms_milli_opt(1, 2, 3, 500).unwrap();
+    let offset = DateTime::parse_from_rfc3339("2024-03-15T01:02:03+08:00").unwrap();
+
+    assert_eq!(to_node(&date), Ok(Node::Date(date)));
+    assert_eq!(to_node(&time), Ok(Node::Time(time)));
+    assert_eq!(to_node(&offset), Ok(Node::OffsetDateTime(offset)));
+
+    assert_eq!(from_node::<NaiveDate>(&Node::Date(date)), Ok(date));
+    assert_eq!(from_node::<NaiveTime>(&Node::Time(time)), Ok(time));
+    assert_eq!(from_node::<DateTime<FixedOffset>>(&Node::OffsetDateTime(offset)), Ok(offset));
+}
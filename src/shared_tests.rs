@@ -0,0 +1,178 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::domain::{Node, Table};
+use crate::domain::merge::{ConflictPolicy, MergeOptions};
+use crate::env::Environment;
+use crate::env::shared::SharedEnvironment;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::ConfigerError;
+use crate::reader::{ConfigReader, ReaderRegistry};
+
+// ----------------------------------------------------------------
+
+fn shared() -> SharedEnvironment {
+    let mut server = Table::new();
+    server.insert("port".to_string(), Node::Int64(8080));
+
+    let mut table = Table::new();
+    table.insert("server".to_string(), Node::Nested(server));
+
+    ConfigerEnvironment::builder()
+        .with_table(table)
+        .with_env_enabled(false)
+        .build()
+        .unwrap()
+        .into_shared()
+}
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_shared_environment_is_send_and_sync() {
+    assert_send_sync::<ConfigerEnvironment>();
+    assert_send_sync::<SharedEnvironment>();
+    assert_send_sync::<Box<dyn ConfigReader>>();
+    assert_send_sync::<Box<dyn ReaderRegistry>>();
+}
+
+#[test]
+fn test_shared_environment_snapshot_is_immutable() {
+    let shared = shared();
+    let before = shared.snapshot();
+
+    shared.set("server.port", Node::Int64(9090)).unwrap();
+    let after = shared.snapshot();
+
+    assert_eq!(before.get("server.port"), Ok(&Node::Int64(8080)));
+    assert_eq!(after.get("server.port"), Ok(&Node::Int64(9090)));
+    assert_eq!(shared.get("server.port"), Ok(Node::Int64(9090)));
+    assert_eq!(before.version(), 0);
+    assert_eq!(after.version(), 1);
+}
+
+#[test]
+fn test_shared_environment_get_errors() {
+    let shared = shared();
+
+    assert_eq!(shared.get("server.host"), Err(ConfigerError::NotFound));
+    assert_eq!(shared.get("servers[+]"), Err(ConfigerError::InvalidKey("servers[+]".to_string())));
+}
+
+#[test]
+fn test_shared_environment_merge_table() {
+    let shared = shared();
+
+    let mut server = Table::new();
+    server.insert("host".to_string(), Node::String("localhost".to_string()));
+    let mut table = Table::new();
+    table.insert("server".to_string(), Node::Nested(server));
+    shared.merge_table(table);
+
+    let snapshot = shared.snapshot();
+    assert_eq!(snapshot.get("server.port"), Ok(&Node::Int64(8080)));
+    assert_eq!(snapshot.get("server.host"), Ok(&Node::String("localhost".to_string())));
+
+    // A failed write still publishes, but nothing changed
+    let mut table = Table::new();
+    table.insert("server".to_string(), Node::String("conflict".to_string()));
+    let options = MergeOptions::default().with_conflict_policy(ConflictPolicy::Error);
+    assert!(shared.merge_table_with(table, &options).is_err());
+    assert_eq!(shared.snapshot().table(), snapshot.table());
+}
+
+#[test]
+fn test_shared_environment_concurrent_readers_and_writers() {
+    let shared = Arc::new(shared());
+
+    let writers: Vec<_> = (0..4)
+        .map(|writer| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                for i in 0..50 {
+                    shared.set_t(format!("writer{}.value", writer).as_str(), i as i64).unwrap();
+                }
+            })
+        })
+        .collect();
+
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                let mut last = 0;
+                for _ in 0..200 {
+                    let snapshot = shared.snapshot();
+                    assert!(snapshot.version() >= last);
+                    assert_eq!(snapshot.get("server.port"), Ok(&Node::Int64(8080)));
+                    last = snapshot.version();
+                }
+            })
+        })
+        .collect();
+
+    for handle in writers.into_iter().chain(readers) {
+        handle.join().unwrap();
+    }
+
+    let snapshot = shared.snapshot();
+    assert_eq!(snapshot.version(), 200);
+    for writer in 0..4 {
+        assert_eq!(snapshot.get(format!("writer{}.value", writer).as_str()), Ok(&Node::Int64(49)));
+    }
+}
+
+#[test]
+fn test_shared_environment_survives_a_panicking_writer() {
+    let shared = shared();
+
+    let rvt = panic::catch_unwind(AssertUnwindSafe(|| {
+        shared.update(|_| panic!("boom"));
+    }));
+    assert!(rvt.is_err());
+
+    shared.set("server.port", Node::Int64(9090)).unwrap();
+    assert_eq!(shared.get("server.port"), Ok(Node::Int64(9090)));
+    assert_eq!(shared.into_inner().get("server.port"), Ok(&Node::Int64(9090)));
+}
+
+#[test]
+fn test_shared_environment_callback_reads_the_previous_snapshot() {
+    let shared = Arc::new(shared());
+    let seen = Arc::new(Mutex::new(Vec::new()));
+
+    let (weak, sink) = (Arc::downgrade(&shared), seen.clone());
+    shared
+        .update(|configer| {
+            configer.subscribe("server.port", move |_| {
+                let port = weak.upgrade().map(|shared| shared.get("server.port"));
+                sink.lock().unwrap().push(port);
+            })
+        })
+        .unwrap();
+
+    shared.set("server.port", Node::Int64(9090)).unwrap();
+    assert_eq!(*seen.lock().unwrap(), vec![Some(Ok(Node::Int64(8080)))]);
+    assert_eq!(shared.get("server.port"), Ok(Node::Int64(9090)));
+}
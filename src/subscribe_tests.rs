@@ -0,0 +1,189 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::sync::{Arc, Mutex};
+
+use crate::domain::{Node, Table};
+use crate::env::{DynamicEnvironment, Environment};
+use crate::env::standard::ConfigerEnvironment;
+use crate::env::subscribe::KeyChange;
+use crate::error::ConfigerError;
+use crate::test_support::string;
+
+// ----------------------------------------------------------------
+
+fn change(key: &str, old: Option<Node>, new: Option<Node>) -> KeyChange {
+    KeyChange {
+        key: key.to_string(),
+        old,
+        new,
+    }
+}
+
+fn configer() -> ConfigerEnvironment {
+    let mut log = Table::new();
+    log.insert("level".to_string(), string("info"));
+    log.insert("format".to_string(), string("json"));
+
+    let mut table = Table::new();
+    table.insert("log".to_string(), Node::Nested(log));
+    table.insert("logger".to_string(), string("root"));
+
+    ConfigerEnvironment::builder()
+        .with_table(table)
+        .with_env_enabled(false)
+        .build()
+        .unwrap()
+}
+
+/// Subscribes to `prefix`, the changes are collected in order.
+fn record(configer: &mut ConfigerEnvironment, prefix: &str) -> Arc<Mutex<Vec<KeyChange>>> {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&changes);
+    configer
+        .subscribe(prefix, move |change| recorded.lock().unwrap().push(change.clone()))
+        .unwrap();
+
+    changes
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_subscribe_set() {
+    let mut configer = configer();
+    let log = record(&mut configer, "log");
+    let level = record(&mut configer, "log.level");
+
+    configer.set("log.level", string("debug")).unwrap();
+    configer.set_t("log.format", "text").unwrap();
+    // Not under `log`
+    configer.set("logger", string("app")).unwrap();
+    // The same value is no change
+    configer.set("log.level", string("debug")).unwrap();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            change("log.level", Some(string("info")), Some(string("debug"))),
+            change("log.format", Some(string("json")), Some(string("text"))),
+        ]
+    );
+    assert_eq!(
+        *level.lock().unwrap(),
+        vec![change("log.level", Some(string("info")), Some(string("debug")))]
+    );
+}
+
+#[test]
+fn test_subscribe_set_array() {
+    let mut configer = configer();
+    let hosts = record(&mut configer, "hosts[1]");
+
+    configer.set("hosts[+]", string("a")).unwrap();
+    configer.set("hosts[+]", string("b")).unwrap();
+    configer.set("hosts[1]", string("c")).unwrap();
+
+    assert_eq!(
+        *hosts.lock().unwrap(),
+        vec![
+            change("hosts[1]", None, Some(string("b"))),
+            change("hosts[1]", Some(string("b")), Some(string("c"))),
+        ]
+    );
+}
+
+#[test]
+fn test_subscribe_merge_table() {
+    let mut configer = configer();
+    let all = record(&mut configer, "");
+
+    let mut log = Table::new();
+    log.insert("level".to_string(), string("warn"));
+    log.insert("file".to_string(), string("app.log"));
+    let mut table = Table::new();
+    table.insert("log".to_string(), Node::Nested(log));
+    configer.merge_table(table);
+
+    assert_eq!(
+        *all.lock().unwrap(),
+        vec![
+            change("log.file", None, Some(string("app.log"))),
+            change("log.level", Some(string("info")), Some(string("warn"))),
+        ]
+    );
+}
+
+#[test]
+fn test_subscribe_register_table_with_env_variables() {
+    let mut configer = configer();
+    let log = record(&mut configer, "log");
+
+    let mut log_table = Table::new();
+    log_table.insert("level".to_string(), string("error"));
+    let mut table = Table::new();
+    table.insert("log".to_string(), Node::Nested(log_table));
+    configer.register_table_with_env_variables(table);
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            change("log.format", Some(string("json")), None),
+            change("log.level", Some(string("info")), Some(string("error"))),
+        ]
+    );
+}
+
+#[test]
+fn test_unsubscribe() {
+    let mut configer = configer();
+    let changes = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&changes);
+    let subscription = configer
+        .subscribe("log.level", move |_| *counter.lock().unwrap() += 1)
+        .unwrap();
+
+    configer.set("log.level", string("debug")).unwrap();
+    assert!(configer.unsubscribe(subscription));
+    configer.set("log.level", string("trace")).unwrap();
+
+    assert_eq!(*changes.lock().unwrap(), 1);
+}
+
+#[test]
+fn test_subscribe_panicking_callback() {
+    let mut configer = configer();
+    configer.subscribe("log", |_| panic!("boom")).unwrap();
+    let level = record(&mut configer, "log.level");
+
+    configer.set("log.level", string("debug")).unwrap();
+    configer.set("log.level", string("trace")).unwrap();
+
+    assert_eq!(configer.get("log.level"), Ok(&string("trace")));
+    assert_eq!(level.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_subscribe_invalid_prefix() {
+    let mut configer = configer();
+
+    assert_eq!(
+        configer.subscribe("hosts[+]", |_| {}).err(),
+        Some(ConfigerError::InvalidKey("hosts[+]".to_string()))
+    );
+}
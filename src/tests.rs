@@ -31,6 +31,7 @@ use crate::error::ConfigerError;
 
 #[test]
 #[rustfmt::skip]
+#[allow(clippy::excessive_precision)]
 fn test_set() {
     let mut configer = ConfigerEnvironment::new();
 
@@ -56,6 +57,7 @@ fn test_set() {
 /// @since 0.4.2
 #[test]
 #[rustfmt::skip]
+#[allow(clippy::excessive_precision)]
 fn test_set_t() {
     let mut configer = ConfigerEnvironment::new();
     configer.set_t("io.github.photowey.string", String::from("Hello, Configer!")).unwrap();
@@ -89,6 +91,7 @@ fn test_set_empty_key() {
 
 #[test]
 #[rustfmt::skip]
+#[allow(clippy::excessive_precision, clippy::useless_conversion)]
 fn test_get() {
     let mut configer = ConfigerEnvironment::new();
 
@@ -139,6 +142,7 @@ fn test_get() {
 /// @since 0.4.2
 #[test]
 #[rustfmt::skip]
+#[allow(clippy::excessive_precision, clippy::useless_conversion)]
 fn test_get_by_set_t() {
     let mut configer = ConfigerEnvironment::new();
 
@@ -203,6 +207,66 @@ fn test_get_not_found() {
     );
 }
 
+/// @since 0.7.0
+#[test]
+fn test_get_by_index() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    configer.set("servers[0].host", "192.168.1.1".into()).unwrap();
+    configer.set("servers[1].host", "192.168.1.2".into()).unwrap();
+    configer.set("servers[1].ports[0]", 8080.into()).unwrap();
+
+    assert_eq!(configer.get("servers[0].host"), Ok(&Node::String("192.168.1.1".to_string())));
+    assert_eq!(configer.get("servers[1].host"), Ok(&Node::String("192.168.1.2".to_string())));
+    assert_eq!(configer.get("servers[1].ports[0]"), Ok(&Node::Int32(8080)));
+
+    assert_eq!(configer.get("servers[2].host"), Err(ConfigerError::IndexOutOfBounds(2, 2)));
+    assert_eq!(configer.get("servers[0].host[0]"), Err(ConfigerError::NonArray));
+    assert_eq!(configer.get("servers[0].port"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.get("servers.host"), Err(ConfigerError::NonNested));
+}
+
+/// @since 0.7.0
+#[test]
+fn test_set_by_index_and_append() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    configer.set("matrix[0][0]", 1.into()).unwrap();
+    configer.set("matrix[0][+]", 2.into()).unwrap();
+    configer.set("matrix[+][0]", 3.into()).unwrap();
+    // Replace
+    configer.set("matrix[0][1]", 4.into()).unwrap();
+    // Append at `len`
+    configer.set("matrix[1][1]", 5.into()).unwrap();
+
+    assert_eq!(
+        configer.get("matrix"),
+        Ok(&Node::Array(vec![
+            Node::Array(vec![Node::Int32(1), Node::Int32(4)]),
+            Node::Array(vec![Node::Int32(3), Node::Int32(5)]),
+        ]))
+    );
+
+    assert_eq!(configer.set("matrix[3]", 6.into()), Err(ConfigerError::IndexOutOfBounds(3, 2)));
+    assert_eq!(configer.set("matrix.key", 6.into()), Err(ConfigerError::NonNested));
+    assert_eq!(configer.set("matrix[0][0][0]", 6.into()), Err(ConfigerError::NonArray));
+
+    // No intermediate container is left behind on failure
+    assert_eq!(configer.set("absent[1]", 6.into()), Err(ConfigerError::IndexOutOfBounds(1, 0)));
+    assert_eq!(configer.get("absent"), Err(ConfigerError::NotFound));
+}
+
+/// @since 0.7.0
+#[test]
+fn test_invalid_key_path() {
+    let mut configer = ConfigerEnvironment::table(Table::new());
+    configer.set("servers[+]", "192.168.1.1".into()).unwrap();
+
+    for key in ["servers[", "servers[x]", "servers[-1]", "servers[0]x", "[0]", "servers.[0]", "servers[+]"] {
+        assert_eq!(configer.get(key), Err(ConfigerError::InvalidKey(key.to_string())));
+    }
+
+    assert_eq!(configer.set("servers[0", 1.into()), Err(ConfigerError::InvalidKey("servers[0".to_string())));
+}
+
 // ----------------------------------------------------------------
 
 /// @since 0.5.0
@@ -19,16 +19,22 @@
 use std::{env, fs};
 use std::collections::HashMap;
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use toml::Value;
 
 use crate::{domain, reader};
 use crate::domain::{Node, Table};
 use crate::domain::converter::NodeConverter;
+use crate::domain::merge::{ArrayStrategy, TOMBSTONE};
 use crate::env::Environment;
+use crate::env::layer::SourcedValue;
 use crate::env::standard::ConfigerEnvironment;
-use crate::error::ConfigerError;
+use crate::error::{ConfigerError, FileError};
 use crate::reader::{ConfigReader, ConfigReaderRegistry, ReaderRegistry};
 use crate::reader::toml::TomlConfigReader;
+use crate::test_support::string;
+use crate::writer::ConfigWriter;
+use crate::writer::toml::TomlConfigWriter;
 
 // ----------------------------------------------------------------
 
@@ -46,6 +52,7 @@ fn test_toml_read() {
 }
 
 #[test]
+#[allow(clippy::needless_return, clippy::unused_unit)]
 fn test_toml_value_to_node() {
     let path = "resources/testdata/configer-dev.toml";
 
@@ -130,6 +137,7 @@ fn test_build_configer_by_table() {
 
 #[test]
 #[allow(deprecated)]
+#[allow(clippy::unused_unit)]
 fn test_build_configer_by_register_table() {
     let path = "resources/testdata/configer-dev.toml";
 
@@ -220,6 +228,7 @@ fn test_load_environment_variables() {
 
 /// @since 0.5.0
 #[test]
+#[allow(clippy::unused_unit)]
 fn test_build_configer_builder_with_table_registry_and_path() {
     env::set_var("CONFIGER_TEST_VAR", "rust.configer");
 
@@ -251,6 +260,7 @@ fn test_build_configer_builder_with_table_registry_and_path() {
 }
 
 #[test]
+#[allow(clippy::unused_unit)]
 fn test_build_configer_builder_with_registry_path_and_profiles() {
     env::set_var("CONFIGER_TEST_VAR", "rust.configer");
 
@@ -289,6 +299,7 @@ fn test_build_configer_builder_with_registry_path_and_profiles() {
 }
 
 #[test]
+#[allow(clippy::unused_unit)]
 fn test_build_configer_builder_with_table_registry_path_and_profiles() {
     env::set_var("CONFIGER_TEST_VAR", "rust.configer");
 
@@ -331,6 +342,7 @@ fn test_build_configer_builder_with_table_registry_path_and_profiles() {
 
 /// @since 0.5.0
 #[test]
+#[allow(clippy::unused_unit)]
 fn test_build_configer_builder_without_table_with_registry_and_path() {
     env::set_var("CONFIGER_TEST_VAR", "rust.configer");
 
@@ -390,6 +402,7 @@ fn test_mixed_configer_with_enn_variables() {
 
 /// @since 0.5.0
 #[test]
+#[allow(clippy::unused_unit)]
 fn test_table_configer_with_env_variables() {
     env::set_var("CONFIGER_TEST_VAR", "rust.configer");
 
@@ -437,6 +450,7 @@ fn traverse_toml(value: &Value) {
 
 // ----------------------------------------------------------------
 
+#[allow(clippy::vec_init_then_push)]
 fn assert_configer_array(rvt_database_servers: Result<&Node, ConfigerError>, key: &str) {
     match NodeConverter::try_array(rvt_database_servers) {
         Some(servers) => {
@@ -451,6 +465,7 @@ fn assert_configer_array(rvt_database_servers: Result<&Node, ConfigerError>, key
     }
 }
 
+#[allow(clippy::vec_init_then_push)]
 fn assert_configer_array_strings(rvt_database_servers: Result<&Node, ConfigerError>, key: &str) {
     match NodeConverter::try_array(rvt_database_servers) {
         Some(servers) => {
@@ -468,4 +483,234 @@ fn assert_configer_array_strings(rvt_database_servers: Result<&Node, ConfigerErr
 
 fn assert_node_array_equals(array: &domain::Array, vec: &domain::Array) -> bool {
     array.iter().zip(vec.iter()).all(|(a, b)| a == b)
-}
\ No newline at end of file
+}
+// ----------------------------------------------------------------
+
+/// @since 0.7.0
+#[test]
+fn test_toml_writer_round_trip() {
+    let toml_reader = TomlConfigReader::default();
+    let table = toml_reader.read_from_path("resources/testdata/configer-dev.toml").unwrap();
+
+    let toml_writer = TomlConfigWriter::default();
+    let content = toml_writer.write_to_string(&table).unwrap();
+
+    assert_eq!(toml_reader.read_from_str(&content), Ok(table));
+}
+
+/// @since 0.7.0
+#[test]
+fn test_toml_writer_node_mapping() {
+    let now = NaiveDateTime::parse_from_str("2024-03-11 22:50:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+    let mut table = Table::new();
+    table.insert("none".to_string(), Node::None);
+    table.insert("u128".to_string(), Node::IntU128(u128::MAX));
+    table.insert("u64".to_string(), Node::IntU64(9527));
+    table.insert("i32".to_string(), Node::Int32(-1));
+    table.insert("f32".to_string(), Node::Float32(0.5));
+    table.insert("time".to_string(), Node::DateTime(now));
+
+    let content = TomlConfigWriter::default().write_to_string(&table).unwrap();
+    let written = TomlConfigReader::default().read_from_str(&content).unwrap();
+
+    assert!(!written.contains_key("none"));
+    assert_eq!(written.get("u128"), Some(&Node::String(u128::MAX.to_string())));
+    assert_eq!(written.get("u64"), Some(&Node::Int64(9527)));
+    assert_eq!(written.get("i32"), Some(&Node::Int64(-1)));
+    assert_eq!(written.get("f32"), Some(&Node::Float64(0.5)));
+    assert_eq!(written.get("time"), Some(&Node::DateTime(now)));
+
+    table.insert("array".to_string(), Node::Array(vec![Node::Int32(1), Node::None]));
+    assert!(matches!(
+        TomlConfigWriter::default().write_to_string(&table),
+        Err(FileError::SerializeFailed(_, _))
+    ));
+}
+
+/// @since 0.7.0
+#[test]
+fn test_configer_write_to_path() {
+    let toml_reader = TomlConfigReader::default();
+    let table = toml_reader.read_from_path("resources/testdata/configer-dev.toml").unwrap();
+    let configer = ConfigerEnvironment::builder().with_table(table).build().unwrap();
+
+    let content = configer.write_to_string("toml").unwrap();
+    assert!(content.contains("[database]"));
+
+    let path = env::temp_dir().join(format!("configer-{}.toml", std::process::id()));
+    let path = path.to_str().unwrap();
+    configer.write_to_path(path).unwrap();
+
+    let written = toml_reader.read_from_path(path).unwrap();
+    fs::remove_file(path).unwrap();
+    assert_eq!(ConfigerEnvironment::table(written).get("table.table_s"), configer.get("table.table_s"));
+
+    assert_eq!(configer.write_to_string("xml"), Err(FileError::WriterNotFound("xml".to_string())));
+    assert_eq!(configer.write_to_path("configer"), Err(FileError::InvalidFile("configer".to_string())));
+
+    // A dotfile without an extension is written by its name, like it is read
+    let dir = env::temp_dir().join(format!("configer-dotfile-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let dotfile = dir.join(".toml");
+    configer.write_to_path(dotfile.to_str().unwrap()).unwrap();
+    assert_eq!(toml_reader.read_from_path(dotfile.to_str().unwrap()), Ok(toml_reader.read_from_str(&content).unwrap()));
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(configer.write_to_path(".env"), Err(FileError::WriterNotFound("env".to_string())));
+}
+
+/// @since 0.7.0
+#[test]
+fn test_get_array_of_tables_by_index() {
+    let toml_reader = TomlConfigReader::default();
+    let table = toml_reader.read_from_path("resources/testdata/configer-dev.toml").unwrap();
+    let mut configer = ConfigerEnvironment::table(table);
+
+    assert_eq!(configer.get("endpoints[0].hello"), Ok(&Node::String("world".to_string())));
+    assert_eq!(configer.get("endpoints[1].world"), Ok(&Node::String("hello".to_string())));
+    assert_eq!(configer.get("database.servers[2]"), Ok(&Node::String("192.168.1.3".to_string())));
+    assert_eq!(configer.get("endpoints[2].hello"), Err(ConfigerError::IndexOutOfBounds(2, 2)));
+
+    configer.set("endpoints[+].configer", "rust".into()).unwrap();
+    assert_eq!(configer.get("endpoints[2].configer"), Ok(&Node::String("rust".to_string())));
+}
+
+/// @since 0.7.0
+#[test]
+fn test_get_quoted_toml_key() {
+    let toml_reader = TomlConfigReader::default();
+    let table = toml_reader
+        .read_from_str("[log.level]\n\"my.crate\" = \"debug\"\n\n[hosts.\"example.com\"]\nport = 8080")
+        .unwrap();
+    let configer = ConfigerEnvironment::table(table);
+
+    assert_eq!(configer.get("log.level.\"my.crate\""), Ok(&Node::String("debug".to_string())));
+    assert_eq!(configer.get("hosts.\"example.com\".port"), Ok(&Node::Int64(8080)));
+}
+
+// ----------------------------------------------------------------
+
+const TOML_DATETIMES: &str = r#"
+odt = 1979-05-27T07:32:00-08:00
+odt_utc = 1979-05-27T07:32:00.999Z
+ldt = 1979-05-27T07:32:00.5
+ld = 1979-05-27
+lt = 07:32:00.999999
+"#;
+
+#[test]
+fn test_toml_read_every_datetime_form() {
+    let table = TomlConfigReader::default().read_from_str(TOML_DATETIMES).unwrap();
+    let configer = ConfigerEnvironment::table(table);
+
+    let date = NaiveDate::from_ymd_opt(1979, 5, 27).unwrap();
+
+    assert_eq!(
+        NodeConverter::try_offset_datetime(configer.get("odt")),
+        Some(&DateTime::parse_from_rfc3339("1979-05-27T07:32:00-08:00").unwrap())
+    );
+    assert_eq!(
+        configer.get("odt_utc"),
+        Ok(&Node::OffsetDateTime(DateTime::parse_from_rfc3339("1979-05-27T07:32:00.999Z").unwrap()))
+    );
+    assert_eq!(
+        NodeConverter::try_datetime(configer.get("ldt")),
+        Some(&date.and_hms_milli_opt(7, 32, 0, 500).unwrap())
+    );
+    assert_eq!(NodeConverter::try_date(configer.get("ld")), Some(&date));
+    assert_eq!(
+        NodeConverter::try_time(configer.get("lt")),
+        Some(&NaiveTime::from_hms_micro_opt(7, 32, 0, 999_999).unwrap())
+    );
+}
+
+#[test]
+fn test_toml_writer_datetime_round_trip() {
+    let table = TomlConfigReader::default().read_from_str(TOML_DATETIMES).unwrap();
+    let content = TomlConfigWriter::default().write_to_string(&table).unwrap();
+
+    assert!(content.contains("odt = 1979-05-27T07:32:00-08:00"), "{}", content);
+    assert!(content.contains("ld = 1979-05-27\n"), "{}", content);
+    assert_eq!(TomlConfigReader::default().read_from_str(&content), Ok(table));
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_build_configer_with_profiles_and_array_strategy() {
+    let build = |strategy: Option<ArrayStrategy>| {
+        let mut registry = ConfigReaderRegistry::default();
+        registry.register(Box::<TomlConfigReader>::default());
+
+        let mut builder = ConfigerEnvironment::builder()
+            .with_registry(Box::new(registry))
+            .with_path(String::from("resources/testdata/merge.toml"))
+            .with_profiles(vec![String::from("dev")])
+            .with_tombstone(Some(Node::String(TOMBSTONE.to_string())));
+        if let Some(strategy) = strategy {
+            builder = builder.with_array_strategy_at("server.allowed_hosts", strategy);
+        }
+
+        builder.build().unwrap()
+    };
+
+    // Appended by default
+    let configer = build(None);
+    assert_eq!(
+        configer.get("server.allowed_hosts"),
+        Ok(&Node::Array(vec![string("localhost"), string("127.0.0.1"), string("dev.example.com"), string("localhost")]))
+    );
+    // The profile removes `server.tls`
+    assert_eq!(configer.get("server.tls"), Err(ConfigerError::NotFound));
+    assert_eq!(configer.get("server.port"), Ok(&Node::Int64(8080)));
+
+    let configer = build(Some(ArrayStrategy::Replace));
+    assert_eq!(
+        configer.get("server.allowed_hosts"),
+        Ok(&Node::Array(vec![string("dev.example.com"), string("localhost")]))
+    );
+
+    let configer = build(Some(ArrayStrategy::DedupeAppend));
+    assert_eq!(
+        configer.get("server.allowed_hosts"),
+        Ok(&Node::Array(vec![string("localhost"), string("127.0.0.1"), string("dev.example.com")]))
+    );
+}
+
+#[test]
+fn test_build_configer_explain_toml_lines() {
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<TomlConfigReader>::default());
+
+    let configer = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(String::from("resources/testdata/merge.toml"))
+        .with_profiles(vec![String::from("dev")])
+        .with_tombstone(Some(Node::String(TOMBSTONE.to_string())))
+        .with_env_enabled(false)
+        .build()
+        .unwrap();
+
+    let origin = |explanation: &SourcedValue| (explanation.origin.source.clone(), explanation.origin.line);
+
+    let explanation = configer.explain("server.allowed_hosts[0]").unwrap();
+    assert_eq!(origin(explanation.winner.as_ref().unwrap()), (String::from("resources/testdata/merge-dev.toml"), Some(2)));
+    assert_eq!(origin(&explanation.shadowed[0]), (String::from("resources/testdata/merge.toml"), Some(4)));
+
+    // Removed by the tombstone in the profile
+    let explanation = configer.explain("server.tls").unwrap();
+    assert_eq!(explanation.value, None);
+    assert_eq!(explanation.winner.unwrap().value, Node::String(String::from("__delete__")));
+    assert_eq!(origin(&explanation.shadowed[0]), (String::from("resources/testdata/merge.toml"), Some(6)));
+
+    let lines = TomlConfigReader::default().read_lines_from_str(
+        "title = \"t\"\n[server]\nport = 8080\nhosts = [\n  \"a\",\n  \"b\",\n]\n\n[[servers]]\nhost = { name = \"x\" }\n",
+    );
+    assert_eq!(lines.get("title"), Some(&1));
+    assert_eq!(lines.get("server"), Some(&2));
+    assert_eq!(lines.get("server.port"), Some(&3));
+    assert_eq!(lines.get("server.hosts[1]"), Some(&6));
+    assert_eq!(lines.get("servers[0]"), Some(&9));
+    assert_eq!(lines.get("servers[0].host.name"), Some(&10));
+}
@@ -0,0 +1,137 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::time::Duration;
+
+use crate::domain::Node;
+use crate::domain::converter::NodeConverter;
+use crate::domain::units::{ByteSize, ByteUnit, DurationUnit, format_duration, parse_byte_size, parse_duration, UnitError};
+use crate::env::Environment;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::{ConfigerError, ConversionError};
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration("30s", DurationUnit::Seconds), Ok(Duration::from_secs(30)));
+    assert_eq!(parse_duration("1m30s", DurationUnit::Seconds), Ok(Duration::from_secs(90)));
+    assert_eq!(parse_duration("1h 2m 3s", DurationUnit::Seconds), Ok(Duration::from_secs(3723)));
+    assert_eq!(parse_duration("1.5h", DurationUnit::Seconds), Ok(Duration::from_secs(5400)));
+    assert_eq!(parse_duration("250ms", DurationUnit::Seconds), Ok(Duration::from_millis(250)));
+    assert_eq!(parse_duration("10us", DurationUnit::Seconds), Ok(Duration::from_micros(10)));
+    assert_eq!(parse_duration("10µs", DurationUnit::Seconds), Ok(Duration::from_micros(10)));
+    assert_eq!(parse_duration("7ns", DurationUnit::Seconds), Ok(Duration::from_nanos(7)));
+    assert_eq!(parse_duration("2d", DurationUnit::Seconds), Ok(Duration::from_secs(2 * 24 * 3600)));
+    assert_eq!(parse_duration("5min", DurationUnit::Seconds), Ok(Duration::from_secs(300)));
+
+    // Plain numbers are in the default unit
+    assert_eq!(parse_duration("30", DurationUnit::Seconds), Ok(Duration::from_secs(30)));
+    assert_eq!(parse_duration("30", DurationUnit::Millis), Ok(Duration::from_millis(30)));
+    assert_eq!(parse_duration("0.5", DurationUnit::Seconds), Ok(Duration::from_millis(500)));
+
+    assert!(matches!(parse_duration("", DurationUnit::Seconds), Err(UnitError::Invalid(_))));
+    assert!(matches!(parse_duration("30x", DurationUnit::Seconds), Err(UnitError::Invalid(_))));
+    assert!(matches!(parse_duration("1m30", DurationUnit::Seconds), Err(UnitError::Invalid(_))));
+    assert!(matches!(parse_duration("-1s", DurationUnit::Seconds), Err(UnitError::Invalid(_))));
+    assert!(matches!(parse_duration("1.5ns", DurationUnit::Seconds), Err(UnitError::Invalid(_))));
+    assert_eq!(parse_duration("999999999999999999999999d", DurationUnit::Seconds), Err(UnitError::Overflow));
+}
+
+#[test]
+fn test_parse_byte_size() {
+    assert_eq!(parse_byte_size("512MiB", ByteUnit::Bytes), Ok(ByteSize(512 * 1024 * 1024)));
+    assert_eq!(parse_byte_size("1.5 GB", ByteUnit::Bytes), Ok(ByteSize(1_500_000_000)));
+    assert_eq!(parse_byte_size("4kb", ByteUnit::Bytes), Ok(ByteSize(4000)));
+    assert_eq!(parse_byte_size("4096", ByteUnit::Bytes), Ok(ByteSize(4096)));
+    assert_eq!(parse_byte_size("4", ByteUnit::KiB), Ok(ByteSize(4096)));
+    assert_eq!("10B".parse::<ByteSize>(), Ok(ByteSize(10)));
+
+    assert!(matches!(parse_byte_size("10XB", ByteUnit::Bytes), Err(UnitError::Invalid(_))));
+    assert!(matches!(parse_byte_size("MiB", ByteUnit::Bytes), Err(UnitError::Invalid(_))));
+    assert!(matches!(parse_byte_size("1.5B", ByteUnit::Bytes), Err(UnitError::Invalid(_))));
+    assert_eq!(parse_byte_size("100000PiB", ByteUnit::Bytes), Err(UnitError::Overflow));
+}
+
+#[test]
+fn test_format_units() {
+    assert_eq!(ByteSize(512 * 1024 * 1024).to_string(), "512MiB");
+    assert_eq!(ByteSize(1000).to_string(), "1000B");
+    assert_eq!(ByteSize(0).to_string(), "0B");
+    assert_eq!(format_duration(&Duration::from_secs(90)), "90s");
+    assert_eq!(format_duration(&Duration::from_secs(3600)), "1h");
+    assert_eq!(format_duration(&Duration::from_millis(250)), "250ms");
+    assert_eq!(format_duration(&Duration::from_nanos(7)), "7ns");
+}
+
+#[test]
+fn test_to_duration_and_byte_size() {
+    assert_eq!(
+        NodeConverter::to_duration(Ok(&Node::String("1m30s".to_string())), DurationUnit::Seconds),
+        Ok(Duration::from_secs(90))
+    );
+    assert_eq!(NodeConverter::to_duration(Ok(&Node::Int64(250)), DurationUnit::Millis), Ok(Duration::from_millis(250)));
+    assert_eq!(NodeConverter::to_duration(Ok(&Node::Float64(1.5)), DurationUnit::Seconds), Ok(Duration::from_millis(1500)));
+    assert_eq!(NodeConverter::to_byte_size(Ok(&Node::IntU32(2)), ByteUnit::MiB), Ok(ByteSize(2 * 1024 * 1024)));
+
+    assert_eq!(
+        NodeConverter::to_duration(Ok(&Node::Boolean(true)), DurationUnit::Seconds),
+        Err(ConversionError::Unsupported("Boolean".to_string(), "Duration".to_string()))
+    );
+    assert_eq!(
+        NodeConverter::to_byte_size(Ok(&Node::Int64(1 << 20)), ByteUnit::PiB),
+        Err(ConversionError::Overflow("Int64".to_string(), "ByteSize".to_string()))
+    );
+    assert!(matches!(
+        NodeConverter::to_duration(Ok(&Node::Int64(-1)), DurationUnit::Seconds),
+        Err(ConversionError::InvalidValue(_, _, _))
+    ));
+}
+
+#[test]
+fn test_get_duration_and_byte_size() {
+    let mut configer = ConfigerEnvironment::new();
+    configer.set("server.timeout", "30s".into()).unwrap();
+    configer.set("server.idle", 60_i64.into()).unwrap();
+    configer.set("cache.limit", "512MiB".into()).unwrap();
+    configer.set("cache.huge", "100000PiB".into()).unwrap();
+
+    assert_eq!(configer.get_duration("server.timeout", DurationUnit::Seconds), Ok(Duration::from_secs(30)));
+    assert_eq!(configer.get_duration("server.idle", DurationUnit::Seconds), Ok(Duration::from_secs(60)));
+    assert_eq!(configer.get_byte_size("cache.limit", ByteUnit::Bytes), Ok(ByteSize(512 * 1024 * 1024)));
+    assert_eq!(configer.get_with("server.idle", NodeConverter::to_u16), Ok(60));
+
+    // The errors point at the key that failed
+    let err = configer.get_byte_size("cache.huge", ByteUnit::Bytes).unwrap_err();
+    assert_eq!(
+        err,
+        ConversionError::AtKey(
+            "cache.huge".to_string(),
+            Box::new(ConversionError::Overflow("String".to_string(), "ByteSize".to_string()))
+        )
+    );
+    assert_eq!(err.to_string(), "Failed to convert key:[cache.huge], Can't convert String to ByteSize: out of range");
+
+    assert_eq!(
+        configer.get_duration("server.missing", DurationUnit::Seconds),
+        Err(ConversionError::AtKey(
+            "server.missing".to_string(),
+            Box::new(ConversionError::Lookup(ConfigerError::NotFound))
+        ))
+    );
+}
@@ -0,0 +1,155 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::fs;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat};
+
+use crate::domain::Table;
+use crate::error::FileError;
+
+// ----------------------------------------------------------------
+
+#[cfg(feature = "usetoml")]
+pub mod toml;
+#[cfg(feature = "useyaml")]
+pub mod yaml;
+#[cfg(feature = "usejson")]
+pub mod json;
+
+// ----------------------------------------------------------------
+
+pub const DATETIME_PATTERN: &str = "%Y-%m-%dT%H:%M:%S%.f";
+/// @since 0.7.0
+pub const DATE_PATTERN: &str = "%Y-%m-%d";
+/// @since 0.7.0
+pub const TIME_PATTERN: &str = "%H:%M:%S%.f";
+
+// ----------------------------------------------------------------
+
+/// The writer side of [`ConfigReader`](crate::reader::ConfigReader), serializes a [`Table`] into a file format.
+///
+/// Every writer maps the [`Node`](crate::domain::Node) variants onto its format as follows, keys are written in sorted order:
+///
+/// - `Nested` => table / object / mapping.
+/// - `Array` => array / sequence.
+/// - `DateTime` => `TOML` local date-time, `JSON` and `YAML` `ISO 8601` string, e.g.: `2024-03-15T01:00:00`.
+/// - `Date`, `Time`, `OffsetDateTime` => `TOML` local date, local time, offset date-time,
+///   `JSON` and `YAML` `ISO 8601` string, e.g.: `2024-03-15`, `01:00:00`, `2024-03-15T01:00:00+08:00`.
+/// - `String`, `Boolean` => string, boolean.
+/// - `Int32`, `Int64`, `IntU32` => integer.
+/// - `IntU64`, `Int128`, `IntU128` => integer. `TOML` integers are `i64` and `YAML` integers are `i64`/`u64`,
+///   values out of range are written as their decimal string. `JSON` numbers keep every digit.
+/// - `Float32`, `Float64` => float, `JSON` writes `NaN` and infinities as `null`.
+/// - `None` => `JSON` and `YAML` `null`. `TOML` has no null, the key is omitted from tables and rejected in arrays.
+///
+/// @since 0.7.0
+pub trait ConfigWriter: Send + Sync {
+    fn name(&self) -> String;
+    fn suffix(&self) -> String;
+    fn supports(&self, suffix: &str) -> bool;
+
+    fn write_to_string(&self, table: &Table) -> Result<String, FileError>;
+
+    fn write_to_path(&self, table: &Table, path: &str) -> Result<(), FileError> {
+        let content = self.write_to_string(table)?;
+        fs::write(path, content).map_err(|_| FileError::WriteFailed(path.to_string()))
+    }
+}
+
+// ----------------------------------------------------------------
+
+pub trait WriterRegistry: Send + Sync {
+    fn register(&mut self, writer: Box<dyn ConfigWriter>);
+    fn try_acquire(&self, suffix: &str) -> Option<&dyn ConfigWriter>;
+    fn try_acquires(&self) -> Vec<&dyn ConfigWriter>;
+}
+
+pub struct ConfigWriterRegistry {
+    writers: HashMap</*suffix*/String, Box<dyn ConfigWriter>>,
+}
+
+impl ConfigWriterRegistry {
+    pub fn new() -> Self {
+        Self {
+            writers: HashMap::new(),
+        }
+    }
+}
+
+impl Default for ConfigWriterRegistry {
+    fn default() -> Self {
+        ConfigWriterRegistry::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl WriterRegistry for ConfigWriterRegistry {
+    fn register(&mut self, writer: Box<dyn ConfigWriter>) {
+        self.writers.insert(writer.suffix(), writer);
+    }
+
+    fn try_acquire(&self, suffix: &str) -> Option<&dyn ConfigWriter> {
+        match self.writers.get(suffix) {
+            Some(writer) => Some(writer.as_ref()),
+            None => self.writers.values().find(|w| w.supports(suffix)).map(|w| w.as_ref()),
+        }
+    }
+
+    fn try_acquires(&self) -> Vec<&dyn ConfigWriter> {
+        self.writers.values().map(|w| w.as_ref() as &dyn ConfigWriter).collect()
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Formats a [`NaiveDateTime`] as an `ISO 8601` local date-time, e.g.: `2024-03-15T01:00:00`.
+pub fn format_datetime(datetime: &NaiveDateTime) -> String {
+    datetime.format(DATETIME_PATTERN).to_string()
+}
+
+/// Formats a [`NaiveDate`] as an `ISO 8601` local date, e.g.: `2024-03-15`.
+///
+/// @since 0.7.0
+pub fn format_date(date: &NaiveDate) -> String {
+    date.format(DATE_PATTERN).to_string()
+}
+
+/// Formats a [`NaiveTime`] as an `ISO 8601` local time, e.g.: `01:00:00`.
+///
+/// @since 0.7.0
+pub fn format_time(time: &NaiveTime) -> String {
+    time.format(TIME_PATTERN).to_string()
+}
+
+/// Formats an offset-aware date-time as `RFC 3339`, e.g.: `2024-03-15T01:00:00+08:00` or `2024-03-15T01:00:00Z`.
+///
+/// @since 0.7.0
+pub fn format_offset_datetime(datetime: &DateTime<FixedOffset>) -> String {
+    datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// Returns the entries of `table` sorted by key.
+#[cfg(any(feature = "usetoml", feature = "useyaml", feature = "usejson"))]
+pub(crate) fn sorted_entries(table: &Table) -> Vec<(&String, &crate::domain::Node)> {
+    let mut entries: Vec<_> = table.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
@@ -0,0 +1,146 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use serde::{Serialize, Serializer};
+use serde::ser::SerializeMap;
+use serde_json::{Map, Number, Value};
+
+use crate::domain::{Node, Table};
+use crate::error::FileError;
+use crate::reader::json::JSON;
+use crate::writer::{ConfigWriter, format_date, format_datetime, format_offset_datetime, format_time, sorted_entries};
+
+// ----------------------------------------------------------------
+
+const JSON_WRITER_NAME: &str = "json";
+
+// ----------------------------------------------------------------
+
+/// A [`ConfigWriter`] for `JSON` files, the output is pretty-printed.
+///
+/// @since 0.7.0
+pub struct JsonConfigWriter {
+    name: String,
+    suffix: String,
+}
+
+impl JsonConfigWriter {
+    fn new() -> Self {
+        Self {
+            name: JSON_WRITER_NAME.to_string(),
+            suffix: JSON_WRITER_NAME.to_string(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl Default for JsonConfigWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigWriter for JsonConfigWriter {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn suffix(&self) -> String {
+        self.suffix.clone()
+    }
+
+    fn supports(&self, suffix: &str) -> bool {
+        self.suffix.eq(suffix)
+    }
+
+    fn write_to_string(&self, table: &Table) -> Result<String, FileError> {
+        serde_json::to_string_pretty(&JsonTable(table))
+            .map_err(|err| FileError::SerializeFailed(JSON.to_string(), err.to_string()))
+    }
+}
+
+// ----------------------------------------------------------------
+
+fn table_to_json(table: &Table) -> Value {
+    let mut object = Map::new();
+    for (key, node) in sorted_entries(table) {
+        object.insert(key.clone(), node_to_json_value(node));
+    }
+
+    Value::Object(object)
+}
+
+/// Converts a [`Node`] into a `JSON` value.
+///
+/// A [`Value`] keeps no integer beyond `u64`, those are converted into strings,
+/// unlike [`JsonConfigWriter`], which writes every digit.
+pub fn node_to_json_value(node: &Node) -> Value {
+    match node {
+        Node::Nested(table) => table_to_json(table),
+        Node::Array(array) => Value::Array(array.iter().map(node_to_json_value).collect()),
+        Node::DateTime(datetime) => Value::String(format_datetime(datetime)),
+        Node::Date(date) => Value::String(format_date(date)),
+        Node::Time(time) => Value::String(format_time(time)),
+        Node::OffsetDateTime(datetime) => Value::String(format_offset_datetime(datetime)),
+        Node::String(s) => Value::String(s.clone()),
+        Node::Boolean(b) => Value::Bool(*b),
+        Node::IntU128(i) => Number::from_u128(*i).map_or_else(|| Value::String(i.to_string()), Value::Number),
+        Node::IntU64(i) => Value::Number((*i).into()),
+        Node::IntU32(i) => Value::Number((*i).into()),
+        Node::Int128(i) => Number::from_i128(*i).map_or_else(|| Value::String(i.to_string()), Value::Number),
+        Node::Int64(i) => Value::Number((*i).into()),
+        Node::Int32(i) => Value::Number((*i).into()),
+        Node::Float64(f) => Number::from_f64(*f).map_or(Value::Null, Value::Number),
+        Node::Float32(f) => Number::from_f64(*f as f64).map_or(Value::Null, Value::Number),
+        Node::None => Value::Null,
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Writes the keys in order, and `Int128` and `IntU128` as numbers, whatever their size.
+struct JsonTable<'a>(&'a Table);
+
+impl Serialize for JsonTable<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let entries = sorted_entries(self.0);
+        let mut map = serializer.serialize_map(Some(entries.len()))?;
+        for (key, node) in entries {
+            map.serialize_entry(key, &JsonNode(node))?;
+        }
+
+        map.end()
+    }
+}
+
+struct JsonNode<'a>(&'a Node);
+
+impl Serialize for JsonNode<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Node::Nested(table) => JsonTable(table).serialize(serializer),
+            Node::Array(array) => serializer.collect_seq(array.iter().map(JsonNode)),
+            Node::IntU128(i) => serializer.serialize_u128(*i),
+            Node::Int128(i) => serializer.serialize_i128(*i),
+            node => node_to_json_value(node).serialize(serializer),
+        }
+    }
+}
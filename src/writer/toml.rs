@@ -0,0 +1,192 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use toml::Value;
+use toml::value::{Date, Datetime, Offset, Time};
+
+use crate::domain::{Node, Table};
+use crate::error::FileError;
+use crate::reader::toml::TOML;
+use crate::writer::{ConfigWriter, format_date, format_datetime, format_offset_datetime, sorted_entries};
+
+// ----------------------------------------------------------------
+
+const TOML_WRITER_NAME: &str = "toml";
+
+// ----------------------------------------------------------------
+
+/// A [`ConfigWriter`] for `TOML` files.
+///
+/// @since 0.7.0
+pub struct TomlConfigWriter {
+    name: String,
+    suffix: String,
+}
+
+impl TomlConfigWriter {
+    fn new() -> Self {
+        Self {
+            name: TOML_WRITER_NAME.to_string(),
+            suffix: TOML_WRITER_NAME.to_string(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl Default for TomlConfigWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigWriter for TomlConfigWriter {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn suffix(&self) -> String {
+        self.suffix.clone()
+    }
+
+    fn supports(&self, suffix: &str) -> bool {
+        self.suffix.eq(suffix)
+    }
+
+    fn write_to_string(&self, table: &Table) -> Result<String, FileError> {
+        let value = Value::Table(table_to_toml(table)?);
+        toml::to_string(&value).map_err(|err| FileError::SerializeFailed(TOML.to_string(), err.to_string()))
+    }
+}
+
+// ----------------------------------------------------------------
+
+/// Inserted in key order, so that the keys stay sorted even when `toml` preserves the insertion order.
+fn table_to_toml(table: &Table) -> Result<toml::Table, FileError> {
+    let mut toml_table = toml::Table::new();
+    for (key, node) in sorted_entries(table) {
+        // `TOML` has no null
+        if let Some(value) = node_to_toml_value(node)? {
+            toml_table.insert(key.clone(), value);
+        }
+    }
+
+    Ok(toml_table)
+}
+
+/// Converts a [`Node`] into a `TOML` value, [`Node::None`] has no `TOML` counterpart and is converted into `None`.
+pub fn node_to_toml_value(node: &Node) -> Result<Option<Value>, FileError> {
+    let value = match node {
+        Node::Nested(table) => Value::Table(table_to_toml(table)?),
+        Node::Array(array) => {
+            let mut values = Vec::with_capacity(array.len());
+            for item in array {
+                match node_to_toml_value(item)? {
+                    Some(value) => values.push(value),
+                    None => return Err(FileError::SerializeFailed(
+                        TOML.to_string(),
+                        "null array elements are not supported".to_string(),
+                    )),
+                }
+            }
+            Value::Array(values)
+        }
+        // `TOML` years are limited to four digits
+        Node::DateTime(datetime) if !(0..=9999).contains(&datetime.year()) => Value::String(format_datetime(datetime)),
+        Node::DateTime(datetime) => Value::Datetime(naive_date_time_to_datetime(datetime)),
+        Node::Date(date) if !(0..=9999).contains(&date.year()) => Value::String(format_date(date)),
+        Node::Date(date) => Value::Datetime(Datetime {
+            date: Some(naive_date_to_date(date)),
+            time: None,
+            offset: None,
+        }),
+        Node::Time(time) => Value::Datetime(Datetime {
+            date: None,
+            time: Some(naive_time_to_time(time)),
+            offset: None,
+        }),
+        Node::OffsetDateTime(datetime) if !(0..=9999).contains(&datetime.year()) => Value::String(format_offset_datetime(datetime)),
+        Node::OffsetDateTime(datetime) => Value::Datetime(offset_date_time_to_datetime(datetime)),
+        Node::String(s) => Value::String(s.clone()),
+        Node::Boolean(b) => Value::Boolean(*b),
+        Node::IntU128(i) => integer_or_string(i64::try_from(*i).ok(), i),
+        Node::IntU64(i) => integer_or_string(i64::try_from(*i).ok(), i),
+        Node::IntU32(i) => Value::Integer(*i as i64),
+        Node::Int128(i) => integer_or_string(i64::try_from(*i).ok(), i),
+        Node::Int64(i) => Value::Integer(*i),
+        Node::Int32(i) => Value::Integer(*i as i64),
+        Node::Float64(f) => Value::Float(*f),
+        Node::Float32(f) => Value::Float(*f as f64),
+        Node::None => return Ok(None),
+    };
+
+    Ok(Some(value))
+}
+
+fn integer_or_string(integer: Option<i64>, literal: &impl ToString) -> Value {
+    match integer {
+        Some(i) => Value::Integer(i),
+        None => Value::String(literal.to_string()),
+    }
+}
+
+pub fn naive_date_time_to_datetime(datetime: &NaiveDateTime) -> Datetime {
+    Datetime {
+        date: Some(naive_date_to_date(&datetime.date())),
+        time: Some(naive_time_to_time(&datetime.time())),
+        offset: None,
+    }
+}
+
+/// @since 0.7.0
+pub fn offset_date_time_to_datetime(datetime: &DateTime<FixedOffset>) -> Datetime {
+    let local = datetime.naive_local();
+    Datetime {
+        date: Some(naive_date_to_date(&local.date())),
+        time: Some(naive_time_to_time(&local.time())),
+        offset: Some(fixed_offset_to_offset(datetime.offset())),
+    }
+}
+
+fn naive_date_to_date(date: &NaiveDate) -> Date {
+    Date {
+        year: date.year() as u16,
+        month: date.month() as u8,
+        day: date.day() as u8,
+    }
+}
+
+fn naive_time_to_time(time: &NaiveTime) -> Time {
+    Time {
+        hour: time.hour() as u8,
+        minute: time.minute() as u8,
+        second: time.second() as u8,
+        nanosecond: time.nanosecond(),
+    }
+}
+
+/// `TOML` offsets are in whole minutes, seconds are truncated.
+fn fixed_offset_to_offset(offset: &FixedOffset) -> Offset {
+    match offset.local_minus_utc() {
+        0 => Offset::Z,
+        seconds => Offset::Custom { minutes: (seconds / 60) as i16 },
+    }
+}
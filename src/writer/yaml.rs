@@ -0,0 +1,120 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use serde_yaml::{Mapping, Value};
+
+use crate::domain::{Node, Table};
+use crate::error::FileError;
+use crate::reader::yaml::YAML;
+use crate::writer::{ConfigWriter, format_date, format_datetime, format_offset_datetime, format_time, sorted_entries};
+
+// ----------------------------------------------------------------
+
+const YAML_WRITER_NAME: &str = "yaml";
+const YML_SUFFIX: &str = "yml";
+
+// ----------------------------------------------------------------
+
+/// A [`ConfigWriter`] for `YAML` files.
+///
+/// Registered under the `yaml` suffix, and also [`supports`](ConfigWriter::supports) the `yml` suffix.
+///
+/// @since 0.7.0
+pub struct YamlConfigWriter {
+    name: String,
+    suffix: String,
+}
+
+impl YamlConfigWriter {
+    fn new() -> Self {
+        Self {
+            name: YAML_WRITER_NAME.to_string(),
+            suffix: YAML_WRITER_NAME.to_string(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl Default for YamlConfigWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ----------------------------------------------------------------
+
+impl ConfigWriter for YamlConfigWriter {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn suffix(&self) -> String {
+        self.suffix.clone()
+    }
+
+    fn supports(&self, suffix: &str) -> bool {
+        self.suffix.eq(suffix) || YML_SUFFIX.eq(suffix)
+    }
+
+    fn write_to_string(&self, table: &Table) -> Result<String, FileError> {
+        serde_yaml::to_string(&table_to_yaml(table))
+            .map_err(|err| FileError::SerializeFailed(YAML.to_string(), err.to_string()))
+    }
+}
+
+// ----------------------------------------------------------------
+
+fn table_to_yaml(table: &Table) -> Value {
+    let mut mapping = Mapping::new();
+    for (key, node) in sorted_entries(table) {
+        mapping.insert(Value::String(key.clone()), node_to_yaml_value(node));
+    }
+
+    Value::Mapping(mapping)
+}
+
+/// Converts a [`Node`] into a `YAML` value.
+pub fn node_to_yaml_value(node: &Node) -> Value {
+    match node {
+        Node::Nested(table) => table_to_yaml(table),
+        Node::Array(array) => Value::Sequence(array.iter().map(node_to_yaml_value).collect()),
+        Node::DateTime(datetime) => Value::String(format_datetime(datetime)),
+        Node::Date(date) => Value::String(format_date(date)),
+        Node::Time(time) => Value::String(format_time(time)),
+        Node::OffsetDateTime(datetime) => Value::String(format_offset_datetime(datetime)),
+        Node::String(s) => Value::String(s.clone()),
+        Node::Boolean(b) => Value::Bool(*b),
+        Node::IntU128(i) => match u64::try_from(*i) {
+            Ok(u) => Value::Number(u.into()),
+            Err(_) => Value::String(i.to_string()),
+        },
+        Node::IntU64(i) => Value::Number((*i).into()),
+        Node::IntU32(i) => Value::Number((*i).into()),
+        Node::Int128(i) => match (i64::try_from(*i), u64::try_from(*i)) {
+            (Ok(i), _) => Value::Number(i.into()),
+            (_, Ok(u)) => Value::Number(u.into()),
+            _ => Value::String(i.to_string()),
+        },
+        Node::Int64(i) => Value::Number((*i).into()),
+        Node::Int32(i) => Value::Number((*i).into()),
+        Node::Float64(f) => Value::Number((*f).into()),
+        Node::Float32(f) => Value::Number((*f as f64).into()),
+        Node::None => Value::Null,
+    }
+}
@@ -0,0 +1,192 @@
+/*
+ * Copyright © 2024 the original author or authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// ----------------------------------------------------------------
+
+use std::fs;
+
+use chrono::{DateTime, NaiveDate};
+
+use crate::domain::{Node, Table};
+use crate::domain::converter::NodeConverter;
+use crate::env::Environment;
+use crate::env::standard::ConfigerEnvironment;
+use crate::error::FileError;
+use crate::reader::{ConfigReader, ConfigReaderRegistry, ReaderRegistry};
+use crate::reader::yaml::{YAML, YamlConfigReader};
+use crate::writer::ConfigWriter;
+use crate::writer::yaml::YamlConfigWriter;
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_yaml_reader_read_from_path() {
+    let yaml_reader = YamlConfigReader::default();
+
+    let path = "resources/testdata/configer-dev.yaml";
+    let yaml_from_path_rvt = yaml_reader.read_from_path(path);
+
+    if let Ok(table) = yaml_from_path_rvt {
+        return assert_table(table);
+    }
+
+    panic!("Failed to read config file")
+}
+
+#[test]
+fn test_yaml_reader_read_from_str() {
+    let yaml_reader = YamlConfigReader::default();
+
+    let path = "resources/testdata/configer-dev.yaml";
+    let content = fs::read_to_string(path).expect("Failed to read config file");
+    let yaml_from_content_rvt = yaml_reader.read_from_str(&content);
+
+    if let Ok(table) = yaml_from_content_rvt {
+        return assert_table(table);
+    }
+
+    panic!("Failed to read config file")
+}
+
+#[test]
+fn test_yaml_reader_node_mapping() {
+    let yaml_reader = YamlConfigReader::default();
+    let table = yaml_reader.read_from_path("resources/testdata/configer-dev.yaml").unwrap();
+    let configer = ConfigerEnvironment::table(table);
+
+    assert_eq!(configer.get("integer_value"), Ok(&Node::Int64(9527)));
+    assert_eq!(configer.get("float_value"), Ok(&Node::Float64(3.8848)));
+    assert_eq!(configer.get("none_value"), Ok(&Node::None));
+    assert_eq!(configer.get("table.table_s"), Ok(&Node::String("value1".to_string())));
+
+    let datetime = DateTime::parse_from_rfc3339("2024-03-15T01:00:00Z").unwrap();
+    assert_eq!(configer.get("table.table_datetime"), Ok(&Node::OffsetDateTime(datetime)));
+    let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    assert_eq!(configer.get("table.table_date"), Ok(&Node::Date(date)));
+
+    match NodeConverter::try_array(configer.get("endpoints")) {
+        Some(endpoints) => {
+            assert_eq!(endpoints.len(), 2);
+            assert!(matches!(endpoints[0], Node::Nested(_)));
+        }
+        _ => panic!("Failed to get key:[endpoints]")
+    }
+}
+
+#[test]
+fn test_yaml_reader_quoted_timestamps() {
+    let yaml_reader = YamlConfigReader::default();
+    let data = "plain: 2024-03-15\ndouble: \"2024-03-15\"\nsingle: '2024-03-15'\nlist: [2024-03-15, \"2024-03-15\"]\n";
+    let table = yaml_reader.read_from_str(data).unwrap();
+    let configer = ConfigerEnvironment::table(table);
+
+    let date = NaiveDate::from_ymd_opt(2024, 3, 15).unwrap();
+    assert_eq!(configer.get("plain"), Ok(&Node::Date(date)));
+    assert_eq!(configer.get("double"), Ok(&Node::String("2024-03-15".to_string())));
+    assert_eq!(configer.get("single"), Ok(&Node::String("2024-03-15".to_string())));
+    assert_eq!(configer.get("list[0]"), Ok(&Node::Date(date)));
+    assert_eq!(configer.get("list[1]"), Ok(&Node::String("2024-03-15".to_string())));
+}
+
+#[test]
+fn test_yaml_reader_incorrect_format() {
+    let yaml_reader = YamlConfigReader::default();
+
+    assert_eq!(
+        yaml_reader.read_from_str("- apple\n- banana"),
+        Err(FileError::IncorrectFormat(YAML.to_string()))
+    );
+    assert!(matches!(
+        yaml_reader.read_from_str("key: [unclosed"),
+        Err(FileError::ParseFailed(_, _))
+    ));
+    // Mapping keys must be scalars
+    assert!(matches!(
+        yaml_reader.read_from_str("? [a, b]\n: 1\n"),
+        Err(FileError::ParseFailed(_, _))
+    ));
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_yaml_reader_registry_suffixes() {
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<YamlConfigReader>::default());
+
+    assert!(registry.try_acquire("yaml").is_some());
+    assert!(registry.try_acquire("yml").is_some());
+    assert!(registry.try_acquire("toml").is_none());
+}
+
+#[test]
+fn test_build_configer_builder_with_registry_path_and_profiles() {
+    let path = "resources/testdata/config.yml";
+
+    let mut registry = ConfigReaderRegistry::default();
+    registry.register(Box::<YamlConfigReader>::default());
+
+    let builder_rvt = ConfigerEnvironment::builder()
+        .with_registry(Box::new(registry))
+        .with_path(path.to_string())
+        .with_profiles(vec![String::from("dev"), String::from("shared")])
+        .build();
+
+    if let Ok(configer) = builder_rvt {
+        // config-dev.yml
+        assert_eq!(configer.get("table.table_i32"), Ok(&Node::Int64(42)));
+        // config-shared.yml
+        assert!(NodeConverter::try_array(configer.get("strings")).is_some());
+        // config.yml
+        assert_eq!(configer.get("boolean_value"), Ok(&Node::Boolean(true)));
+
+        return;
+    }
+
+    panic!("Failed to read config-[dev, shared].yml file")
+}
+
+// ----------------------------------------------------------------
+
+fn assert_table(table: Table) {
+    assert!(table.contains_key("string_value"));
+    assert!(table.contains_key("floats"));
+    assert!(table.contains_key("table"));
+    assert!(table.contains_key("database"));
+}
+
+// ----------------------------------------------------------------
+
+#[test]
+fn test_yaml_writer_round_trip() {
+    let yaml_reader = YamlConfigReader::default();
+    let table = yaml_reader.read_from_path("resources/testdata/configer-dev.yaml").unwrap();
+
+    let yaml_writer = YamlConfigWriter::default();
+    let content = yaml_writer.write_to_string(&table).unwrap();
+
+    // The timestamps are written in `ISO 8601`, and read back as the same `Node`s
+    assert_eq!(yaml_reader.read_from_str(&content), Ok(table));
+}
+
+#[test]
+fn test_yaml_writer_registry_suffixes() {
+    let configer = ConfigerEnvironment::new();
+
+    assert!(configer.try_acquire_writer("yaml").is_some());
+    assert!(configer.try_acquire_writer("yml").is_some());
+    assert!(configer.write_to_string("yml").is_ok());
+}